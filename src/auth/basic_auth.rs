@@ -132,6 +132,26 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn password_with_colons() -> crate::Result<()> {
+        let authz = BasicAuth::new("nori", "secret:fish:!!");
+
+        let mut headers = Headers::new();
+        authz.apply_header(&mut headers);
+
+        let authz = BasicAuth::from_headers(headers)?.unwrap();
+        assert_eq!(authz.username(), "nori");
+        assert_eq!(authz.password(), "secret:fish:!!");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_non_utf8_credentials() {
+        let credentials = base64::encode([b'n', b'o', b'r', b'i', b':', 0xff, 0xfe]);
+        let err = BasicAuth::from_credentials(credentials).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
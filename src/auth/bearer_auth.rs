@@ -0,0 +1,143 @@
+use crate::auth::{AuthenticationScheme, Authorization};
+use crate::ensure_status as ensure;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, AUTHORIZATION};
+
+/// HTTP Bearer authorization.
+///
+/// # Specifications
+///
+/// - [RFC6750](https://tools.ietf.org/html/rfc6750)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::auth::BearerAuth;
+///
+/// let token = "mF_9.B5f-4.1JqM";
+/// let authz = BearerAuth::new(token);
+///
+/// let mut res = Response::new(200);
+/// res.insert_header(&authz, &authz);
+///
+/// let authz = BearerAuth::from_headers(res)?.unwrap();
+///
+/// assert_eq!(authz.token(), token);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct BearerAuth {
+    token: String,
+}
+
+impl BearerAuth {
+    /// Create a new instance of `BearerAuth`.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let token = token.as_ref().to_owned();
+        Self { token }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let auth = match Authorization::from_headers(headers)? {
+            Some(auth) => auth,
+            None => return Ok(None),
+        };
+
+        let scheme = auth.scheme();
+        ensure!(
+            matches!(scheme, AuthenticationScheme::Bearer),
+            400,
+            "Expected bearer auth scheme found `{}`",
+            scheme
+        );
+
+        let token = auth.credentials().trim();
+        ensure!(
+            !token.is_empty(),
+            400,
+            "Expected bearer auth to contain a token"
+        );
+        Ok(Some(Self::new(token)))
+    }
+
+    /// Get the token.
+    pub fn token(&self) -> &str {
+        self.token.as_str()
+    }
+}
+
+impl Header for BearerAuth {
+    fn header_name(&self) -> HeaderName {
+        AUTHORIZATION
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let scheme = AuthenticationScheme::Bearer;
+        let auth = Authorization::new(scheme, self.token.clone());
+        auth.header_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let token = "mF_9.B5f-4.1JqM";
+        let authz = BearerAuth::new(token);
+
+        let mut headers = Headers::new();
+        authz.apply_header(&mut headers);
+        assert_eq!(headers[AUTHORIZATION], "Bearer mF_9.B5f-4.1JqM");
+
+        let authz = BearerAuth::from_headers(headers)?.unwrap();
+        assert_eq!(authz.token(), token);
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_case_scheme() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers
+            .insert(AUTHORIZATION, "bEaReR mF_9.B5f-4.1JqM")
+            .unwrap();
+
+        let authz = BearerAuth::from_headers(headers)?.unwrap();
+        assert_eq!(authz.token(), "mF_9.B5f-4.1JqM");
+        Ok(())
+    }
+
+    #[test]
+    fn missing_header() -> crate::Result<()> {
+        let headers = Headers::new();
+        assert!(BearerAuth::from_headers(headers)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_empty_token() {
+        let mut headers = Headers::new();
+        headers.insert(AUTHORIZATION, "Bearer ").unwrap();
+        let err = BearerAuth::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let mut headers = Headers::new();
+        headers
+            .insert(AUTHORIZATION, "<nori ate the tag. yum.>")
+            .unwrap();
+        let err = BearerAuth::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -26,9 +26,11 @@
 mod authentication_scheme;
 mod authorization;
 mod basic_auth;
+mod bearer_auth;
 mod www_authenticate;
 
 pub use authentication_scheme::AuthenticationScheme;
 pub use authorization::Authorization;
 pub use basic_auth::BasicAuth;
+pub use bearer_auth::BearerAuth;
 pub use www_authenticate::WwwAuthenticate;
@@ -1,7 +1,10 @@
 use crate::bail_status as bail;
 use crate::headers::{HeaderName, HeaderValue, Headers, WWW_AUTHENTICATE};
+use crate::parse_utils::{format_quoted_string, parse_quoted_string, parse_token};
 use crate::{auth::AuthenticationScheme, headers::Header};
 
+use std::fmt::Write;
+
 /// Define the authentication method that should be used to gain access to a
 /// resource.
 ///
@@ -12,7 +15,8 @@ use crate::{auth::AuthenticationScheme, headers::Header};
 /// # Implementation Notes
 ///
 /// This implementation only encodes and parses a single authentication method,
-/// further authorization methods are ignored. It also always passes the utf-8 encoding flag.
+/// further authorization methods are ignored. `Basic` challenges always pass
+/// the utf-8 encoding flag.
 ///
 /// # Examples
 ///
@@ -40,12 +44,68 @@ use crate::{auth::AuthenticationScheme, headers::Header};
 pub struct WwwAuthenticate {
     scheme: AuthenticationScheme,
     realm: String,
+    error: Option<String>,
+    error_description: Option<String>,
 }
 
 impl WwwAuthenticate {
     /// Create a new instance of `WwwAuthenticate`.
     pub fn new(scheme: AuthenticationScheme, realm: String) -> Self {
-        Self { scheme, realm }
+        Self {
+            scheme,
+            realm,
+            error: None,
+            error_description: None,
+        }
+    }
+
+    /// Create a new `Basic` challenge for the given realm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::auth::WwwAuthenticate;
+    /// use http_types::headers::Header;
+    ///
+    /// let authz = WwwAuthenticate::basic("staging site".into());
+    /// assert_eq!(
+    ///     authz.header_value(),
+    ///     r#"Basic realm="staging site", charset="UTF-8""#
+    /// );
+    /// ```
+    pub fn basic(realm: String) -> Self {
+        Self::new(AuthenticationScheme::Basic, realm)
+    }
+
+    /// Create a new `Bearer` challenge for the given realm.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 6750, section 3: The WWW-Authenticate Response Header Field](https://tools.ietf.org/html/rfc6750#section-3)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::auth::WwwAuthenticate;
+    /// use http_types::headers::Header;
+    ///
+    /// let authz = WwwAuthenticate::bearer(
+    ///     "example".into(),
+    ///     Some("invalid_token".into()),
+    ///     Some("The access token expired".into()),
+    /// );
+    /// assert_eq!(
+    ///     authz.header_value(),
+    ///     r#"Bearer realm="example", error="invalid_token", error_description="The access token expired""#
+    /// );
+    /// ```
+    pub fn bearer(realm: String, error: Option<String>, error_description: Option<String>) -> Self {
+        Self {
+            scheme: AuthenticationScheme::Bearer,
+            realm,
+            error,
+            error_description,
+        }
     }
 
     /// Create a new instance from headers.
@@ -61,36 +121,54 @@ impl WwwAuthenticate {
 
         let mut iter = value.as_str().splitn(2, ' ');
         let scheme = iter.next();
-        let credential = iter.next();
-        let (scheme, realm) = match (scheme, credential) {
+        let params = iter.next();
+        let (scheme, mut input) = match (scheme, params) {
             (None, _) => bail!(400, "Could not find scheme"),
             (Some(_), None) => bail!(400, "Could not find realm"),
-            (Some(scheme), Some(realm)) => (scheme.parse()?, realm.to_owned()),
+            (Some(scheme), Some(params)) => (scheme.parse()?, params),
         };
 
-        let realm = realm.trim_start();
-        let realm = match realm.strip_prefix(r#"realm=""#) {
+        let mut realm = None;
+        let mut error = None;
+        let mut error_description = None;
+        loop {
+            input = input.trim_start_matches([' ', ',']);
+            if input.is_empty() {
+                break;
+            }
+
+            let (key, rest) = match parse_token(input) {
+                (Some(key), rest) if rest.starts_with('=') => (key, &rest[1..]),
+                _ => bail!(400, "Expected an auth-param"),
+            };
+            let (value, rest) = match parse_token(rest) {
+                (Some(token), rest) => (token.to_owned(), rest),
+                (None, rest) => match parse_quoted_string(rest) {
+                    (Some(value), rest) => (value.into_owned(), rest),
+                    (None, _) => bail!(400, r"Expected a closing quote"),
+                },
+            };
+            input = rest;
+
+            match key.to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value),
+                "error" => error = Some(value),
+                "error_description" => error_description = Some(value),
+                _ => { /* other auth-params, such as `charset`, are ignored */ }
+            }
+        }
+
+        let realm = match realm {
             Some(realm) => realm,
             None => bail!(400, "realm not found"),
         };
 
-        let mut chars = realm.chars();
-        let mut closing_quote = false;
-        let realm = (&mut chars)
-            .take_while(|c| {
-                if c == &'"' {
-                    closing_quote = true;
-                    false
-                } else {
-                    true
-                }
-            })
-            .collect();
-        if !closing_quote {
-            bail!(400, r"Expected a closing quote");
-        }
-
-        Ok(Some(Self { scheme, realm }))
+        Ok(Some(Self {
+            scheme,
+            realm,
+            error,
+            error_description,
+        }))
     }
 
     /// Get the authorization scheme.
@@ -112,6 +190,26 @@ impl WwwAuthenticate {
     pub fn set_realm(&mut self, realm: String) {
         self.realm = realm;
     }
+
+    /// Get the error code, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Set the error code.
+    pub fn set_error(&mut self, error: Option<String>) {
+        self.error = error;
+    }
+
+    /// Get the human-readable error description, if any.
+    pub fn error_description(&self) -> Option<&str> {
+        self.error_description.as_deref()
+    }
+
+    /// Set the human-readable error description.
+    pub fn set_error_description(&mut self, error_description: Option<String>) {
+        self.error_description = error_description;
+    }
 }
 
 impl Header for WwwAuthenticate {
@@ -120,7 +218,25 @@ impl Header for WwwAuthenticate {
     }
 
     fn header_value(&self) -> HeaderValue {
-        let output = format!(r#"{} realm="{}", charset="UTF-8""#, self.scheme, self.realm);
+        let mut output = format!(
+            "{} realm={}",
+            self.scheme,
+            format_quoted_string(&self.realm)
+        );
+        if let Some(error) = &self.error {
+            write!(output, ", error={}", format_quoted_string(error)).unwrap();
+        }
+        if let Some(description) = &self.error_description {
+            write!(
+                output,
+                ", error_description={}",
+                format_quoted_string(description)
+            )
+            .unwrap();
+        }
+        if let AuthenticationScheme::Basic = self.scheme {
+            output.push_str(r#", charset="UTF-8""#);
+        }
 
         // SAFETY: the internal string is validated to be ASCII.
         unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
@@ -153,6 +269,47 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn escapes_quotes_in_realm() -> crate::Result<()> {
+        let authz = WwwAuthenticate::basic(r#"Nori's "secret" site"#.into());
+
+        let mut headers = Headers::new();
+        authz.apply_header(&mut headers);
+
+        assert_eq!(
+            headers["WWW-Authenticate"],
+            r#"Basic realm="Nori's \"secret\" site", charset="UTF-8""#
+        );
+
+        let authz = WwwAuthenticate::from_headers(headers)?.unwrap();
+        assert_eq!(authz.realm(), r#"Nori's "secret" site"#);
+        Ok(())
+    }
+
+    #[test]
+    fn bearer() -> crate::Result<()> {
+        let authz = WwwAuthenticate::bearer(
+            "example".into(),
+            Some("invalid_token".into()),
+            Some("The access token expired".into()),
+        );
+
+        let mut headers = Headers::new();
+        authz.apply_header(&mut headers);
+
+        assert_eq!(
+            headers["WWW-Authenticate"],
+            r#"Bearer realm="example", error="invalid_token", error_description="The access token expired""#
+        );
+
+        let authz = WwwAuthenticate::from_headers(headers)?.unwrap();
+        assert_eq!(authz.scheme(), AuthenticationScheme::Bearer);
+        assert_eq!(authz.realm(), "example");
+        assert_eq!(authz.error(), Some("invalid_token"));
+        assert_eq!(authz.error_description(), Some("The access token expired"));
+        Ok(())
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
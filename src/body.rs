@@ -2,12 +2,24 @@ use futures_lite::{io, prelude::*, ready};
 #[cfg(feature = "serde")]
 use serde_crate::{de::DeserializeOwned, Serialize};
 
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::convert::TryFrom;
 use std::fmt::{self, Debug};
 use std::pin::Pin;
-use std::task::{Context, Poll};
-
+use std::rc::Rc;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "timer")]
+use std::time::Duration;
+
+#[cfg(feature = "md5")]
+use crate::content::ContentMd5;
+#[cfg(feature = "digest")]
+use crate::content::{DigestAlgorithm, DigestHandle, DigestReader};
 use crate::mime::{self, Mime};
+use crate::trailers;
+use crate::transfer::{ChunkedDecoder, ChunkedEncoder};
 use crate::{Status, StatusCode};
 
 pin_project_lite::pin_project! {
@@ -59,6 +71,8 @@ pin_project_lite::pin_project! {
         mime: Option<Mime>,
         length: Option<u64>,
         bytes_read: u64,
+        buffer: Option<SharedBytes>,
+        chunked: bool,
     }
 }
 
@@ -77,11 +91,14 @@ impl Body {
     /// req.set_body(Body::empty());
     /// ```
     pub fn empty() -> Self {
+        // `io::Empty` is zero-sized, so boxing it doesn't allocate.
         Self {
             reader: Box::new(io::empty()),
             mime: Some(mime::BYTE_STREAM),
             length: Some(0),
             bytes_read: 0,
+            buffer: None,
+            chunked: false,
         }
     }
 
@@ -113,9 +130,140 @@ impl Body {
             mime: Some(mime::BYTE_STREAM),
             length,
             bytes_read: 0,
+            buffer: None,
+            chunked: false,
         }
     }
 
+    /// Create a `Body` from an unbuffered reader with an optional length.
+    ///
+    /// This behaves like [`Body::from_reader`], but wraps the reader in a
+    /// [`BufReader`](futures_lite::io::BufReader) first, so that plain
+    /// `AsyncRead` sources such as sockets can be used directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use futures_lite::io::AsyncRead;
+    /// use http_types::Body;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll};
+    ///
+    /// /// A reader which only implements `AsyncRead`, producing `n` zeroes.
+    /// struct Zeroes(usize);
+    ///
+    /// impl AsyncRead for Zeroes {
+    ///     fn poll_read(
+    ///         mut self: Pin<&mut Self>,
+    ///         _cx: &mut Context<'_>,
+    ///         buf: &mut [u8],
+    ///     ) -> Poll<std::io::Result<usize>> {
+    ///         let len = self.0.min(buf.len());
+    ///         buf[..len].fill(0);
+    ///         self.0 -= len;
+    ///         Poll::Ready(Ok(len))
+    ///     }
+    /// }
+    ///
+    /// let body = Body::from_async_read(Zeroes(4), Some(4));
+    /// assert_eq!(body.len(), Some(4));
+    /// assert_eq!(body.into_bytes().await?, vec![0; 4]);
+    /// # Ok(()) }) }
+    /// ```
+    pub fn from_async_read(reader: impl AsyncRead + Unpin + 'static, length: Option<u64>) -> Self {
+        Self::from_reader(io::BufReader::new(reader), length)
+    }
+
+    /// Create a `Body` from a reader, sniffing the Mime type from its contents.
+    ///
+    /// The first bytes of the reader are buffered to detect the Mime type,
+    /// and are then read again as part of the body. This works for readers
+    /// which can't seek. If no Mime type can be sniffed, the Mime type is set
+    /// to `application/octet-stream`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::{mime, Body};
+    /// use async_std::io::Cursor;
+    ///
+    /// let png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    /// let body = Body::from_reader_sniffed(Cursor::new(png.clone()), None).await?;
+    /// assert_eq!(body.mime(), Some(&mime::PNG));
+    /// assert_eq!(body.into_bytes().await?, png);
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn from_reader_sniffed(
+        mut reader: impl AsyncBufRead + Unpin + 'static,
+        length: Option<u64>,
+    ) -> io::Result<Self> {
+        // We need to read the first 300 bytes to correctly infer formats such as tar.
+        let limit = length.map_or(300, |length| length.min(300));
+        let mut prefix = Vec::with_capacity(limit as usize);
+        (&mut reader).take(limit).read_to_end(&mut prefix).await?;
+        let mime = Mime::sniff(&prefix).unwrap_or(mime::BYTE_STREAM);
+
+        let reader = io::Cursor::new(prefix).chain(reader);
+        let mut body = Self::from_reader(reader, length);
+        body.set_mime(Some(mime));
+        Ok(body)
+    }
+
+    /// Create a `Body` of `len` bytes, all set to `byte`.
+    ///
+    /// The contents are generated while the body is read, so large bodies
+    /// can be created without allocating them up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::repeat(b'a', 3);
+    /// assert_eq!(body.len(), Some(3));
+    /// assert_eq!(&body.into_string().await?, "aaa");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn repeat(byte: u8, len: u64) -> Self {
+        Self::from_async_read(io::repeat(byte).take(len), Some(len))
+    }
+
+    /// Create a `Body` of `len` bytes, made up of `pattern` repeated.
+    ///
+    /// The contents are generated while the body is read, so large bodies
+    /// can be created without allocating them up front. The last repetition
+    /// of the pattern is cut short if `len` isn't a multiple of its length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty, and `len` isn't zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::repeat_slice(b"abc".to_vec(), 7);
+    /// assert_eq!(body.len(), Some(7));
+    /// assert_eq!(&body.into_string().await?, "abcabca");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn repeat_slice(pattern: Vec<u8>, len: u64) -> Self {
+        assert!(
+            !pattern.is_empty() || len == 0,
+            "cannot repeat an empty pattern"
+        );
+        let reader = RepeatSlice {
+            pattern,
+            position: 0,
+        };
+        Self::from_async_read(reader.take(len), Some(len))
+    }
+
     /// Get the inner reader from the `Body`
     ///
     /// # Examples
@@ -152,12 +300,7 @@ impl Body {
     /// req.set_body(Body::from_bytes(input));
     /// ```
     pub fn from_bytes(bytes: Vec<u8>) -> Self {
-        Self {
-            mime: Some(mime::BYTE_STREAM),
-            length: Some(bytes.len() as u64),
-            reader: Box::new(io::Cursor::new(bytes)),
-            bytes_read: 0,
-        }
+        Self::from_buffer(bytes, mime::BYTE_STREAM)
     }
 
     /// Parse the body into a `Vec<u8>`.
@@ -177,9 +320,7 @@ impl Body {
     /// ```
     pub async fn into_bytes(mut self) -> crate::Result<Vec<u8>> {
         let mut buf = Vec::with_capacity(1024);
-        self.read_to_end(&mut buf)
-            .await
-            .status(StatusCode::UnprocessableEntity)?;
+        self.read_to_end(&mut buf).await.map_err(read_error)?;
         Ok(buf)
     }
 
@@ -202,12 +343,7 @@ impl Body {
     /// req.set_body(Body::from_string(input));
     /// ```
     pub fn from_string(s: String) -> Self {
-        Self {
-            mime: Some(mime::PLAIN),
-            length: Some(s.len() as u64),
-            reader: Box::new(io::Cursor::new(s.into_bytes())),
-            bytes_read: 0,
-        }
+        Self::from_buffer(s.into_bytes(), mime::PLAIN)
     }
 
     /// Read the body as a string
@@ -227,9 +363,7 @@ impl Body {
     pub async fn into_string(mut self) -> crate::Result<String> {
         let len = usize::try_from(self.len().unwrap_or(0)).status(StatusCode::PayloadTooLarge)?;
         let mut result = String::with_capacity(len);
-        self.read_to_string(&mut result)
-            .await
-            .status(StatusCode::UnprocessableEntity)?;
+        self.read_to_string(&mut result).await.map_err(read_error)?;
         Ok(result)
     }
 
@@ -250,13 +384,71 @@ impl Body {
     #[cfg(feature = "serde")]
     pub fn from_json(json: &impl Serialize) -> crate::Result<Self> {
         let bytes = serde_json::to_vec(&json)?;
-        let body = Self {
-            length: Some(bytes.len() as u64),
-            reader: Box::new(io::Cursor::new(bytes)),
-            mime: Some(mime::JSON),
-            bytes_read: 0,
+        Ok(Self::from_buffer(bytes, mime::JSON))
+    }
+
+    /// Creates a `Body` from a JSON value.
+    ///
+    /// This is the fallible counterpart to `From<serde_json::Value>`, for
+    /// callers which can't risk a panic.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/json`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::{Body, convert::json};
+    ///
+    /// let body = Body::try_from_json_value(json!({ "name": "Chashu" }))?;
+    /// assert_eq!(&body.into_string().await?, r#"{"name":"Chashu"}"#);
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn try_from_json_value(value: serde_json::Value) -> crate::Result<Self> {
+        Self::from_json(&value)
+    }
+
+    /// Creates a `Body` from a sequence of items, streaming them as a JSON
+    /// array.
+    ///
+    /// Items are serialized one at a time while the body is read, so large
+    /// arrays don't have to be held in memory. The body has no length. Errors
+    /// serializing an item are returned as `io::Error`s when reading the body.
+    ///
+    /// # Mime
+    ///
+    /// The encoding is set to `application/json`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from_json_array(vec!["Nori", "Chashu"]);
+    /// assert_eq!(&body.into_string().await?, r#"["Nori","Chashu"]"#);
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json_array<T, I>(items: I) -> Self
+    where
+        T: Serialize,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Unpin + 'static,
+    {
+        let reader = JsonArray {
+            items: items.into_iter(),
+            framed: Vec::new(),
+            position: 0,
+            started: false,
+            done: false,
         };
-        Ok(body)
+        let mut body = Self::from_async_read(reader, None);
+        body.set_mime(Some(mime::JSON));
+        body
     }
 
     /// Parse the body as JSON, serializing it to a struct.
@@ -317,15 +509,7 @@ impl Body {
     #[cfg(feature = "serde")]
     pub fn from_form(form: &impl Serialize) -> crate::Result<Self> {
         let query = serde_urlencoded::to_string(form)?;
-        let bytes = query.into_bytes();
-
-        let body = Self {
-            length: Some(bytes.len() as u64),
-            reader: Box::new(io::Cursor::new(bytes)),
-            mime: Some(mime::FORM),
-            bytes_read: 0,
-        };
-        Ok(body)
+        Ok(Self::from_buffer(query.into_bytes(), mime::FORM))
     }
 
     /// Parse the body from form encoding into a type.
@@ -448,6 +632,8 @@ impl Body {
             length: Some(len),
             reader: Box::new(io::BufReader::new(file)),
             bytes_read: 0,
+            buffer: None,
+            chunked: false,
         })
     }
 
@@ -473,6 +659,25 @@ impl Body {
         self.length.map(|length| length == 0)
     }
 
+    /// Returns `true` only if the body is known to have a length of zero.
+    ///
+    /// Bodies of unknown length return `false`, which makes this convenient to
+    /// decide whether writing the body can be skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Body;
+    /// use async_std::io::Cursor;
+    ///
+    /// assert!(Body::empty().is_empty_known());
+    /// assert!(!Body::from("Nori").is_empty_known());
+    /// assert!(!Body::from_reader(Cursor::new(""), None).is_empty_known());
+    /// ```
+    pub fn is_empty_known(&self) -> bool {
+        self.length == Some(0)
+    }
+
     /// Returns the mime type of this Body.
     pub fn mime(&self) -> Option<&Mime> {
         self.mime.as_ref()
@@ -532,83 +737,905 @@ impl Body {
             length,
             reader: Box::new(futures_lite::io::AsyncReadExt::chain(self, other)),
             bytes_read: 0,
+            buffer: None,
+            chunked: false,
         }
     }
-}
-
-impl Debug for Body {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Body")
-            .field("reader", &"<hidden>")
-            .field("length", &self.length)
-            .field("bytes_read", &self.bytes_read)
-            .finish()
-    }
-}
-
-#[cfg(feature = "serde")]
-impl From<serde_json::Value> for Body {
-    fn from(json_value: serde_json::Value) -> Self {
-        Self::from_json(&json_value).unwrap()
-    }
-}
 
-impl From<String> for Body {
-    fn from(s: String) -> Self {
-        Self::from_string(s)
+    /// Attempt to clone the body.
+    ///
+    /// This only succeeds for bodies which are held in memory, such as those
+    /// created from strings, bytes, JSON, or forms, and which haven't been
+    /// read from yet. Streaming bodies can't be replayed, and return `None`,
+    /// unless they are known to be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    /// use async_std::io::Cursor;
+    ///
+    /// let body = Body::from_string("Hello Nori".to_string());
+    /// let clone = body.try_clone().unwrap();
+    /// assert_eq!(&body.into_string().await?, "Hello Nori");
+    /// assert_eq!(&clone.into_string().await?, "Hello Nori");
+    ///
+    /// let cursor = Cursor::new("Hello Chashu");
+    /// let body = Body::from_reader(cursor, None);
+    /// assert!(body.try_clone().is_none());
+    /// # Ok(()) }) }
+    /// ```
+    pub fn try_clone(&self) -> Option<Self> {
+        if self.bytes_read != 0 {
+            return None;
+        }
+        if self.is_empty_known() {
+            let mut body = Self::empty();
+            body.mime = self.mime.clone();
+            return Some(body);
+        }
+        let buffer = self.buffer.clone()?;
+        Some(Self {
+            reader: Box::new(io::Cursor::new(buffer.clone())),
+            mime: self.mime.clone(),
+            length: self.length,
+            bytes_read: 0,
+            buffer: Some(buffer),
+            chunked: false,
+        })
     }
-}
 
-impl<'a> From<&'a str> for Body {
-    fn from(s: &'a str) -> Self {
-        Self::from_string(s.to_owned())
+    /// Write the body to a writer, and flush it.
+    ///
+    /// Reading stops once the length of the body has been written, even if
+    /// the underlying reader has more data. Returns the number of bytes
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let mut output = vec![];
+    /// let written = Body::from("Hello Nori").encode_to(&mut output).await?;
+    /// assert_eq!(written, 10);
+    /// assert_eq!(output, b"Hello Nori");
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn encode_to<W>(self, writer: &mut W) -> io::Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        // `io::copy` reads through `poll_read`, which respects the length.
+        let written = io::copy(self, &mut *writer).await?;
+        writer.flush().await?;
+        Ok(written)
     }
-}
 
-impl From<Vec<u8>> for Body {
-    fn from(b: Vec<u8>) -> Self {
-        Self::from_bytes(b)
+    /// Read the body to the end, discarding its contents.
+    ///
+    /// This is useful when a body must be consumed, for example so the
+    /// connection can be reused, without its contents being needed. The
+    /// contents are read through a fixed-size buffer, rather than collected
+    /// into memory. Returns the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("Hello Nori");
+    /// assert_eq!(body.drain().await?, 10);
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn drain(self) -> io::Result<u64> {
+        io::copy(self, &mut io::sink()).await
     }
-}
 
-impl<'a> From<&'a [u8]> for Body {
-    fn from(b: &'a [u8]) -> Self {
-        Self::from_bytes(b.to_owned())
+    /// Read this body and another body to the end, and compare their contents.
+    ///
+    /// This is mostly useful in tests. Both bodies are consumed by the
+    /// comparison: afterwards they're empty, and reading them yields no more
+    /// data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let mut body = Body::from("Hello Nori");
+    /// assert!(body.bytes_eq(&mut Body::from(b"Hello Nori".to_vec())).await?);
+    /// # Ok(()) }) }
+    /// ```
+    pub async fn bytes_eq(&mut self, other: &mut Body) -> crate::Result<bool> {
+        let mut ours = Vec::new();
+        self.read_to_end(&mut ours).await.map_err(read_error)?;
+        let mut theirs = Vec::new();
+        other.read_to_end(&mut theirs).await.map_err(read_error)?;
+        Ok(ours == theirs)
     }
-}
-
-impl AsyncRead for Body {
-    #[allow(rustdoc::missing_doc_code_examples)]
-    fn poll_read(
-        mut self: Pin<&mut Self>,
-        cx: &mut Context<'_>,
-        buf: &mut [u8],
-    ) -> Poll<io::Result<usize>> {
-        let buf = match self.length {
-            None => buf,
-            Some(length) if length == self.bytes_read => return Poll::Ready(Ok(0)),
-            Some(length) => {
-                // Compute `min` using u64, then truncate back to usize. Since
-                // buf.len() is a usize, this can never overflow.
-                let max_len = (length - self.bytes_read).min(buf.len() as u64) as usize;
-                &mut buf[0..max_len]
-            }
-        };
 
-        let bytes = ready!(Pin::new(&mut self.reader).poll_read(cx, buf))?;
-        self.bytes_read += bytes as u64;
-        Poll::Ready(Ok(bytes))
+    /// Read the body line by line.
+    ///
+    /// Each line is yielded without its trailing `\n` or `\r\n`. If the body
+    /// is not valid UTF-8, an error of kind [`io::ErrorKind::InvalidData`] is
+    /// yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use futures_lite::stream::StreamExt;
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("GET /\nHost: example.com\n");
+    /// let lines: Vec<String> = body.lines().try_collect().await?;
+    /// assert_eq!(lines, ["GET /", "Host: example.com"]);
+    /// # Ok(()) }) }
+    /// ```
+    pub fn lines(self) -> impl Stream<Item = io::Result<String>> {
+        // Buffer through `poll_read`, which respects the length of the body.
+        io::BufReader::new(self).lines()
     }
-}
 
-impl AsyncBufRead for Body {
-    #[allow(rustdoc::missing_doc_code_examples)]
-    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
-        self.project().reader.poll_fill_buf(cx)
+    /// Limit the number of bytes that can be read from the body.
+    ///
+    /// Unlike [`AsyncReadExt::take`], which silently stops reading at the
+    /// limit, reading past `max` bytes returns an [`io::Error`] of kind
+    /// [`io::ErrorKind::InvalidData`]. [`Body::into_bytes`] and
+    /// [`Body::into_string`] turn this error into a `413: Payload Too Large`,
+    /// so servers can reject oversized bodies rather than processing a
+    /// truncated one. The limit applies regardless of whether the body has a
+    /// length.
+    ///
+    /// [`AsyncReadExt::take`]: futures_lite::io::AsyncReadExt::take
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    /// use async_std::io::Cursor;
+    ///
+    /// let body = Body::from_reader(Cursor::new("Hello Nori"), None).bounded(5);
+    /// let err = body.into_bytes().await.unwrap_err();
+    /// assert_eq!(err.status(), 413);
+    ///
+    /// let body = Body::from_reader(Cursor::new("Hello"), None).bounded(5);
+    /// assert_eq!(&body.into_string().await?, "Hello");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn bounded(self, max: u64) -> Self {
+        let mime = self.mime.clone();
+        let length = self.length.map(|length| length - self.bytes_read);
+        let reader = io::BufReader::new(Bounded {
+            inner: self,
+            remaining: max,
+        });
+        Self {
+            reader: Box::new(reader),
+            mime,
+            length,
+            bytes_read: 0,
+            buffer: None,
+            chunked: false,
+        }
     }
 
-    fn consume(mut self: Pin<&mut Self>, amt: usize) {
-        Pin::new(&mut self.reader).consume(amt)
+    /// Fail reads which take longer than `timeout`.
+    ///
+    /// The timeout applies to each read separately, and is reset after every
+    /// read which completes. Reads which time out fail with an error of kind
+    /// [`TimedOut`](std::io::ErrorKind::TimedOut), which is converted to a
+    /// `408 Request Timeout` by methods such as
+    /// [`into_bytes`](Body::into_bytes). This protects against peers which
+    /// send a body very slowly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    /// use std::time::Duration;
+    ///
+    /// let body = Body::from("Hello Nori").with_read_timeout(Duration::from_secs(5));
+    /// assert_eq!(&body.into_string().await?, "Hello Nori");
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "timer")]
+    pub fn with_read_timeout(self, timeout: Duration) -> Self {
+        let mime = self.mime.clone();
+        let length = self.length.map(|length| length - self.bytes_read);
+        let reader = io::BufReader::new(ReadTimeout {
+            inner: self,
+            timeout,
+            timer: None,
+        });
+        Self {
+            reader: Box::new(reader),
+            mime,
+            length,
+            bytes_read: 0,
+            buffer: None,
+            chunked: false,
+        }
+    }
+
+    /// Transform the contents of the body while it is read.
+    ///
+    /// `f` is called with each buffer read from the body, and its output is
+    /// read in place of the buffer. Buffers can be of any size, so `f`
+    /// shouldn't expect a pattern to be contained within a single buffer.
+    /// Because the output may be of a different length than the input, the
+    /// resulting body has no length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("Hello Nori").map_bytes(|bytes| bytes.to_ascii_uppercase());
+    /// assert_eq!(body.len(), None);
+    /// assert_eq!(&body.into_string().await?, "HELLO NORI");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn map_bytes<F>(self, f: F) -> Self
+    where
+        F: FnMut(&[u8]) -> Vec<u8> + Send + 'static,
+    {
+        let mime = self.mime.clone();
+        let reader = io::BufReader::new(MapBytes {
+            inner: self,
+            f,
+            pending: Vec::new(),
+            position: 0,
+        });
+        Self {
+            reader: Box::new(reader),
+            mime,
+            length: None,
+            bytes_read: 0,
+            buffer: None,
+            chunked: false,
+        }
+    }
+
+    /// Compute a digest of the body while it is read.
+    ///
+    /// Returns the wrapped body, and a handle from which the digest can be
+    /// retrieved once the body has been read to the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::content::DigestAlgorithm;
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("Hello Nori");
+    /// let (body, digest) = body.with_digest(DigestAlgorithm::Sha256);
+    /// assert!(digest.finalize().is_none());
+    ///
+    /// assert_eq!(&body.into_string().await?, "Hello Nori");
+    /// assert_eq!(digest.finalize().unwrap().len(), 32);
+    /// # Ok(()) }) }
+    /// ```
+    #[cfg(feature = "digest")]
+    pub fn with_digest(self, algorithm: DigestAlgorithm) -> (Self, DigestHandle) {
+        let mime = self.mime.clone();
+        let length = self.length.map(|length| length - self.bytes_read);
+        let (reader, handle) = DigestReader::new(self, length, algorithm);
+        let body = Self {
+            reader: Box::new(io::BufReader::new(reader)),
+            mime,
+            length,
+            bytes_read: 0,
+            buffer: None,
+            chunked: false,
+        };
+        (body, handle)
+    }
+
+    /// Split the body into two bodies which both yield its contents.
+    ///
+    /// The body is only read once: whichever half reads ahead reads from the
+    /// original body, and the bytes it reads are buffered until the other
+    /// half has read them too. Both halves have the same length and mime type
+    /// as the original body.
+    ///
+    /// # Memory
+    ///
+    /// The buffer grows by as much as one half is ahead of the other. If only
+    /// one half is read, the entire body ends up in memory, so halves which
+    /// won't be read should be dropped, which stops buffering for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let (body, copy) = Body::from("Hello Nori").fork();
+    /// assert_eq!(&body.into_string().await?, "Hello Nori");
+    /// assert_eq!(&copy.into_string().await?, "Hello Nori");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn fork(self) -> (Self, Self) {
+        let mime = self.mime.clone();
+        let length = self.length.map(|length| length - self.bytes_read);
+        let state = Rc::new(RefCell::new(ForkState {
+            source: self,
+            buffer: VecDeque::new(),
+            start: 0,
+            positions: [Some(0), Some(0)],
+            wakers: [None, None],
+            done: false,
+            error: None,
+        }));
+        let half = |index| Self {
+            reader: Box::new(io::BufReader::new(Fork {
+                state: state.clone(),
+                index,
+            })),
+            mime: mime.clone(),
+            length,
+            bytes_read: 0,
+            buffer: None,
+            chunked: false,
+        };
+        (half(0), half(1))
+    }
+
+    /// Compute the MD5 digest of the body, if it's held in memory.
+    ///
+    /// The body isn't read, so `None` is returned for streaming bodies. Use
+    /// [`into_bytes`](Body::into_bytes) to buffer those first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Body, Response};
+    ///
+    /// let body = Body::from("Hello Nori");
+    /// let md5 = body.content_md5().unwrap();
+    ///
+    /// let mut res = Response::new(200);
+    /// md5.apply(&mut res);
+    /// res.set_body(body);
+    /// assert_eq!(res["Content-MD5"], "80zDaXwRP4Ee8/ZpSfIJaQ==");
+    /// ```
+    #[cfg(feature = "md5")]
+    pub fn content_md5(&self) -> Option<ContentMd5> {
+        self.peek().map(ContentMd5::from_bytes)
+    }
+
+    /// Frame the body using chunked transfer-encoding.
+    ///
+    /// Reading the resulting body yields the chunk-size lines, chunk data,
+    /// and the terminating zero-length chunk, ready to be written to the
+    /// wire as-is. The resulting body has no length, and is marked as
+    /// [chunked](Body::is_chunked) so it won't be framed again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("Hello Nori").into_chunked();
+    /// assert!(body.is_chunked());
+    /// assert_eq!(body.len(), None);
+    /// assert_eq!(&body.into_string().await?, "A\r\nHello Nori\r\n0\r\n\r\n");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn into_chunked(self) -> Self {
+        if self.chunked {
+            return self;
+        }
+        let mime = self.mime.clone();
+        Self {
+            reader: Box::new(io::BufReader::new(ChunkedEncoder::new(self))),
+            mime,
+            length: None,
+            bytes_read: 0,
+            buffer: None,
+            chunked: true,
+        }
+    }
+
+    /// Returns `true` if reading the body yields data framed using chunked
+    /// transfer-encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Body;
+    ///
+    /// let body = Body::from("Hello Nori");
+    /// assert!(!body.is_chunked());
+    /// assert!(body.into_chunked().is_chunked());
+    /// ```
+    pub fn is_chunked(&self) -> bool {
+        self.chunked
+    }
+
+    /// Create a `Body` by decoding a reader framed using chunked
+    /// transfer-encoding.
+    ///
+    /// Chunk extensions are ignored. The body ends after the last chunk, and
+    /// has no length. Any trailers following the last chunk are sent to the
+    /// returned receiver once the body has been read to the end. Reading a
+    /// malformed or truncated stream results in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::Body;
+    /// use async_std::io::Cursor;
+    ///
+    /// let framed = Cursor::new("5\r\nHello\r\n5\r\n Nori\r\n0\r\nExpires: 0\r\n\r\n");
+    /// let (body, trailers) = Body::from_chunked(framed);
+    /// assert_eq!(&body.into_string().await?, "Hello Nori");
+    /// assert_eq!(trailers.await.unwrap()["Expires"], "0");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn from_chunked(reader: impl AsyncBufRead + Unpin + 'static) -> (Self, trailers::Receiver) {
+        let (decoder, trailers) = ChunkedDecoder::new(reader);
+        let body = Self::from_reader(io::BufReader::new(decoder), None);
+        (body, trailers)
+    }
+
+    /// Get the unread contents of the body, if it's held in memory.
+    pub(crate) fn peek(&self) -> Option<&[u8]> {
+        if self.is_empty_known() {
+            return Some(&[]);
+        }
+        let buffer = self.buffer.as_ref()?;
+        Some(&buffer.0[self.bytes_read as usize..])
+    }
+
+    /// Describe where the contents of the body come from, without reading it.
+    fn kind(&self) -> &'static str {
+        match self.peek() {
+            Some(_) => "in-memory",
+            None => "stream",
+        }
+    }
+
+    /// Create a `Body` from an in-memory buffer, which can be cloned later on.
+    fn from_buffer(bytes: Vec<u8>, mime: Mime) -> Self {
+        let buffer = SharedBytes(Arc::new(bytes));
+        Self {
+            mime: Some(mime),
+            length: Some(buffer.0.len() as u64),
+            reader: Box::new(io::Cursor::new(buffer.clone())),
+            bytes_read: 0,
+            buffer: Some(buffer),
+            chunked: false,
+        }
+    }
+}
+
+/// A reader which errors once more than a fixed number of bytes is read.
+#[derive(Debug)]
+struct Bounded {
+    inner: Body,
+    remaining: u64,
+}
+
+impl AsyncRead for Bounded {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.remaining == 0 {
+            // Check whether the body ends exactly at the limit.
+            let mut probe = [0_u8; 1];
+            return match ready!(Pin::new(&mut self.inner).poll_read(cx, &mut probe))? {
+                0 => Poll::Ready(Ok(0)),
+                _ => Poll::Ready(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    LimitExceeded,
+                ))),
+            };
+        }
+
+        let max_len = self.remaining.min(buf.len() as u64) as usize;
+        let bytes = ready!(Pin::new(&mut self.inner).poll_read(cx, &mut buf[..max_len]))?;
+        self.remaining -= bytes as u64;
+        Poll::Ready(Ok(bytes))
+    }
+}
+
+/// A reader which transforms the contents of a body with a closure.
+struct MapBytes<F> {
+    inner: Body,
+    f: F,
+    /// The output of `f` which hasn't been read yet.
+    pending: Vec<u8>,
+    position: usize,
+}
+
+// The closure is never pinned, so `MapBytes` can be moved freely.
+impl<F> Unpin for MapBytes<F> {}
+
+impl<F> AsyncRead for MapBytes<F>
+where
+    F: FnMut(&[u8]) -> Vec<u8>,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        let this = &mut *self;
+        while this.position == this.pending.len() {
+            let bytes = ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+            if bytes == 0 {
+                return Poll::Ready(Ok(0));
+            }
+            this.pending = (this.f)(&buf[..bytes]);
+            this.position = 0;
+        }
+
+        let len = buf.len().min(this.pending.len() - this.position);
+        buf[..len].copy_from_slice(&this.pending[this.position..this.position + len]);
+        this.position += len;
+        Poll::Ready(Ok(len))
+    }
+}
+
+/// The state shared between the two halves of a forked body.
+struct ForkState {
+    source: Body,
+    /// The bytes read from the source which one of the halves hasn't read yet.
+    buffer: VecDeque<u8>,
+    /// The offset in the source of the first byte in the buffer.
+    start: u64,
+    /// The offset in the source each half has read up to, or `None` once the
+    /// half is dropped.
+    positions: [Option<u64>; 2],
+    /// The tasks waiting for the source to be read by the other half.
+    wakers: [Option<Waker>; 2],
+    done: bool,
+    /// The source failed, and can't be read anymore.
+    error: Option<io::Error>,
+}
+
+impl ForkState {
+    /// Drop the bytes which both halves have read.
+    fn trim(&mut self) {
+        let consumed = self.positions.iter().flatten().min();
+        let consumed = consumed.map_or(self.buffer.len(), |pos| (pos - self.start) as usize);
+        self.buffer.drain(..consumed);
+        self.start += consumed as u64;
+    }
+
+    fn wake(&mut self, index: usize) {
+        if let Some(waker) = self.wakers[index].take() {
+            waker.wake();
+        }
+    }
+}
+
+/// One half of a forked body.
+struct Fork {
+    state: Rc<RefCell<ForkState>>,
+    index: usize,
+}
+
+impl AsyncRead for Fork {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut state = self.state.borrow_mut();
+        let state = &mut *state;
+        let other = 1 - self.index;
+        let position = state.positions[self.index].unwrap();
+
+        let offset = (position - state.start) as usize;
+        if offset < state.buffer.len() {
+            // The other half already read ahead.
+            let len = buf.len().min(state.buffer.len() - offset);
+            for (dest, byte) in buf[..len].iter_mut().zip(state.buffer.range(offset..)) {
+                *dest = *byte;
+            }
+            state.positions[self.index] = Some(position + len as u64);
+            state.trim();
+            return Poll::Ready(Ok(len));
+        }
+
+        if let Some(err) = &state.error {
+            return Poll::Ready(Err(copy_error(err)));
+        }
+        if state.done {
+            return Poll::Ready(Ok(0));
+        }
+
+        match Pin::new(&mut state.source).poll_read(cx, buf) {
+            Poll::Pending => {
+                state.wakers[self.index] = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            Poll::Ready(Ok(0)) => state.done = true,
+            Poll::Ready(Ok(len)) => {
+                if state.positions[other].is_some() {
+                    state.buffer.extend(&buf[..len]);
+                } else {
+                    state.start += len as u64;
+                }
+                state.positions[self.index] = Some(position + len as u64);
+                state.wake(other);
+                return Poll::Ready(Ok(len));
+            }
+            Poll::Ready(Err(err)) => {
+                state.error = Some(copy_error(&err));
+                state.wake(other);
+                return Poll::Ready(Err(err));
+            }
+        }
+        state.wake(other);
+        Poll::Ready(Ok(0))
+    }
+}
+
+impl Drop for Fork {
+    fn drop(&mut self) {
+        let mut state = self.state.borrow_mut();
+        state.positions[self.index] = None;
+        state.trim();
+        // The other half may be waiting for this half to read the source.
+        state.wake(1 - self.index);
+    }
+}
+
+/// A reader which errors if a single read takes too long.
+#[cfg(feature = "timer")]
+struct ReadTimeout {
+    inner: Body,
+    timeout: Duration,
+    /// The timer for the read in progress.
+    timer: Option<Pin<Box<dyn Future<Output = ()>>>>,
+}
+
+#[cfg(feature = "timer")]
+impl AsyncRead for ReadTimeout {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if let Poll::Ready(res) = Pin::new(&mut self.inner).poll_read(cx, buf) {
+            self.timer = None;
+            return Poll::Ready(res);
+        }
+
+        let timeout = self.timeout;
+        let timer = self
+            .timer
+            .get_or_insert_with(|| Box::pin(async_std::task::sleep(timeout)));
+        ready!(timer.as_mut().poll(cx));
+        self.timer = None;
+        Poll::Ready(Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            "timed out reading the body",
+        )))
+    }
+}
+
+/// A reader which serializes items as a JSON array, one item at a time.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+struct JsonArray<I> {
+    items: I,
+    /// The serialized output which hasn't been read yet.
+    framed: Vec<u8>,
+    position: usize,
+    started: bool,
+    done: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<I> AsyncRead for JsonArray<I>
+where
+    I: Iterator + Unpin,
+    I::Item: Serialize,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        while this.position == this.framed.len() {
+            if this.done {
+                return Poll::Ready(Ok(0));
+            }
+            this.framed.clear();
+            this.position = 0;
+            match this.items.next() {
+                Some(item) => {
+                    this.framed.push(if this.started { b',' } else { b'[' });
+                    serde_json::to_writer(&mut this.framed, &item)?;
+                }
+                None => {
+                    if !this.started {
+                        this.framed.push(b'[');
+                    }
+                    this.framed.push(b']');
+                    this.done = true;
+                }
+            }
+            this.started = true;
+        }
+
+        let len = buf.len().min(this.framed.len() - this.position);
+        buf[..len].copy_from_slice(&this.framed[this.position..this.position + len]);
+        this.position += len;
+        Poll::Ready(Ok(len))
+    }
+}
+
+/// A reader which endlessly repeats a pattern.
+#[derive(Debug)]
+struct RepeatSlice {
+    pattern: Vec<u8>,
+    position: usize,
+}
+
+impl AsyncRead for RepeatSlice {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if self.pattern.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        let this = &mut *self;
+        for byte in buf.iter_mut() {
+            *byte = this.pattern[this.position];
+            this.position = (this.position + 1) % this.pattern.len();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+}
+
+/// The error returned when reading past the limit of a bounded body.
+#[derive(Debug)]
+struct LimitExceeded;
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("body exceeded the maximum allowed size")
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+/// Copy an error from reading a body, so that it can be returned by both
+/// halves of a forked body. Only the kind and message are kept, except for
+/// `LimitExceeded`, which is needed to pick the status code.
+fn copy_error(err: &io::Error) -> io::Error {
+    match err.get_ref() {
+        Some(inner) if inner.is::<LimitExceeded>() => io::Error::new(err.kind(), LimitExceeded),
+        _ => io::Error::new(err.kind(), err.to_string()),
+    }
+}
+
+/// Convert an error from reading a body into an HTTP error.
+fn read_error(err: io::Error) -> crate::Error {
+    let status = match err.get_ref() {
+        Some(inner) if inner.is::<LimitExceeded>() => StatusCode::PayloadTooLarge,
+        _ if err.kind() == io::ErrorKind::TimedOut => StatusCode::RequestTimeout,
+        _ => StatusCode::UnprocessableEntity,
+    };
+    crate::Error::new(status, err)
+}
+
+/// The contents of an in-memory body, shared between its clones.
+#[derive(Clone)]
+struct SharedBytes(Arc<Vec<u8>>);
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Debug for Body {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Body")
+            .field("reader", &"<hidden>")
+            .field("kind", &self.kind())
+            .field("length", &self.length)
+            .field("bytes_read", &self.bytes_read)
+            .field("chunked", &self.chunked)
+            .finish()
+    }
+}
+
+/// # Panics
+///
+/// Panics if the value can't be serialized. Serializing a `Value` into memory
+/// doesn't fail in practice, but [`Body::try_from_json_value`] can be used to
+/// handle the error instead.
+#[cfg(feature = "serde")]
+impl From<serde_json::Value> for Body {
+    fn from(json_value: serde_json::Value) -> Self {
+        Self::try_from_json_value(json_value).expect("serializing a JSON value failed")
+    }
+}
+
+impl From<String> for Body {
+    fn from(s: String) -> Self {
+        Self::from_string(s)
+    }
+}
+
+impl<'a> From<&'a str> for Body {
+    fn from(s: &'a str) -> Self {
+        Self::from_string(s.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for Body {
+    fn from(b: Vec<u8>) -> Self {
+        Self::from_bytes(b)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Body {
+    fn from(b: &'a [u8]) -> Self {
+        Self::from_bytes(b.to_owned())
+    }
+}
+
+impl AsyncRead for Body {
+    #[allow(rustdoc::missing_doc_code_examples)]
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let buf = match self.length {
+            None => buf,
+            Some(length) if length == self.bytes_read => return Poll::Ready(Ok(0)),
+            Some(length) => {
+                // Compute `min` using u64, then truncate back to usize. Since
+                // buf.len() is a usize, this can never overflow.
+                let max_len = (length - self.bytes_read).min(buf.len() as u64) as usize;
+                &mut buf[0..max_len]
+            }
+        };
+
+        let bytes = ready!(Pin::new(&mut self.reader).poll_read(cx, buf))?;
+        self.bytes_read += bytes as u64;
+        Poll::Ready(Ok(bytes))
+    }
+}
+
+impl AsyncBufRead for Body {
+    #[allow(rustdoc::missing_doc_code_examples)]
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&'_ [u8]>> {
+        self.project().reader.poll_fill_buf(cx)
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        Pin::new(&mut self.reader).consume(amt)
     }
 }
 
@@ -680,6 +1707,394 @@ mod test {
         }
     }
 
+    #[async_std::test]
+    async fn encode_to() -> crate::Result<()> {
+        let body = Body::from_reader(Cursor::new("hello world"), Some(5))
+            .chain(Body::from(" "))
+            .chain(Body::from_reader(Cursor::new("Nori"), None));
+
+        let mut output = vec![];
+        let written = body.encode_to(&mut output).await?;
+        assert_eq!(written, 10);
+        assert_eq!(output, b"hello Nori");
+        Ok(())
+    }
+
+    /// Decode a chunked payload, asserting it is framed correctly.
+    fn dechunk(mut framed: &[u8]) -> Vec<u8> {
+        let mut output = vec![];
+        loop {
+            let line_end = framed.windows(2).position(|w| w == b"\r\n").unwrap();
+            let size = std::str::from_utf8(&framed[..line_end]).unwrap();
+            let size = usize::from_str_radix(size, 16).unwrap();
+            framed = &framed[line_end + 2..];
+            if size == 0 {
+                assert_eq!(framed, b"\r\n");
+                return output;
+            }
+            output.extend_from_slice(&framed[..size]);
+            assert_eq!(&framed[size..size + 2], b"\r\n");
+            framed = &framed[size + 2..];
+        }
+    }
+
+    #[async_std::test]
+    async fn into_chunked() -> crate::Result<()> {
+        let content: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+        let body = Body::from_reader(Cursor::new(content.clone()), None).into_chunked();
+        assert_eq!(body.len(), None);
+        assert!(body.is_chunked());
+
+        let framed = body.into_bytes().await?;
+        assert_eq!(dechunk(&framed), content);
+
+        let framed = Body::empty().into_chunked().into_bytes().await?;
+        assert_eq!(framed, b"0\r\n\r\n");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn map_bytes() -> crate::Result<()> {
+        let body = Body::from("Hello Nori").map_bytes(|bytes| bytes.to_ascii_uppercase());
+        assert_eq!(body.len(), None);
+        assert_eq!(body.mime(), Some(&mime::PLAIN));
+        assert_eq!(&body.into_string().await?, "HELLO NORI");
+
+        // Output larger than the caller's buffer is held until the next read.
+        let body = Body::from("abc").map_bytes(|bytes| bytes.repeat(10_000));
+        assert_eq!(body.into_bytes().await?.len(), 30_000);
+
+        // Empty output doesn't end the body early.
+        let body = Body::from("--").chain(Body::from("a-b-c"));
+        let body = body.map_bytes(|bytes| bytes.iter().copied().filter(|b| *b != b'-').collect());
+        assert_eq!(&body.into_string().await?, "abc");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn try_from_json_value() -> crate::Result<()> {
+        let value = serde_json::json!({
+            "name": "Nori",
+            "toys": [{ "kind": "mouse", "squeaks": true }, null],
+            "age": 3.5,
+        });
+        let body = Body::try_from_json_value(value.clone())?;
+        assert_eq!(body.mime(), Some(&mime::JSON));
+        assert_eq!(body.into_json::<serde_json::Value>().await?, value);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn bytes_eq() -> crate::Result<()> {
+        let mut body = Body::from("Hello Nori");
+        let mut other = Body::from_reader(Cursor::new("Hello Nori"), None);
+        assert!(body.bytes_eq(&mut other).await?);
+        assert_eq!(body.bytes_read, 10);
+        assert_eq!(&other.into_string().await?, "");
+
+        let mut body = Body::from("Hello Nori");
+        assert!(!body.bytes_eq(&mut Body::from("Hello Chashu")).await?);
+
+        // The comparison consumed the body.
+        assert!(body.bytes_eq(&mut Body::empty()).await?);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn fork() -> crate::Result<()> {
+        let content: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+        let body = Body::from_reader(Cursor::new(content.clone()), Some(20_000));
+        let (mut left, mut right) = body.fork();
+        assert_eq!(left.len(), Some(20_000));
+        assert_eq!(right.mime(), Some(&mime::BYTE_STREAM));
+
+        // Read both halves interleaved, at different rates.
+        let (mut left_bytes, mut right_bytes) = (vec![], vec![]);
+        let (mut left_buf, mut right_buf) = ([0; 100], [0; 3_000]);
+        loop {
+            let left_len = left.read(&mut left_buf).await?;
+            left_bytes.extend_from_slice(&left_buf[..left_len]);
+            let right_len = right.read(&mut right_buf).await?;
+            right_bytes.extend_from_slice(&right_buf[..right_len]);
+            if left_len == 0 && right_len == 0 {
+                break;
+            }
+        }
+        assert_eq!(left_bytes, content);
+        assert_eq!(right_bytes, content);
+
+        // Dropping a half doesn't affect the other half.
+        let (left, right) = Body::from("Hello Nori").fork();
+        drop(left);
+        assert_eq!(&right.into_string().await?, "Hello Nori");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn fork_bounded() -> crate::Result<()> {
+        let input = "0123456789abcdefghij";
+        let body = Body::from_reader(Cursor::new(input), None).bounded(10);
+        let (left, right) = body.fork();
+        assert_eq!(left.into_string().await.unwrap_err().status(), 413);
+        assert_eq!(right.into_string().await.unwrap_err().status(), 413);
+
+        let body = Body::from_reader(Cursor::new(input), None).bounded(10);
+        let (left, right) = body.fork();
+        assert_eq!(right.into_bytes().await.unwrap_err().status(), 413);
+        assert_eq!(left.into_bytes().await.unwrap_err().status(), 413);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn fork_concurrent() -> crate::Result<()> {
+        /// A pipe which, like most sources, only wakes the last task to poll it.
+        #[derive(Default)]
+        struct Pipe {
+            chunks: VecDeque<Vec<u8>>,
+            closed: bool,
+            waker: Option<Waker>,
+        }
+
+        struct PipeReader(Rc<RefCell<Pipe>>);
+
+        impl AsyncRead for PipeReader {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                cx: &mut Context<'_>,
+                buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                let mut pipe = self.0.borrow_mut();
+                match pipe.chunks.pop_front() {
+                    Some(chunk) => {
+                        buf[..chunk.len()].copy_from_slice(&chunk);
+                        Poll::Ready(Ok(chunk.len()))
+                    }
+                    None if pipe.closed => Poll::Ready(Ok(0)),
+                    None => {
+                        pipe.waker = Some(cx.waker().clone());
+                        Poll::Pending
+                    }
+                }
+            }
+        }
+
+        /// A waker which records whether it was woken.
+        #[derive(Default)]
+        struct Flag(std::sync::atomic::AtomicBool);
+
+        impl std::task::Wake for Flag {
+            fn wake(self: Arc<Self>) {
+                self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        let pipe = Rc::new(RefCell::new(Pipe::default()));
+        let body = Body::from_async_read(PipeReader(pipe.clone()), None);
+        let (mut left, mut right) = body.fork();
+        let (left_flag, right_flag) = (Arc::new(Flag::default()), Arc::new(Flag::default()));
+        let left_waker = Waker::from(left_flag.clone());
+        let right_waker = Waker::from(right_flag.clone());
+        let mut buf = [0; 64];
+
+        // Both halves wait on the source, which only remembers the right half.
+        let mut cx = Context::from_waker(&left_waker);
+        let poll = Pin::new(&mut left).poll_read(&mut cx, &mut buf);
+        assert!(poll.is_pending());
+        let mut cx = Context::from_waker(&right_waker);
+        let poll = Pin::new(&mut right).poll_read(&mut cx, &mut buf);
+        assert!(poll.is_pending());
+
+        pipe.borrow_mut().chunks.push_back(b"Hello Nori".to_vec());
+        pipe.borrow_mut().waker.take().unwrap().wake();
+        assert!(right_flag.0.load(std::sync::atomic::Ordering::SeqCst));
+
+        // Reading the source on behalf of both halves wakes the left half.
+        let poll = Pin::new(&mut right).poll_read(&mut cx, &mut buf);
+        assert!(matches!(poll, Poll::Ready(Ok(10))));
+        assert!(left_flag.0.load(std::sync::atomic::Ordering::SeqCst));
+
+        pipe.borrow_mut().closed = true;
+        assert_eq!(&left.into_string().await?, "Hello Nori");
+        assert_eq!(&right.into_string().await?, "");
+        Ok(())
+    }
+
+    #[test]
+    fn debug_kind() {
+        let body = Body::from("Hello Nori");
+        let debug = format!("{:?}", body);
+        assert!(debug.contains(r#"kind: "in-memory""#), "{}", debug);
+        assert!(debug.contains("length: Some(10)"), "{}", debug);
+
+        let body = Body::from_reader(Cursor::new("Hello Nori"), None);
+        assert!(format!("{:?}", body).contains(r#"kind: "stream""#));
+    }
+
+    #[async_std::test]
+    async fn from_chunked() -> crate::Result<()> {
+        let framed = "3\r\nabc\r\n10;ext=1\r\n0123456789abcdef\r\n0\r\nX-Sum: 42\r\n\r\n";
+        let (body, trailers) = Body::from_chunked(Cursor::new(framed));
+        assert_eq!(body.len(), None);
+        assert_eq!(&body.into_string().await?, "abc0123456789abcdef");
+        assert_eq!(trailers.await.unwrap()["X-Sum"], "42");
+
+        let content: Vec<u8> = (0..20_000).map(|i| (i % 251) as u8).collect();
+        let body = Body::from_reader(Cursor::new(content.clone()), None).into_chunked();
+        let (body, _) = Body::from_chunked(body);
+        assert_eq!(body.into_bytes().await?, content);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn from_chunked_errors() {
+        let (body, trailers) = Body::from_chunked(Cursor::new("5\r\nHello\r\n5\r\n No"));
+        assert!(body.into_bytes().await.is_err());
+        assert!(trailers.await.is_none());
+
+        let (body, _) = Body::from_chunked(Cursor::new("5\r\nHello\r\n"));
+        assert!(body.into_bytes().await.is_err());
+
+        let (body, _) = Body::from_chunked(Cursor::new("zz\r\nHello\r\n0\r\n\r\n"));
+        assert!(body.into_bytes().await.is_err());
+
+        let (body, _) = Body::from_chunked(Cursor::new("3\r\nHello\r\n0\r\n\r\n"));
+        assert!(body.into_bytes().await.is_err());
+    }
+
+    #[async_std::test]
+    async fn repeat() -> crate::Result<()> {
+        let body = Body::repeat(0x41, 10);
+        assert_eq!(body.len(), Some(10));
+        assert_eq!(body.into_bytes().await?, vec![0x41; 10]);
+
+        let mut body = Body::repeat_slice(b"0123456789".to_vec(), 25);
+        assert_eq!(
+            read_with_buffers_of_size(&mut body, 8).await?,
+            "0123456789012345678901234"
+        );
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn drain() -> crate::Result<()> {
+        let body = Body::from_reader(Cursor::new(vec![0; 150]), Some(100));
+        assert_eq!(body.drain().await?, 100);
+        Ok(())
+    }
+
+    #[cfg(feature = "timer")]
+    #[async_std::test]
+    async fn read_timeout() -> crate::Result<()> {
+        /// A reader which never yields any data.
+        struct Stalled;
+
+        impl AsyncRead for Stalled {
+            fn poll_read(
+                self: Pin<&mut Self>,
+                _cx: &mut Context<'_>,
+                _buf: &mut [u8],
+            ) -> Poll<io::Result<usize>> {
+                Poll::Pending
+            }
+        }
+
+        let timeout = std::time::Duration::from_millis(10);
+        let body = Body::from_async_read(Stalled, None).with_read_timeout(timeout);
+        let err = body.into_bytes().await.unwrap_err();
+        assert_eq!(err.status(), StatusCode::RequestTimeout);
+
+        let body = Body::from_async_read(Cursor::new("Hello"), Some(5)).with_read_timeout(timeout);
+        assert_eq!(body.len(), Some(5));
+        assert_eq!(&body.into_string().await?, "Hello");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn from_json_array() -> crate::Result<()> {
+        let cats = vec![
+            ("Nori".to_string(), 3),
+            ("Chashu".to_string(), 5),
+            ("Mochi".to_string(), 1),
+        ];
+        let body = Body::from_json_array(cats.clone());
+        assert_eq!(body.len(), None);
+        assert_eq!(body.mime(), Some(&mime::JSON));
+        let parsed: Vec<(String, u8)> = serde_json::from_slice(&body.into_bytes().await?)?;
+        assert_eq!(parsed, cats);
+
+        let body = Body::from_json_array(Vec::<u8>::new());
+        assert_eq!(&body.into_string().await?, "[]");
+
+        // JSON objects can't have non-string keys.
+        let invalid = std::iter::once(std::collections::BTreeMap::from([((1, 2), 3)]));
+        let mut body = Body::from_json_array(invalid);
+        let err = body.read_to_end(&mut vec![]).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn lines() -> crate::Result<()> {
+        let body = Body::from("first\r\nsecond\nthird");
+        let lines: Vec<String> = body.lines().try_collect().await?;
+        assert_eq!(lines, ["first", "second", "third"]);
+
+        let body = Body::from_reader(Cursor::new("one\ntwo\nthree"), Some(7));
+        let lines: Vec<String> = body.lines().try_collect().await?;
+        assert_eq!(lines, ["one", "two"]);
+
+        let body = Body::from(vec![b'a', b'\n', 0xff, b'\n']);
+        let mut lines = Box::pin(body.lines());
+        assert_eq!(lines.next().await.unwrap()?, "a");
+        let err = lines.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn from_reader_sniffed() -> crate::Result<()> {
+        let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png.extend_from_slice(&[0; 1024]);
+
+        // Read the bytes one at a time, like a slow socket would.
+        let reader = io::BufReader::with_capacity(1, Cursor::new(png.clone()));
+        let body = Body::from_reader_sniffed(reader, None).await?;
+        assert_eq!(body.mime(), Some(&mime::PNG));
+        assert_eq!(body.len(), None);
+        assert_eq!(body.into_bytes().await?, png);
+
+        let body = Body::from_reader_sniffed(Cursor::new("hello world"), Some(5)).await?;
+        assert_eq!(body.mime(), Some(&mime::BYTE_STREAM));
+        assert_eq!(body.into_string().await?, "hello");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn bounded() -> crate::Result<()> {
+        let input = "0123456789abcdefghij";
+        for buf_len in 1..13 {
+            let mut body = Body::from_reader(Cursor::new(input), None).bounded(10);
+            let mut output = vec![];
+            let err = loop {
+                let mut buf = vec![0; buf_len];
+                match body.read(&mut buf).await {
+                    Ok(0) => panic!("bounded body ended without an error"),
+                    Ok(bytes_read) => output.extend_from_slice(&buf[..bytes_read]),
+                    Err(err) => break err,
+                }
+            };
+            assert_eq!(output, b"0123456789");
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+
+        let body = Body::from_reader(Cursor::new(input), Some(20)).bounded(10);
+        assert_eq!(body.into_string().await.unwrap_err().status(), 413);
+
+        let body = Body::from_reader(Cursor::new(input), None).bounded(20);
+        assert_eq!(body.into_string().await?, input);
+        Ok(())
+    }
+
     #[async_std::test]
     async fn attempting_to_read_past_length() -> crate::Result<()> {
         for buf_len in 1..13 {
@@ -879,4 +2294,41 @@ mod test {
 
         Ok(())
     }
+
+    #[async_std::test]
+    async fn try_clone_in_memory() -> crate::Result<()> {
+        let body = Body::from_json(&serde_json::json!({ "name": "Chashu" }))?;
+        let clone = body.try_clone().unwrap();
+        assert_eq!(clone.len(), body.len());
+        assert_eq!(clone.mime(), Some(&mime::JSON));
+        assert_eq!(body.into_string().await?, r#"{"name":"Chashu"}"#);
+        assert_eq!(clone.into_string().await?, r#"{"name":"Chashu"}"#);
+
+        let mut body = Body::from_string("hello world".to_string());
+        let mut buf = vec![0; 5];
+        body.read(&mut buf).await?;
+        assert!(body.try_clone().is_none());
+
+        let body = Body::from_reader(Cursor::new("hello world"), Some(11));
+        assert!(body.try_clone().is_none());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn empty_never_yields_bytes() -> crate::Result<()> {
+        let mut buf = vec![0; 16];
+        for _ in 0..10_000 {
+            let mut body = Body::empty();
+            assert!(body.is_empty_known());
+            assert_eq!(body.read(&mut buf).await?, 0);
+            assert!(body.fill_buf().await?.is_empty());
+        }
+
+        let body = Body::empty();
+        let clone = body.try_clone().unwrap();
+        assert_eq!(clone.mime(), Some(&mime::BYTE_STREAM));
+        assert_eq!(body.into_bytes().await?, b"");
+        assert_eq!(clone.into_bytes().await?, b"");
+        Ok(())
+    }
 }
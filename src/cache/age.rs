@@ -1,10 +1,13 @@
+use crate::ensure_status as ensure;
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, AGE};
-use crate::Status;
 
 use std::fmt::Debug;
 
 use std::time::Duration;
 
+/// The largest delta-seconds value a cache must be able to represent.
+const MAX_DELTA_SECONDS: u64 = 2_147_483_648;
+
 /// HTTP `Age` header
 ///
 /// # Specifications
@@ -51,7 +54,14 @@ impl Age {
         self.dur
     }
 
+    /// Sets the `Age` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// Create an instance of `Age` from a `Headers` instance.
+    ///
+    /// Values too large to be represented are clamped to 2^31 seconds.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let headers = match headers.as_ref().get(AGE) {
             Some(headers) => headers,
@@ -62,8 +72,16 @@ impl Age {
         // entry. We want the last entry.
         let header = headers.iter().last().unwrap();
 
-        let num: u64 = header.as_str().parse().status(400)?;
-        let dur = Duration::from_secs_f64(num as f64);
+        let s = header.as_str();
+        ensure!(
+            !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()),
+            400,
+            "Invalid Age header"
+        );
+
+        // Overflowing values are clamped, as recommended by RFC 7234, section 1.2.1.
+        let secs = s.parse::<u64>().unwrap_or(MAX_DELTA_SECONDS);
+        let dur = Duration::from_secs(secs.min(MAX_DELTA_SECONDS));
 
         Ok(Some(Self { dur }))
     }
@@ -99,11 +117,40 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn zero() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(AGE, "0").unwrap();
+        let age = Age::from_headers(headers)?.unwrap();
+        assert_eq!(age.duration(), Duration::from_secs(0));
+        Ok(())
+    }
+
+    #[test]
+    fn clamp_overflow() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers
+            .insert(AGE, "99999999999999999999999999999999")
+            .unwrap();
+        let age = Age::from_headers(headers)?.unwrap();
+        assert_eq!(age.duration(), Duration::from_secs(2_147_483_648));
+
+        let mut headers = Headers::new();
+        age.apply(&mut headers);
+        assert_eq!(headers[AGE], "2147483648");
+        Ok(())
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
         headers.insert(AGE, "<nori ate the tag. yum.>").unwrap();
         let err = Age::from_headers(headers).unwrap_err();
         assert_eq!(err.status(), 400);
+
+        let mut headers = Headers::new();
+        headers.insert(AGE, "-12").unwrap();
+        let err = Age::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
     }
 }
@@ -1,10 +1,12 @@
 use headers::Header;
 
 use crate::headers::{HeaderName, HeaderValue, Headers, CACHE_CONTROL};
+use crate::parse_utils::split_list;
 use crate::{cache::CacheDirective, headers};
 
 use std::fmt::{self, Debug, Write};
 use std::iter::Iterator;
+use std::time::Duration;
 
 use std::slice;
 
@@ -31,6 +33,22 @@ use std::slice;
 /// #
 /// # Ok(()) }
 /// ```
+///
+/// Common policies can also be built up using the builder methods:
+///
+/// ```
+/// use http_types::Response;
+/// use http_types::cache::CacheControl;
+/// use std::time::Duration;
+///
+/// let mut res = Response::new(200);
+/// CacheControl::new()
+///     .public()
+///     .max_age(Duration::from_secs(3600))
+///     .apply(&mut res);
+///
+/// assert_eq!(res["Cache-Control"], "public, max-age=3600");
+/// ```
 pub struct CacheControl {
     entries: Vec<CacheDirective>,
 }
@@ -50,9 +68,9 @@ impl CacheControl {
         };
 
         for value in headers {
-            for part in value.as_str().trim().split(',') {
-                // Try and parse a directive from a str. If the directive is
-                // unkown we skip it.
+            for part in split_list(value.as_str().trim()) {
+                // Try and parse a directive from a str. Unknown directives
+                // are kept as `CacheDirective::Extension`.
                 if let Some(entry) = CacheDirective::from_str(part)? {
                     entries.push(entry);
                 }
@@ -61,11 +79,59 @@ impl CacheControl {
 
         Ok(Some(Self { entries }))
     }
+
+    /// Sets the `Cache-Control` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// Push a directive into the list of entries.
     pub fn push(&mut self, directive: CacheDirective) {
         self.entries.push(directive);
     }
 
+    /// Add the `max-age` directive. The duration is truncated to whole seconds.
+    pub fn max_age(&mut self, dur: Duration) -> &mut Self {
+        self.push(CacheDirective::MaxAge(dur));
+        self
+    }
+
+    /// Add the `s-maxage` directive. The duration is truncated to whole seconds.
+    pub fn s_maxage(&mut self, dur: Duration) -> &mut Self {
+        self.push(CacheDirective::SMaxAge(dur));
+        self
+    }
+
+    /// Add the `no-store` directive.
+    pub fn no_store(&mut self) -> &mut Self {
+        self.push(CacheDirective::NoStore);
+        self
+    }
+
+    /// Add the `no-cache` directive.
+    pub fn no_cache(&mut self) -> &mut Self {
+        self.push(CacheDirective::NoCache);
+        self
+    }
+
+    /// Add the `public` directive.
+    pub fn public(&mut self) -> &mut Self {
+        self.push(CacheDirective::Public);
+        self
+    }
+
+    /// Add the `private` directive.
+    pub fn private(&mut self) -> &mut Self {
+        self.push(CacheDirective::Private);
+        self
+    }
+
+    /// Add the `immutable` directive.
+    pub fn immutable(&mut self) -> &mut Self {
+        self.push(CacheDirective::Immutable);
+        self
+    }
+
     /// An iterator visiting all server entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -1,4 +1,6 @@
+use crate::bail_status as bail;
 use crate::headers::HeaderValue;
+use crate::parse_utils::{is_quotable, parse_quoted_string, parse_token};
 use crate::Status;
 
 use std::time::Duration;
@@ -41,6 +43,11 @@ pub enum CacheDirective {
     /// Indicates the client will accept a stale response, while asynchronously
     /// checking in the background for a fresh one.
     StaleWhileRevalidate(Duration),
+    /// A directive not known to this crate, with an optional argument.
+    ///
+    /// Unknown directives are preserved when parsing so they can be re-emitted
+    /// unchanged.
+    Extension(CacheExtension),
 }
 
 impl CacheDirective {
@@ -49,7 +56,14 @@ impl CacheDirective {
         use CacheDirective::*;
         matches!(
             self,
-            MaxAge(_) | MaxStale(_) | MinFresh(_) | NoCache | NoStore | NoTransform | OnlyIfCached
+            MaxAge(_)
+                | MaxStale(_)
+                | MinFresh(_)
+                | NoCache
+                | NoStore
+                | NoTransform
+                | OnlyIfCached
+                | Extension(_)
         )
     }
 
@@ -69,6 +83,7 @@ impl CacheDirective {
                 | SMaxAge(_)
                 | StaleIfError(_)
                 | StaleWhileRevalidate(_)
+                | Extension(_)
         )
     }
 
@@ -88,18 +103,18 @@ impl CacheDirective {
             return Ok(None);
         }
 
-        let s = s.to_lowercase();
-        let mut parts = s.split('=');
-        let next = parts.next().unwrap();
+        // This won't panic because each input string has at least one part.
+        let mut parts = s.splitn(2, '=');
+        let name = parts.next().unwrap().trim().to_lowercase();
+        let mut parts = parts.next().map(|arg| arg.trim());
 
         let mut get_dur = || -> crate::Result<Duration> {
-            let dur = parts.next().status(400)?;
+            let dur = parts.take().status(400)?;
             let dur: u64 = dur.parse().status(400)?;
             Ok(Duration::new(dur, 0))
         };
 
-        // This won't panic because each input string has at least one part.
-        let res = match next {
+        let res = match name.as_str() {
             "immutable" => Some(Immutable),
             "no-cache" => Some(NoCache),
             "no-store" => Some(NoStore),
@@ -110,7 +125,7 @@ impl CacheDirective {
             "private" => Some(Private),
             "proxy-revalidate" => Some(ProxyRevalidate),
             "max-age" => Some(MaxAge(get_dur()?)),
-            "max-stale" => match parts.next() {
+            "max-stale" => match parts.take() {
                 Some(secs) => {
                     let dur: u64 = secs.parse().status(400)?;
                     Some(MaxStale(Some(Duration::new(dur, 0))))
@@ -121,7 +136,7 @@ impl CacheDirective {
             "s-maxage" => Some(SMaxAge(get_dur()?)),
             "stale-if-error" => Some(StaleIfError(get_dur()?)),
             "stale-while-revalidate" => Some(StaleWhileRevalidate(get_dur()?)),
-            _ => None,
+            _ => Some(Extension(CacheExtension::new(&name, parts.take())?)),
         };
         Ok(res)
     }
@@ -148,9 +163,83 @@ impl From<CacheDirective> for HeaderValue {
             Private => h("private".to_string()),
             ProxyRevalidate => h("proxy-revalidate".to_string()),
             Public => h("public".to_string()),
-            SMaxAge(dur) => h(format!("s-max-age={}", dur.as_secs())),
+            SMaxAge(dur) => h(format!("s-maxage={}", dur.as_secs())),
             StaleIfError(dur) => h(format!("stale-if-error={}", dur.as_secs())),
             StaleWhileRevalidate(dur) => h(format!("stale-while-revalidate={}", dur.as_secs())),
+            Extension(ext) => match ext.value {
+                Some(value) => h(format!("{}={}", ext.name, value)),
+                None => h(ext.name),
+            },
+        }
+    }
+}
+
+/// A `Cache-Control` directive not known to this crate.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::{CacheControl, CacheDirective, CacheExtension};
+/// use http_types::Response;
+///
+/// let mut entries = CacheControl::new();
+/// entries.push(CacheDirective::Extension(CacheExtension::new(
+///     "stale-ok",
+///     Some("30"),
+/// )?));
+///
+/// let mut res = Response::new(200);
+/// entries.apply(&mut res);
+/// assert_eq!(res["Cache-Control"], "stale-ok=30");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheExtension {
+    name: String,
+    value: Option<String>,
+}
+
+impl CacheExtension {
+    /// Create a new directive, with an optional argument.
+    ///
+    /// The name is converted to lowercase. The argument is kept as-is, and
+    /// must be either a token or a quoted-string including its quotes.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the name isn't
+    /// a valid token, or the argument isn't a valid token or quoted-string.
+    pub fn new(name: &str, value: Option<&str>) -> crate::Result<Self> {
+        if !matches!(parse_token(name), (Some(_), "")) {
+            bail!(400, "invalid cache directive `{}`", name.escape_debug());
+        }
+        if let Some(value) = value {
+            let is_token = matches!(parse_token(value), (Some(_), ""));
+            let is_quoted = matches!(parse_quoted_string(value), (Some(_), ""));
+            if !is_quotable(value) || !(is_token || is_quoted) {
+                bail!(
+                    400,
+                    "invalid cache directive argument `{}`",
+                    value.escape_debug()
+                );
+            }
         }
+        Ok(Self {
+            name: name.to_ascii_lowercase(),
+            value: value.map(ToOwned::to_owned),
+        })
+    }
+
+    /// Get the name of the directive.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the argument of the directive, if any.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
     }
 }
@@ -11,12 +11,13 @@ mod cache_control;
 mod cache_directive;
 
 pub use cache_control::CacheControl;
-pub use cache_directive::CacheDirective;
+pub use cache_directive::{CacheDirective, CacheExtension};
 
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::headers::{Header, Headers, CACHE_CONTROL};
+    use std::time::Duration;
 
     #[test]
     fn smoke() -> crate::Result<()> {
@@ -35,15 +36,101 @@ mod test {
     }
 
     #[test]
-    fn ignore_unkonwn_directives() -> crate::Result<()> {
+    fn preserve_unknown_directives() -> crate::Result<()> {
         let mut headers = Headers::new();
-        headers.insert(CACHE_CONTROL, "barrel_roll").unwrap();
-        let entries = CacheControl::from_headers(headers)?.unwrap();
-        let mut entries = entries.iter();
-        assert!(entries.next().is_none());
+        headers
+            .insert(CACHE_CONTROL, "barrel_roll, stale-ok=30")
+            .unwrap();
+        let entries = CacheControl::from_headers(&headers)?.unwrap();
+        let mut iter = entries.iter();
+        assert_eq!(
+            iter.next().unwrap(),
+            &CacheDirective::Extension(CacheExtension::new("barrel_roll", None)?)
+        );
+        assert_eq!(
+            iter.next().unwrap(),
+            &CacheDirective::Extension(CacheExtension::new("stale-ok", Some("30"))?)
+        );
+        assert!(iter.next().is_none());
+
+        let mut headers = Headers::new();
+        entries.apply(&mut headers);
+        assert_eq!(headers[CACHE_CONTROL], "barrel_roll, stale-ok=30");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_and_round_trip() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers
+            .insert(CACHE_CONTROL, "max-age=60, must-revalidate")
+            .unwrap();
+        let entries = CacheControl::from_headers(&headers)?.unwrap();
+        let mut iter = entries.iter();
+        assert_eq!(
+            iter.next().unwrap(),
+            &CacheDirective::MaxAge(Duration::from_secs(60))
+        );
+        assert_eq!(iter.next().unwrap(), &CacheDirective::MustRevalidate);
+        assert!(iter.next().is_none());
+
+        let mut headers = Headers::new();
+        entries.apply(&mut headers);
+        assert_eq!(headers[CACHE_CONTROL], "max-age=60, must-revalidate");
         Ok(())
     }
 
+    #[test]
+    fn builder() {
+        let mut headers = Headers::new();
+        CacheControl::new()
+            .private()
+            .no_cache()
+            .max_age(Duration::from_millis(1500))
+            .s_maxage(Duration::from_secs(30))
+            .apply(&mut headers);
+        assert_eq!(
+            headers[CACHE_CONTROL],
+            "private, no-cache, max-age=1, s-maxage=30"
+        );
+    }
+
+    #[test]
+    fn quoted_field_names() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(
+            CACHE_CONTROL,
+            r#"no-cache="Set-Cookie, Foo", private="a, b", community="UCI, x""#,
+        )?;
+        let entries = CacheControl::from_headers(&headers)?.unwrap();
+        let mut iter = entries.iter();
+        assert_eq!(iter.next().unwrap(), &CacheDirective::NoCache);
+        assert_eq!(iter.next().unwrap(), &CacheDirective::Private);
+        let ext = CacheExtension::new("community", Some(r#""UCI, x""#))?;
+        assert_eq!(iter.next().unwrap(), &CacheDirective::Extension(ext));
+        assert!(iter.next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_extensions() {
+        for (name, value) in &[
+            ("barrel roll", None),
+            ("", None),
+            ("stale-ok", Some("a b")),
+            ("stale-ok", Some("30\r\nSet-Cookie: evil=1")),
+            ("stale-ok", Some("\"unterminated")),
+        ] {
+            let err = CacheExtension::new(name, *value).unwrap_err();
+            assert_eq!(err.status(), 400, "{} {:?}", name, value);
+        }
+
+        let mut headers = Headers::new();
+        headers.insert(CACHE_CONTROL, "stale-ok=a b").unwrap();
+        let err = CacheControl::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
@@ -2,7 +2,7 @@ use crate::headers::{Header, HeaderName, HeaderValue, Headers, EXPIRES};
 use crate::utils::{fmt_http_date, parse_http_date};
 
 use std::fmt::Debug;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// HTTP `Expires` header
 ///
@@ -55,7 +55,22 @@ impl Expires {
         self.instant
     }
 
+    /// Get the expiration time.
+    ///
+    /// This is an alias for [`Expires::expiration`].
+    pub fn time(&self) -> SystemTime {
+        self.instant
+    }
+
+    /// Sets the `Expires` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// Create an instance of `Expires` from a `Headers` instance.
+    ///
+    /// An invalid date, such as `0`, represents a time in the past and is
+    /// parsed as the unix epoch.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let headers = match headers.as_ref().get(EXPIRES) {
             Some(headers) => headers,
@@ -66,7 +81,8 @@ impl Expires {
         // entry. We want the last entry.
         let header = headers.iter().last().unwrap();
 
-        let instant = parse_http_date(header.as_str())?;
+        // A cache recipient must interpret invalid dates as "already expired".
+        let instant = parse_http_date(header.as_str()).unwrap_or(UNIX_EPOCH);
         Ok(Some(Self { instant }))
     }
 }
@@ -105,10 +121,31 @@ mod test {
     }
 
     #[test]
-    fn bad_request_on_parse_error() {
+    fn invalid_date_is_expired() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(EXPIRES, "0").unwrap();
+        let expires = Expires::from_headers(&headers)?.unwrap();
+        assert_eq!(expires.time(), UNIX_EPOCH);
+        assert!(expires.time() < SystemTime::now());
+
         let mut headers = Headers::new();
         headers.insert(EXPIRES, "<nori ate the tag. yum.>").unwrap();
-        let err = Expires::from_headers(headers).unwrap_err();
-        assert_eq!(err.status(), 400);
+        let expires = Expires::from_headers(&headers)?.unwrap();
+        assert_eq!(expires.time(), UNIX_EPOCH);
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_formats() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers
+            .insert(EXPIRES, "Sunday, 06-Nov-94 08:49:37 GMT")
+            .unwrap();
+        let expires = Expires::from_headers(&headers)?.unwrap();
+
+        let mut headers = Headers::new();
+        expires.apply(&mut headers);
+        assert_eq!(headers[EXPIRES], "Sun, 06 Nov 1994 08:49:37 GMT");
+        Ok(())
     }
 }
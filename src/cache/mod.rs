@@ -12,9 +12,14 @@ mod age;
 mod cache_control;
 mod clear_site_data;
 mod expires;
+mod warning;
 
 pub use age::Age;
 pub use cache_control::CacheControl;
-pub use cache_control::CacheDirective;
+pub use cache_control::{CacheDirective, CacheExtension};
 pub use clear_site_data::{ClearDirective, ClearSiteData};
 pub use expires::Expires;
+pub use warning::{Warning, WarningValue};
+
+#[doc(inline)]
+pub use crate::conditional::Vary;
@@ -0,0 +1,366 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, WARNING};
+use crate::parse_utils::{format_quoted_string, is_quotable, parse_list, parse_quoted_string};
+use crate::utils::{fmt_http_date, parse_http_date};
+
+use std::fmt::{self, Debug, Write};
+use std::iter::Iterator;
+use std::slice;
+use std::time::SystemTime;
+
+/// Additional information about the status of a message, such as a cached
+/// response being stale.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Warning)
+///
+/// # Specifications
+///
+/// - [RFC 7234, section 5.5: Warning](https://tools.ietf.org/html/rfc7234#section-5.5)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cache::{Warning, WarningValue};
+/// use http_types::Response;
+///
+/// let mut warning = Warning::new();
+/// warning.push(WarningValue::new(110, "-", "Response is Stale")?);
+///
+/// let mut res = Response::new(200);
+/// warning.apply(&mut res);
+/// assert_eq!(res["Warning"], r#"110 - "Response is Stale""#);
+///
+/// let warning = Warning::from_headers(res)?.unwrap();
+/// assert_eq!(warning.iter().next().unwrap().code(), 110);
+/// #
+/// # Ok(()) }
+/// ```
+pub struct Warning {
+    warnings: Vec<WarningValue>,
+}
+
+impl Warning {
+    /// Create a new instance of `Warning`.
+    pub fn new() -> Self {
+        Self { warnings: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if a warning is
+    /// malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(WARNING) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut warnings = vec![];
+        for value in headers {
+            warnings.extend(parse_warnings(value.as_str())?);
+        }
+        Ok(Some(Self { warnings }))
+    }
+
+    /// Sets the `Warning` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Push a warning into the list of warnings.
+    pub fn push(&mut self, warning: WarningValue) {
+        self.warnings.push(warning);
+    }
+
+    /// An iterator visiting all warnings.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.warnings.iter(),
+        }
+    }
+}
+
+impl Header for Warning {
+    fn header_name(&self) -> HeaderName {
+        WARNING
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, warning) in self.warnings.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", warning).unwrap(),
+                _ => write!(output, ", {}", warning).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Warning {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Warning {
+    type Item = &'a WarningValue;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowing iterator over entries in `Warning`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, WarningValue>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a WarningValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for warning in &self.warnings {
+            list.entry(warning);
+        }
+        list.finish()
+    }
+}
+
+/// A single warning in the [`Warning`] header, made up of a warn-code, the
+/// agent which added the warning, a description, and an optional date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarningValue {
+    code: u16,
+    agent: String,
+    text: String,
+    date: Option<SystemTime>,
+}
+
+impl WarningValue {
+    /// Create a new warning.
+    ///
+    /// The agent is the host which added the warning, or `-` if it's unknown.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the code doesn't
+    /// have three digits, if the agent is empty or contains whitespace, or if
+    /// the text contains characters other than printable ASCII.
+    pub fn new(
+        code: u16,
+        agent: impl Into<String>,
+        text: impl Into<String>,
+    ) -> crate::Result<Self> {
+        let agent = agent.into();
+        let text = text.into();
+        if !(100..=999).contains(&code) {
+            bail!(400, "invalid warn-code {}", code);
+        }
+        if !is_warn_agent(&agent) {
+            bail!(400, "invalid warn-agent {:?}", agent);
+        }
+        if !is_quotable(&text) {
+            bail!(400, "invalid warn-text {:?}", text);
+        }
+        Ok(Self {
+            code,
+            agent,
+            text,
+            date: None,
+        })
+    }
+
+    /// Set the date at which the warning was added.
+    ///
+    /// HTTP dates only have second-precision.
+    pub fn with_date(mut self, date: SystemTime) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    /// Get the warn-code, such as `110` for a stale response.
+    pub fn code(&self) -> u16 {
+        self.code
+    }
+
+    /// Get the agent which added the warning.
+    pub fn agent(&self) -> &str {
+        &self.agent
+    }
+
+    /// Get the description of the warning.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the date at which the warning was added, if any.
+    pub fn date(&self) -> Option<SystemTime> {
+        self.date
+    }
+}
+
+impl fmt::Display for WarningValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.code,
+            self.agent,
+            format_quoted_string(&self.text)
+        )?;
+        if let Some(date) = self.date {
+            write!(f, " \"{}\"", fmt_http_date(date))?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that a string is a host, optionally with a port, or a pseudonym.
+fn is_warn_agent(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_graphic() && !matches!(b, b'"' | b','))
+}
+
+/// Parse a comma-separated list of warnings.
+fn parse_warnings(input: &str) -> crate::Result<Vec<WarningValue>> {
+    parse_list(input, "warning", |input| {
+        let (code, rest) = match input.split_once(' ') {
+            Some((code, rest)) if code.len() == 3 && code.bytes().all(|b| b.is_ascii_digit()) => {
+                (code.parse().unwrap(), rest)
+            }
+            _ => bail!(400, "invalid warn-code"),
+        };
+        let (agent, rest) = match rest.split_once(' ') {
+            Some((agent, rest)) if is_warn_agent(agent) => (agent.to_owned(), rest),
+            _ => bail!(400, "invalid warn-agent"),
+        };
+        let (text, rest) = match parse_quoted_string(rest) {
+            (Some(text), rest) if is_quotable(&text) => (text.into_owned(), rest),
+            _ => bail!(400, "invalid warn-text"),
+        };
+        let mut warning = WarningValue {
+            code,
+            agent,
+            text,
+            date: None,
+        };
+
+        let rest = match rest.strip_prefix(" \"").and_then(|s| s.split_once('"')) {
+            Some((date, rest)) => {
+                warning.date = Some(parse_http_date(date)?);
+                rest
+            }
+            None => rest,
+        };
+        Ok((warning, rest))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    use std::time::Duration;
+
+    #[test]
+    fn round_trip_stale_warning() -> crate::Result<()> {
+        let date = SystemTime::UNIX_EPOCH + Duration::from_secs(784_111_777);
+        let mut warning = Warning::new();
+        warning.push(
+            WarningValue::new(110, "cache.example.com:8080", "Response is Stale")?.with_date(date),
+        );
+
+        let mut headers = Headers::new();
+        warning.apply(&mut headers);
+        assert_eq!(
+            headers[WARNING],
+            r#"110 cache.example.com:8080 "Response is Stale" "Sun, 06 Nov 1994 08:49:37 GMT""#
+        );
+
+        let parsed = Warning::from_headers(headers)?.unwrap();
+        let stale = parsed.iter().next().unwrap();
+        assert_eq!(stale.code(), 110);
+        assert_eq!(stale.agent(), "cache.example.com:8080");
+        assert_eq!(stale.text(), "Response is Stale");
+        assert_eq!(stale.date(), Some(date));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_warnings() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(WARNING, r#"110 - "Stale, very", 112 - "Disconnected""#)?;
+        headers.append(
+            WARNING,
+            r#"299 proxy "Miscellaneous \"persistent\" warning""#,
+        )?;
+
+        let warning = Warning::from_headers(headers)?.unwrap();
+        let parsed: Vec<_> = warning.iter().map(|w| (w.code(), w.text())).collect();
+        assert_eq!(
+            parsed,
+            [
+                (110, "Stale, very"),
+                (112, "Disconnected"),
+                (299, r#"Miscellaneous "persistent" warning"#),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_warnings() {
+        for (code, agent, text) in &[
+            (99, "-", "Too short"),
+            (1000, "-", "Too long"),
+            (110, "", "Empty agent"),
+            (110, "a b", "Agent with whitespace"),
+            (110, "-", "a\r\nSet-Cookie: evil=1"),
+            (110, "-", "Café"),
+        ] {
+            let err = WarningValue::new(*code, *agent, *text).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", text);
+        }
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() -> crate::Result<()> {
+        for value in &[
+            "110",
+            r#"11 - "Short code""#,
+            r#"110 - Unquoted"#,
+            r#"110 - "Stale" "not a date""#,
+            r#"110 - "Stale" extra"#,
+        ] {
+            let mut headers = Headers::new();
+            headers.insert(WARNING, *value)?;
+            let err = Warning::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+        Ok(())
+    }
+}
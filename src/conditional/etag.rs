@@ -66,6 +66,46 @@ impl ETag {
         Self::from_str(s).map(Some)
     }
 
+    /// Sets the `ETag` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Get the opaque tag, without the quotes or weakness indicator.
+    pub fn value(&self) -> &str {
+        match self {
+            Self::Strong(s) | Self::Weak(s) => s,
+        }
+    }
+
+    /// Compare two ETags using the strong comparison function.
+    ///
+    /// Two ETags are equivalent if both are strong and their opaque tags
+    /// match character-by-character.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7232, section 2.3.2: Comparison](https://tools.ietf.org/html/rfc7232#section-2.3.2)
+    pub fn strong_eq(&self, other: &ETag) -> bool {
+        match (self, other) {
+            (Self::Strong(left), Self::Strong(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    /// Compare two ETags using the weak comparison function.
+    ///
+    /// Two ETags are equivalent if their opaque tags match
+    /// character-by-character, regardless of either or both being tagged as
+    /// weak.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7232, section 2.3.2: Comparison](https://tools.ietf.org/html/rfc7232#section-2.3.2)
+    pub fn weak_eq(&self, other: &ETag) -> bool {
+        self.value() == other.value()
+    }
+
     /// Returns `true` if the ETag is a `Strong` value.
     pub fn is_strong(&self) -> bool {
         matches!(self, Self::Strong(_))
@@ -154,13 +194,34 @@ mod test {
         let etag = ETag::new_weak("0xcafebeef".to_string());
 
         let mut headers = Headers::new();
-        etag.apply_header(&mut headers);
+        etag.apply(&mut headers);
+        assert_eq!(headers[ETAG], r#"W/"0xcafebeef""#);
 
         let etag = ETag::from_headers(headers)?.unwrap();
         assert_eq!(etag, ETag::Weak(String::from("0xcafebeef")));
         Ok(())
     }
 
+    #[test]
+    fn comparison() {
+        // Examples from RFC 7232, section 2.3.2.
+        let weak_1 = ETag::new_weak("1".to_string());
+        let weak_2 = ETag::new_weak("2".to_string());
+        let strong_1 = ETag::new("1".to_string());
+
+        assert!(!weak_1.strong_eq(&weak_1));
+        assert!(weak_1.weak_eq(&weak_1));
+
+        assert!(!weak_1.strong_eq(&weak_2));
+        assert!(!weak_1.weak_eq(&weak_2));
+
+        assert!(!weak_1.strong_eq(&strong_1));
+        assert!(weak_1.weak_eq(&strong_1));
+
+        assert!(strong_1.strong_eq(&strong_1));
+        assert!(strong_1.weak_eq(&strong_1));
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
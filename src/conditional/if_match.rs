@@ -88,6 +88,18 @@ impl IfMatch {
         self.wildcard = wildcard
     }
 
+    /// Returns `true` if the given ETag matches any of the entries.
+    ///
+    /// A wildcard matches any current representation of the resource.
+    /// Entries are compared using the strong comparison function.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7232, section 3.1](https://tools.ietf.org/html/rfc7232#section-3.1)
+    pub fn matches(&self, etag: &ETag) -> bool {
+        self.wildcard || self.entries.iter().any(|entry| entry.strong_eq(etag))
+    }
+
     /// An iterator visiting all server entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -273,4 +285,20 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn matches_strong() {
+        // If-Match uses the strong comparison function.
+        let mut entries = IfMatch::new();
+        entries.push(ETag::new("xyzzy".to_string()));
+        entries.push(ETag::new_weak("r2d2xxxx".to_string()));
+
+        assert!(entries.matches(&ETag::new("xyzzy".to_string())));
+        assert!(!entries.matches(&ETag::new_weak("xyzzy".to_string())));
+        assert!(!entries.matches(&ETag::new_weak("r2d2xxxx".to_string())));
+        assert!(!entries.matches(&ETag::new("c3piozzzz".to_string())));
+
+        entries.set_wildcard(true);
+        assert!(entries.matches(&ETag::new("c3piozzzz".to_string())));
+    }
 }
@@ -3,7 +3,7 @@ use crate::utils::{fmt_http_date, parse_http_date};
 
 use std::fmt::Debug;
 
-use std::time::SystemTime;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Apply the HTTP method if the entity has been modified after the given
 /// date.
@@ -51,7 +51,38 @@ impl IfModifiedSince {
         self.instant
     }
 
+    /// Returns `true` if a resource last modified at the given time has been
+    /// modified since the date listed in this header.
+    ///
+    /// HTTP dates only have second-precision, so both times are truncated to
+    /// whole seconds before being compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::conditional::IfModifiedSince;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let time = SystemTime::now();
+    /// let header = IfModifiedSince::new(time);
+    ///
+    /// assert!(!header.is_modified_since(time));
+    /// assert!(header.is_modified_since(time + Duration::from_secs(1)));
+    /// ```
+    pub fn is_modified_since(&self, last_modified: SystemTime) -> bool {
+        let secs = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .map(|dur| dur.as_secs())
+                .unwrap_or(0)
+        };
+        secs(last_modified) > secs(self.instant)
+    }
+
     /// Create an instance of `IfModifiedSince` from a `Headers` instance.
+    ///
+    /// Dates may be in any of the formats accepted by RFC 7231: IMF-fixdate,
+    /// RFC 850, or asctime. A malformed date is treated as if the header were
+    /// absent, as required by the spec.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let headers = match headers.as_ref().get(IF_MODIFIED_SINCE) {
             Some(headers) => headers,
@@ -62,7 +93,11 @@ impl IfModifiedSince {
         // entry. We want the last entry.
         let header = headers.iter().last().unwrap();
 
-        let instant = parse_http_date(header.as_str())?;
+        // A recipient must ignore the header if the date is invalid.
+        let instant = match parse_http_date(header.as_str()) {
+            Ok(instant) => instant,
+            Err(_) => return Ok(None),
+        };
         Ok(Some(Self { instant }))
     }
 }
@@ -102,12 +137,43 @@ mod test {
     }
 
     #[test]
-    fn bad_request_on_parse_error() {
+    fn ignore_parse_error() -> crate::Result<()> {
         let mut headers = Headers::new();
         headers
             .insert(IF_MODIFIED_SINCE, "<nori ate the tag. yum.>")
             .unwrap();
-        let err = IfModifiedSince::from_headers(headers).unwrap_err();
-        assert_eq!(err.status(), 400);
+        assert!(IfModifiedSince::from_headers(headers)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn date_formats() -> crate::Result<()> {
+        let expected = UNIX_EPOCH + Duration::from_secs(784111777);
+        for date in &[
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            let mut headers = Headers::new();
+            headers.insert(IF_MODIFIED_SINCE, *date).unwrap();
+            let header = IfModifiedSince::from_headers(&headers)?.unwrap();
+            assert_eq!(header.modified(), expected);
+
+            // Serialized as IMF-fixdate regardless of the input format.
+            let mut headers = Headers::new();
+            header.apply_header(&mut headers);
+            assert_eq!(headers[IF_MODIFIED_SINCE], "Sun, 06 Nov 1994 08:49:37 GMT");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn modified_since() {
+        let time = UNIX_EPOCH + Duration::from_secs(784111777);
+        let header = IfModifiedSince::new(time);
+
+        assert!(!header.is_modified_since(time - Duration::from_secs(1)));
+        assert!(!header.is_modified_since(time + Duration::from_millis(500)));
+        assert!(header.is_modified_since(time + Duration::from_secs(1)));
     }
 }
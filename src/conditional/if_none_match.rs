@@ -94,6 +94,18 @@ impl IfNoneMatch {
         self.wildcard = wildcard
     }
 
+    /// Returns `true` if the given ETag matches any of the entries.
+    ///
+    /// A wildcard matches any current representation of the resource.
+    /// Entries are compared using the weak comparison function.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7232, section 3.2](https://tools.ietf.org/html/rfc7232#section-3.2)
+    pub fn matches(&self, etag: &ETag) -> bool {
+        self.wildcard || self.entries.iter().any(|entry| entry.weak_eq(etag))
+    }
+
     /// An iterator visiting all server entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -279,4 +291,20 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn matches_weak() {
+        // If-None-Match uses the weak comparison function.
+        let mut entries = IfNoneMatch::new();
+        entries.push(ETag::new("xyzzy".to_string()));
+        entries.push(ETag::new_weak("r2d2xxxx".to_string()));
+
+        assert!(entries.matches(&ETag::new("xyzzy".to_string())));
+        assert!(entries.matches(&ETag::new_weak("xyzzy".to_string())));
+        assert!(entries.matches(&ETag::new("r2d2xxxx".to_string())));
+        assert!(!entries.matches(&ETag::new("c3piozzzz".to_string())));
+
+        entries.set_wildcard(true);
+        assert!(entries.matches(&ETag::new("c3piozzzz".to_string())));
+    }
 }
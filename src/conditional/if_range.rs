@@ -0,0 +1,172 @@
+use crate::conditional::ETag;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, IF_RANGE};
+use crate::utils::{fmt_http_date, parse_http_date};
+
+use std::fmt::Debug;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Only apply the `Range` header if the entity is unchanged.
+///
+/// This is used to resume downloads: if the entity has changed since the
+/// client received the first part of it, the `Range` header must be ignored,
+/// and the full entity sent with `200 OK` instead.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/If-Range)
+///
+/// # Specifications
+///
+/// - [RFC 7233, section 3.2: If-Range](https://tools.ietf.org/html/rfc7233#section-3.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::conditional::{ETag, IfRange};
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com/movie.mp4");
+/// req.insert_header("Range", "bytes=1000-")?;
+/// req.insert_header("If-Range", r#""xyzzy""#)?;
+///
+/// let if_range = IfRange::from_headers(&req)?.unwrap();
+/// let current = ETag::new("xyzzy".to_string());
+/// assert!(if_range.is_satisfied(Some(&current), None));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum IfRange {
+    /// The entity tag of the entity the client has part of.
+    ETag(ETag),
+    /// The last modification date of the entity the client has part of.
+    Date(SystemTime),
+}
+
+impl IfRange {
+    /// Create an instance of `IfRange` from a `Headers` instance.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header is
+    /// neither an entity tag nor a date.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(IF_RANGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If a header is returned we can assume at least one exists.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        if value.starts_with('"') || value.starts_with("W/") {
+            return Ok(Some(Self::ETag(ETag::from_str(value)?)));
+        }
+        let instant = parse_http_date(value).map_err(|mut err| {
+            err.set_status(400);
+            err
+        })?;
+        Ok(Some(Self::Date(instant)))
+    }
+
+    /// Sets the `If-Range` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Returns `true` if the `Range` header should be applied, given the
+    /// current validators of the entity.
+    ///
+    /// Entity tags are compared using the strong comparison function, so a
+    /// weak entity tag is never satisfied. Dates must match the last
+    /// modification date exactly, to the second. If the current validator of
+    /// the matching kind is unknown, the condition isn't satisfied.
+    ///
+    /// If this returns `false`, the `Range` header must be ignored, and the
+    /// full entity sent with `200 OK`.
+    pub fn is_satisfied(&self, etag: Option<&ETag>, last_modified: Option<SystemTime>) -> bool {
+        match (self, etag, last_modified) {
+            (Self::ETag(expected), Some(etag), _) => expected.strong_eq(etag),
+            (Self::Date(expected), _, Some(last_modified)) => {
+                secs(*expected) == secs(last_modified)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The whole seconds since the Unix epoch, as HTTP dates are precise to.
+fn secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|dur| dur.as_secs())
+        .unwrap_or(0)
+}
+
+impl Header for IfRange {
+    fn header_name(&self) -> HeaderName {
+        IF_RANGE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = match self {
+            Self::ETag(etag) => etag.to_string(),
+            Self::Date(instant) => fmt_http_date(*instant),
+        };
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+    use std::time::Duration;
+
+    #[test]
+    fn etag_matching() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(IF_RANGE, r#""xyzzy""#)?;
+        let if_range = IfRange::from_headers(&headers)?.unwrap();
+        assert_eq!(if_range, IfRange::ETag(ETag::new("xyzzy".to_string())));
+
+        assert!(if_range.is_satisfied(Some(&ETag::new("xyzzy".to_string())), None));
+        assert!(!if_range.is_satisfied(Some(&ETag::new("r2d2".to_string())), None));
+        assert!(!if_range.is_satisfied(Some(&ETag::new_weak("xyzzy".to_string())), None));
+        assert!(!if_range.is_satisfied(None, Some(SystemTime::now())));
+
+        // Weak tags never match under the strong comparison function.
+        let mut headers = Headers::new();
+        headers.insert(IF_RANGE, r#"W/"xyzzy""#)?;
+        let if_range = IfRange::from_headers(&headers)?.unwrap();
+        assert!(!if_range.is_satisfied(Some(&ETag::new_weak("xyzzy".to_string())), None));
+        Ok(())
+    }
+
+    #[test]
+    fn date_stale() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(IF_RANGE, "Sun, 06 Nov 1994 08:49:37 GMT")?;
+        let if_range = IfRange::from_headers(&headers)?.unwrap();
+
+        let modified = UNIX_EPOCH + Duration::from_secs(784111777);
+        assert_eq!(if_range, IfRange::Date(modified));
+        assert!(if_range.is_satisfied(None, Some(modified + Duration::from_millis(500))));
+        assert!(!if_range.is_satisfied(None, Some(modified + Duration::from_secs(60))));
+        assert!(!if_range.is_satisfied(Some(&ETag::new("xyzzy".to_string())), None));
+
+        let mut headers = Headers::new();
+        if_range.apply(&mut headers);
+        assert_eq!(headers[IF_RANGE], "Sun, 06 Nov 1994 08:49:37 GMT");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(IF_RANGE, "yesterday")?;
+        let err = IfRange::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+}
@@ -10,14 +10,17 @@
 
 mod etag;
 mod if_modified_since;
+mod if_range;
 mod if_unmodified_since;
 mod last_modified;
+mod preconditions;
 mod vary;
 
 pub mod if_match;
 pub mod if_none_match;
 
 pub use etag::ETag;
+pub use preconditions::evaluate_preconditions;
 pub use vary::Vary;
 
 #[doc(inline)]
@@ -27,6 +30,8 @@ pub use if_modified_since::IfModifiedSince;
 #[doc(inline)]
 pub use if_none_match::IfNoneMatch;
 #[doc(inline)]
+pub use if_range::IfRange;
+#[doc(inline)]
 pub use if_unmodified_since::IfUnmodifiedSince;
 #[doc(inline)]
 pub use last_modified::LastModified;
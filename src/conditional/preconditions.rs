@@ -0,0 +1,116 @@
+//! Evaluate ETag preconditions for a request.
+
+use crate::conditional::{ETag, IfMatch, IfNoneMatch};
+use crate::{Method, Request, StatusCode};
+
+/// Evaluate the `If-Match` and `If-None-Match` preconditions of a request
+/// against the current ETag of the targeted resource.
+///
+/// Returns `None` if the request should be processed as normal. Otherwise the
+/// status code that should be sent instead is returned:
+///
+/// - `412 Precondition Failed` if `If-Match` is present and does not match.
+/// - `304 Not Modified` if `If-None-Match` matches on a `GET` or `HEAD` request.
+/// - `412 Precondition Failed` if `If-None-Match` matches on any other method.
+///
+/// # Specifications
+///
+/// - [RFC 7232, section 6: Precedence](https://tools.ietf.org/html/rfc7232#section-6)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::conditional::{evaluate_preconditions, ETag};
+/// use http_types::{Method, Request, StatusCode};
+///
+/// let mut req = Request::new(Method::Get, "https://example.com");
+/// req.insert_header("If-None-Match", r#"W/"0xcafebeef""#)?;
+///
+/// let etag = ETag::new("0xcafebeef".to_string());
+/// let status = evaluate_preconditions(&req, &etag)?;
+/// assert_eq!(status, Some(StatusCode::NotModified));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn evaluate_preconditions(req: &Request, etag: &ETag) -> crate::Result<Option<StatusCode>> {
+    if let Some(if_match) = IfMatch::from_headers(req)? {
+        if !if_match.matches(etag) {
+            return Ok(Some(StatusCode::PreconditionFailed));
+        }
+    }
+
+    if let Some(if_none_match) = IfNoneMatch::from_headers(req)? {
+        if if_none_match.matches(etag) {
+            let status = match req.method() {
+                Method::Get | Method::Head => StatusCode::NotModified,
+                _ => StatusCode::PreconditionFailed,
+            };
+            return Ok(Some(status));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{IF_MATCH, IF_NONE_MATCH};
+
+    fn etag() -> ETag {
+        ETag::new("xyzzy".to_string())
+    }
+
+    #[test]
+    fn no_preconditions() -> crate::Result<()> {
+        let req = Request::new(Method::Get, "https://example.com");
+        assert_eq!(evaluate_preconditions(&req, &etag())?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn if_none_match_get() -> crate::Result<()> {
+        let mut req = Request::new(Method::Get, "https://example.com");
+        req.insert_header(IF_NONE_MATCH, r#""r2d2xxxx", W/"xyzzy""#)?;
+        let status = evaluate_preconditions(&req, &etag())?;
+        assert_eq!(status, Some(StatusCode::NotModified));
+
+        let mut req = Request::new(Method::Head, "https://example.com");
+        req.insert_header(IF_NONE_MATCH, r#""c3piozzzz""#)?;
+        assert_eq!(evaluate_preconditions(&req, &etag())?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn if_none_match_put() -> crate::Result<()> {
+        let mut req = Request::new(Method::Put, "https://example.com");
+        req.insert_header(IF_NONE_MATCH, "*")?;
+        let status = evaluate_preconditions(&req, &etag())?;
+        assert_eq!(status, Some(StatusCode::PreconditionFailed));
+        Ok(())
+    }
+
+    #[test]
+    fn if_match() -> crate::Result<()> {
+        let mut req = Request::new(Method::Put, "https://example.com");
+        req.insert_header(IF_MATCH, r#""xyzzy""#)?;
+        assert_eq!(evaluate_preconditions(&req, &etag())?, None);
+
+        // Weak tags never match under the strong comparison function.
+        let mut req = Request::new(Method::Put, "https://example.com");
+        req.insert_header(IF_MATCH, r#"W/"xyzzy""#)?;
+        let status = evaluate_preconditions(&req, &etag())?;
+        assert_eq!(status, Some(StatusCode::PreconditionFailed));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let mut req = Request::new(Method::Get, "https://example.com");
+        req.insert_header(IF_NONE_MATCH, "xyzzy").unwrap();
+        let err = evaluate_preconditions(&req, &etag()).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -1,4 +1,4 @@
-//! Apply the HTTP method if the ETag matches.
+//! Indicate which request headers were used to select a response.
 
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, VARY};
 
@@ -8,7 +8,13 @@ use std::iter::Iterator;
 use std::slice;
 use std::str::FromStr;
 
-/// Apply the HTTP method if the ETag matches.
+/// Indicate which request headers were used to select a response.
+///
+/// Caches use the listed headers as part of the key for a stored response.
+/// Header names are compared case-insensitively, and each name is only listed
+/// once. The `*` wildcard is tracked separately, and signals that the response
+/// was selected on something other than request headers, meaning caches can't
+/// reuse it.
 ///
 /// # Specifications
 ///
@@ -67,7 +73,9 @@ impl Vary {
                     continue;
                 }
                 let entry = HeaderName::from_str(part.trim())?;
-                entries.push(entry);
+                if !entries.contains(&entry) {
+                    entries.push(entry);
+                }
             }
         }
 
@@ -84,12 +92,27 @@ impl Vary {
         self.wildcard = wildcard
     }
 
+    /// Sets the `Vary` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// Push a directive into the list of entries.
+    ///
+    /// Header names which are already listed are ignored.
     pub fn push(&mut self, directive: impl Into<HeaderName>) -> crate::Result<()> {
-        self.entries.push(directive.into());
+        let directive = directive.into();
+        if !self.contains(&directive) {
+            self.entries.push(directive);
+        }
         Ok(())
     }
 
+    /// Returns `true` if the header name is listed.
+    pub fn contains(&self, name: &HeaderName) -> bool {
+        self.entries.contains(name)
+    }
+
     /// An iterator visiting all server entries.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -269,6 +292,45 @@ mod test {
         assert!(entries.wildcard());
         let mut entries = entries.iter();
         assert_eq!(entries.next().unwrap(), "User-Agent");
+
+        let mut res = Response::new(200);
+        res.insert_header(VARY, "*")?;
+        let entries = Vary::from_headers(res)?.unwrap();
+        assert!(entries.wildcard());
+        assert_eq!(entries.iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_list() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.insert_header(VARY, "Accept-Encoding, Accept-Language")?;
+
+        let entries = Vary::from_headers(&res)?.unwrap();
+        assert!(!entries.wildcard());
+        let names: Vec<_> = entries.iter().map(|name| name.as_str()).collect();
+        assert_eq!(names, ["accept-encoding", "accept-language"]);
+
+        let mut res = Response::new(200);
+        entries.apply(&mut res);
+        assert_eq!(res[VARY], "accept-encoding, accept-language");
+        Ok(())
+    }
+
+    #[test]
+    fn dedup_case_insensitive() -> crate::Result<()> {
+        let mut entries = Vary::new();
+        entries.push("Accept-Encoding")?;
+        entries.push("accept-encoding")?;
+        entries.push("ACCEPT-ENCODING")?;
+        assert_eq!(entries.iter().count(), 1);
+
+        let mut res = Response::new(200);
+        res.insert_header(VARY, "Origin, origin")?;
+        res.append_header(VARY, "ORIGIN, Accept")?;
+        let entries = Vary::from_headers(res)?.unwrap();
+        assert_eq!(entries.iter().count(), 2);
+        assert!(entries.contains(&"accept".parse()?));
         Ok(())
     }
 }
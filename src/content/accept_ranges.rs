@@ -0,0 +1,184 @@
+use crate::ensure_status as ensure;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCEPT_RANGES};
+
+/// The range units supported by the server.
+///
+/// Servers send `Accept-Ranges: bytes` to advertise support for byte range
+/// requests, or `Accept-Ranges: none` to advise clients not to attempt them.
+/// Unknown units are preserved.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Accept-Ranges)
+///
+/// # Specifications
+///
+/// - [RFC 7233, section 2.3: Accept-Ranges](https://tools.ietf.org/html/rfc7233#section-2.3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::content::AcceptRanges;
+///
+/// let accept_ranges = AcceptRanges::bytes();
+///
+/// let mut res = Response::new(200);
+/// accept_ranges.apply(&mut res);
+/// assert_eq!(res["Accept-Ranges"], "bytes");
+///
+/// let accept_ranges = AcceptRanges::from_headers(res)?.unwrap();
+/// assert!(accept_ranges.accepts_bytes());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceptRanges {
+    units: Vec<String>,
+}
+
+impl AcceptRanges {
+    /// Create a new instance advertising support for byte ranges.
+    pub fn bytes() -> Self {
+        Self {
+            units: vec!["bytes".to_owned()],
+        }
+    }
+
+    /// Create a new instance advertising that no ranges are supported.
+    pub fn none() -> Self {
+        Self { units: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(ACCEPT_RANGES) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        ensure!(
+            headers
+                .iter()
+                .any(|value| !value.as_str().trim().is_empty()),
+            400,
+            "Accept-Ranges header is empty"
+        );
+
+        let mut accept_ranges = Self::none();
+        for value in headers {
+            for unit in value.as_str().split(',') {
+                let unit = unit.trim();
+                if unit.is_empty() || unit.eq_ignore_ascii_case("none") {
+                    continue;
+                }
+                accept_ranges.push(unit);
+            }
+        }
+
+        Ok(Some(accept_ranges))
+    }
+
+    /// Sets the `Accept-Ranges` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Add a range unit.
+    ///
+    /// Units are compared case-insensitively, and listed only once.
+    pub fn push(&mut self, unit: impl AsRef<str>) {
+        let unit = unit.as_ref().to_ascii_lowercase();
+        if !self.units.contains(&unit) {
+            self.units.push(unit);
+        }
+    }
+
+    /// Returns `true` if byte ranges are supported.
+    pub fn accepts_bytes(&self) -> bool {
+        self.units.iter().any(|unit| unit == "bytes")
+    }
+
+    /// Returns `true` if no range units are supported.
+    pub fn is_none(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// An iterator visiting all supported range units.
+    pub fn units(&self) -> impl Iterator<Item = &str> + '_ {
+        self.units.iter().map(|unit| unit.as_str())
+    }
+}
+
+impl Header for AcceptRanges {
+    fn header_name(&self) -> HeaderName {
+        ACCEPT_RANGES
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = match self.units.len() {
+            0 => "none".to_owned(),
+            _ => self.units.join(", "),
+        };
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn bytes() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        AcceptRanges::bytes().apply(&mut headers);
+        assert_eq!(headers[ACCEPT_RANGES], "bytes");
+
+        let accept_ranges = AcceptRanges::from_headers(headers)?.unwrap();
+        assert_eq!(accept_ranges, AcceptRanges::bytes());
+        assert!(accept_ranges.accepts_bytes());
+        assert!(!accept_ranges.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn none() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        AcceptRanges::none().apply(&mut headers);
+        assert_eq!(headers[ACCEPT_RANGES], "none");
+
+        let accept_ranges = AcceptRanges::from_headers(headers)?.unwrap();
+        assert_eq!(accept_ranges, AcceptRanges::none());
+        assert!(!accept_ranges.accepts_bytes());
+        assert!(accept_ranges.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_unit() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(ACCEPT_RANGES, "Bytes, Pages")?;
+
+        let accept_ranges = AcceptRanges::from_headers(&headers)?.unwrap();
+        assert!(accept_ranges.accepts_bytes());
+        assert_eq!(
+            accept_ranges.units().collect::<Vec<_>>(),
+            ["bytes", "pages"]
+        );
+
+        accept_ranges.apply(&mut headers);
+        assert_eq!(headers[ACCEPT_RANGES], "bytes, pages");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_empty_header() {
+        let mut headers = Headers::new();
+        headers.insert(ACCEPT_RANGES, "").unwrap();
+        let err = AcceptRanges::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::content::DigestAlgorithm;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CONTENT_DIGEST};
+use crate::{bail_status as bail, Body};
+
+use std::fmt::Write;
+
+/// The digest of the content of a message.
+///
+/// A header may carry digests computed with several algorithms. Digests
+/// computed with unsupported algorithms are ignored when parsing.
+///
+/// # Specifications
+///
+/// - [RFC 9530, section 2: The Content-Digest Field](https://www.rfc-editor.org/rfc/rfc9530#section-2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::content::{ContentDigest, DigestAlgorithm};
+///
+/// let digest = ContentDigest::from_bytes(DigestAlgorithm::Sha256, b"{\"hello\": \"world\"}");
+///
+/// let mut res = Response::new(200);
+/// digest.apply(&mut res);
+/// assert_eq!(
+///     res["Content-Digest"],
+///     "sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:"
+/// );
+///
+/// let digest = ContentDigest::from_headers(res)?.unwrap();
+/// assert!(digest.verify(b"{\"hello\": \"world\"}"));
+/// assert!(!digest.verify(b"{\"hello\": \"nori\"}"));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentDigest {
+    entries: Vec<(DigestAlgorithm, Vec<u8>)>,
+}
+
+impl ContentDigest {
+    /// Create a new, empty instance of `ContentDigest`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create a new instance by computing the digest of a byte slice.
+    pub fn from_bytes(algorithm: DigestAlgorithm, bytes: &[u8]) -> Self {
+        let mut digest = Self::new();
+        digest.push(algorithm, algorithm.digest(bytes));
+        digest
+    }
+
+    /// Create a new instance by computing the digest of a body.
+    ///
+    /// The body is read into memory, and returned so it can still be sent.
+    pub async fn from_body(body: Body, algorithm: DigestAlgorithm) -> crate::Result<(Self, Body)> {
+        let mime = body.mime().cloned();
+        let bytes = body.into_bytes().await?;
+        let digest = Self::from_bytes(algorithm, &bytes);
+
+        let mut body = Body::from_bytes(bytes);
+        body.set_mime(mime);
+        Ok((digest, body))
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(CONTENT_DIGEST) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut digest = Self::new();
+        for value in headers {
+            for member in value.as_str().split(',') {
+                // Parameters carry no meaning for digests, and are ignored.
+                let member = member.split(';').next().unwrap().trim();
+                if member.is_empty() {
+                    continue;
+                }
+
+                let (key, value) = match member.split_once('=') {
+                    Some(parts) => parts,
+                    None => bail!(400, "malformed Content-Digest member `{}`", member),
+                };
+                let value = match value.strip_prefix(':').and_then(|v| v.strip_suffix(':')) {
+                    Some(value) => value,
+                    None => bail!(400, "Content-Digest values must be byte sequences"),
+                };
+                let bytes = match base64::decode(value) {
+                    Ok(bytes) => bytes,
+                    Err(_) => bail!(400, "Content-Digest values must be base64 encoded"),
+                };
+
+                if let Ok(algorithm) = key.parse() {
+                    digest.push(algorithm, bytes);
+                }
+            }
+        }
+
+        Ok(Some(digest))
+    }
+
+    /// Sets the `Content-Digest` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Add a digest, replacing any digest with the same algorithm.
+    pub fn push(&mut self, algorithm: DigestAlgorithm, digest: Vec<u8>) {
+        self.entries.retain(|(existing, _)| *existing != algorithm);
+        self.entries.push((algorithm, digest));
+    }
+
+    /// Get the raw digest computed with the given algorithm.
+    pub fn get(&self, algorithm: DigestAlgorithm) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|(existing, _)| *existing == algorithm)
+            .map(|(_, digest)| digest.as_slice())
+    }
+
+    /// An iterator visiting all algorithms and their raw digests.
+    pub fn iter(&self) -> impl Iterator<Item = (DigestAlgorithm, &[u8])> + '_ {
+        self.entries
+            .iter()
+            .map(|(algorithm, digest)| (*algorithm, digest.as_slice()))
+    }
+
+    /// Check the integrity of the content.
+    ///
+    /// Returns `true` if there is at least one digest, and every digest
+    /// matches the content.
+    pub fn verify(&self, bytes: &[u8]) -> bool {
+        !self.entries.is_empty()
+            && self
+                .entries
+                .iter()
+                .all(|(algorithm, digest)| algorithm.digest(bytes) == *digest)
+    }
+}
+
+impl Default for ContentDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for ContentDigest {
+    fn header_name(&self) -> HeaderName {
+        CONTENT_DIGEST
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, (algorithm, digest)) in self.entries.iter().enumerate() {
+            if n > 0 {
+                output.push_str(", ");
+            }
+            write!(output, "{}=:{}:", algorithm, base64::encode(digest)).unwrap();
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    // Example from RFC 9530, appendix D.1.
+    const BODY: &[u8] = b"{\"hello\": \"world\"}\n";
+    const SHA256: &str = "sha-256=:RK/0qy18MlBSVnWgjwz6lZEWjP/lF5HF9bvEF8FabDg=:";
+
+    #[test]
+    fn format() {
+        let digest = ContentDigest::from_bytes(DigestAlgorithm::Sha256, BODY);
+
+        let mut headers = Headers::new();
+        digest.apply(&mut headers);
+        assert_eq!(headers[CONTENT_DIGEST], SHA256);
+    }
+
+    #[test]
+    fn verify() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_DIGEST, SHA256)?;
+
+        let digest = ContentDigest::from_headers(headers)?.unwrap();
+        assert_eq!(
+            digest.get(DigestAlgorithm::Sha256),
+            Some(DigestAlgorithm::Sha256.digest(BODY).as_slice())
+        );
+        assert!(digest.verify(BODY));
+        assert!(!digest.verify(b"{\"hello\": \"nori\"}\n"));
+        assert!(!ContentDigest::new().verify(BODY));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_algorithms() -> crate::Result<()> {
+        let mut digest = ContentDigest::from_bytes(DigestAlgorithm::Sha256, BODY);
+        digest.push(
+            DigestAlgorithm::Sha512,
+            DigestAlgorithm::Sha512.digest(BODY),
+        );
+
+        let mut headers = Headers::new();
+        digest.apply(&mut headers);
+        headers.append(CONTENT_DIGEST, "unixsum=:MTIzNA==:")?;
+
+        let parsed = ContentDigest::from_headers(headers)?.unwrap();
+        assert_eq!(parsed, digest);
+        let algorithms: Vec<_> = parsed.iter().map(|(algorithm, _)| algorithm).collect();
+        assert_eq!(
+            algorithms,
+            [DigestAlgorithm::Sha256, DigestAlgorithm::Sha512]
+        );
+        assert!(parsed.verify(BODY));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn from_body() -> crate::Result<()> {
+        let body = Body::from_reader(async_std::io::Cursor::new(BODY), None);
+        let (digest, body) = ContentDigest::from_body(body, DigestAlgorithm::Sha256).await?;
+        assert!(digest.verify(BODY));
+        assert_eq!(body.into_bytes().await?, BODY);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["sha-256", "sha-256=abc", "sha-256=:not base64!:"] {
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_DIGEST, *value).unwrap();
+            let err = ContentDigest::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
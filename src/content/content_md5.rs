@@ -0,0 +1,160 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CONTENT_MD5};
+
+use md5::{Digest, Md5};
+
+/// The MD5 digest of the content of a message.
+///
+/// This header is obsolete, and is only supported for compatibility with
+/// legacy APIs which still require it. New applications should use
+/// [`ContentDigest`](super::ContentDigest) instead.
+///
+/// # Specifications
+///
+/// - [RFC 1864: The Content-MD5 Header Field](https://tools.ietf.org/html/rfc1864)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::ContentMd5;
+/// use http_types::Response;
+///
+/// let md5 = ContentMd5::from_bytes(b"Hello Nori");
+///
+/// let mut res = Response::new(200);
+/// md5.apply(&mut res);
+/// assert_eq!(res["Content-MD5"], "80zDaXwRP4Ee8/ZpSfIJaQ==");
+///
+/// let md5 = ContentMd5::from_headers(res)?.unwrap();
+/// assert!(md5.verify(b"Hello Nori"));
+/// assert!(!md5.verify(b"Hello Chashu"));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentMd5 {
+    digest: [u8; 16],
+}
+
+impl ContentMd5 {
+    /// Create a new instance from a raw digest.
+    pub fn new(digest: [u8; 16]) -> Self {
+        Self { digest }
+    }
+
+    /// Create a new instance by computing the digest of a byte slice.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            digest: Md5::digest(bytes).into(),
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header isn't
+    /// the base64 encoding of a 16 byte digest.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(CONTENT_MD5) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap();
+        let bytes = match base64::decode(value.as_str().trim()) {
+            Ok(bytes) => bytes,
+            Err(_) => bail!(400, "Content-MD5 must be base64 encoded"),
+        };
+        let mut digest = [0; 16];
+        if bytes.len() != digest.len() {
+            bail!(400, "Content-MD5 must be a 16 byte digest");
+        }
+        digest.copy_from_slice(&bytes);
+        Ok(Some(Self { digest }))
+    }
+
+    /// Sets the `Content-MD5` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Get the raw digest.
+    pub fn digest(&self) -> [u8; 16] {
+        self.digest
+    }
+
+    /// Check the integrity of the content.
+    pub fn verify(&self, bytes: &[u8]) -> bool {
+        Self::from_bytes(bytes) == *self
+    }
+}
+
+impl Header for ContentMd5 {
+    fn header_name(&self) -> HeaderName {
+        CONTENT_MD5
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = base64::encode(self.digest);
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::Body;
+
+    #[test]
+    fn known_vectors() {
+        // Test suite from RFC 1321, appendix A.5.
+        let cases = [
+            ("", "1B2M2Y8AsgTpgAmY7PhCfg=="),
+            ("abc", "kAFQmDzST7DWlj99KOF/cg=="),
+            ("message digest", "+WtpfXy3k41SWi8xqvFh0A=="),
+        ];
+        for (input, expected) in cases.iter() {
+            let mut headers = Headers::new();
+            ContentMd5::from_bytes(input.as_bytes()).apply(&mut headers);
+            assert_eq!(headers[CONTENT_MD5], *expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn verify() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_MD5, "kAFQmDzST7DWlj99KOF/cg==")?;
+        let md5 = ContentMd5::from_headers(headers)?.unwrap();
+        assert_eq!(md5.digest()[..4], [0x90, 0x01, 0x50, 0x98]);
+        assert!(md5.verify(b"abc"));
+        assert!(!md5.verify(b"abd"));
+        Ok(())
+    }
+
+    #[test]
+    fn body() {
+        let body = Body::from("abc");
+        assert_eq!(body.content_md5(), Some(ContentMd5::from_bytes(b"abc")));
+
+        let body = Body::from_reader(async_std::io::Cursor::new("abc"), None);
+        assert_eq!(body.content_md5(), None);
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["not base64!", "YWJj"] {
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_MD5, *value).unwrap();
+            let err = ContentMd5::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
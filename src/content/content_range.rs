@@ -0,0 +1,205 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CONTENT_RANGE};
+use crate::Status;
+
+use std::ops::RangeInclusive;
+
+/// Indicate which part of a representation a `206: Partial Content` response
+/// contains.
+///
+/// # Specifications
+///
+/// - [RFC 7233, section 4.2: Content-Range](https://tools.ietf.org/html/rfc7233#section-4.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::ContentRange;
+/// use http_types::{Response, StatusCode};
+///
+/// let content_range = ContentRange::new(200, 999, Some(1234))?;
+///
+/// let mut res = Response::new(StatusCode::PartialContent);
+/// res.insert_header(&content_range, &content_range);
+/// assert_eq!(res["Content-Range"], "bytes 200-999/1234");
+///
+/// let content_range = ContentRange::from_headers(res)?.unwrap();
+/// assert_eq!(content_range.start(), 200);
+/// assert_eq!(content_range.end(), 999);
+/// assert_eq!(content_range.len(), 800);
+/// assert_eq!(content_range.total(), Some(1234));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ContentRange {
+    start: u64,
+    end: u64,
+    total: Option<u64>,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl ContentRange {
+    /// Create a new instance from the first and last position of the range,
+    /// and the total size of the representation if it's known.
+    ///
+    /// # Errors
+    ///
+    /// This function will error unless `start <= end < total`.
+    pub fn new(start: u64, end: u64, total: Option<u64>) -> crate::Result<Self> {
+        crate::ensure!(start <= end, "Invalid range {}-{}", start, end);
+        if let Some(total) = total {
+            crate::ensure!(
+                end < total,
+                "Range {}-{} is out of bounds for size {}",
+                start,
+                end,
+                total
+            );
+        }
+        Ok(Self { start, end, total })
+    }
+
+    /// Create a new instance from a resolved range, such as those returned by
+    /// [`Range::satisfiable`](crate::content::Range::satisfiable).
+    pub fn from_range(range: RangeInclusive<u64>, total: Option<u64>) -> crate::Result<Self> {
+        Self::new(*range.start(), *range.end(), total)
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header is
+    /// malformed or describes an invalid range.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(CONTENT_RANGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        let invalid = || format!("Invalid Content-Range header {:?}", value);
+
+        let range = match value.split_once(' ') {
+            Some((unit, range)) if unit.eq_ignore_ascii_case("bytes") => range.trim(),
+            _ => crate::bail_status!(400, "{}", invalid()),
+        };
+        let (range, total) = match range.split_once('/') {
+            Some(parts) => parts,
+            None => crate::bail_status!(400, "{}", invalid()),
+        };
+        let (start, end) = match range.split_once('-') {
+            Some(parts) => parts,
+            None => crate::bail_status!(400, "{}", invalid()),
+        };
+
+        let start = parse_pos(start)?;
+        let end = parse_pos(end)?;
+        let total = match total {
+            "*" => None,
+            total => Some(parse_pos(total)?),
+        };
+        match Self::new(start, end, total) {
+            Ok(content_range) => Ok(Some(content_range)),
+            Err(mut err) => {
+                err.set_status(400);
+                Err(err)
+            }
+        }
+    }
+
+    /// Get the first position of the range.
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Get the last position of the range. The range includes this position.
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Get the number of bytes in the range.
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    /// Get the total size of the representation, or `None` if it's unknown.
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+impl Header for ContentRange {
+    fn header_name(&self) -> HeaderName {
+        CONTENT_RANGE
+    }
+    fn header_value(&self) -> HeaderValue {
+        let output = match self.total {
+            Some(total) => format!("bytes {}-{}/{}", self.start, self.end, total),
+            None => format!("bytes {}-{}/*", self.start, self.end),
+        };
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+fn parse_pos(s: &str) -> crate::Result<u64> {
+    crate::ensure_status!(
+        !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()),
+        400,
+        "Invalid range position {:?}",
+        s
+    );
+    s.parse().status(400)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let content_range = ContentRange::new(0, 499, Some(1234))?;
+
+        let mut headers = Headers::new();
+        content_range.apply_header(&mut headers);
+        assert_eq!(headers[CONTENT_RANGE], "bytes 0-499/1234");
+
+        let content_range = ContentRange::from_headers(headers)?.unwrap();
+        assert_eq!(content_range, ContentRange::new(0, 499, Some(1234))?);
+        assert_eq!(content_range.len(), 500);
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_total() -> crate::Result<()> {
+        let content_range = ContentRange::from_range(42..=1233, None)?;
+
+        let mut headers = Headers::new();
+        content_range.apply_header(&mut headers);
+        assert_eq!(headers[CONTENT_RANGE], "bytes 42-1233/*");
+
+        let content_range = ContentRange::from_headers(headers)?.unwrap();
+        assert_eq!(content_range.total(), None);
+        assert_eq!(content_range.start(), 42);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_range() {
+        assert!(ContentRange::new(500, 499, None).is_err());
+        assert!(ContentRange::new(0, 1234, Some(1234)).is_err());
+
+        for value in &["bytes 5-1/10", "bytes 0-10/10", "items 0-1/2", "bytes */10"] {
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_RANGE, *value).unwrap();
+            let err = ContentRange::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use futures_lite::{io, prelude::*, ready};
+use sha2::{Digest, Sha256, Sha512};
+
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+use std::mem;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+use crate::Body;
+
+/// A hash algorithm used to compute the digest of a body.
+///
+/// # Specifications
+///
+/// - [RFC 9530, section 5: Hash Algorithm Considerations](https://www.rfc-editor.org/rfc/rfc9530#section-5)
+///
+/// # Examples
+///
+/// ```
+/// use http_types::content::DigestAlgorithm;
+///
+/// let digest = DigestAlgorithm::Sha256.digest(b"abc");
+/// assert_eq!(digest.len(), 32);
+/// assert_eq!(DigestAlgorithm::Sha256.as_str(), "sha-256");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DigestAlgorithm {
+    /// The SHA-256 algorithm.
+    Sha256,
+
+    /// The SHA-512 algorithm.
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    /// Get the registered name of the algorithm.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha-256",
+            DigestAlgorithm::Sha512 => "sha-512",
+        }
+    }
+
+    /// Compute the digest of a byte slice.
+    pub fn digest(&self, bytes: &[u8]) -> Vec<u8> {
+        let mut hasher = Hasher::new(*self);
+        hasher.update(bytes);
+        hasher.finalize()
+    }
+}
+
+impl Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DigestAlgorithm {
+    type Err = crate::Error;
+
+    /// Parse a registered algorithm name, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "sha-256" => Ok(DigestAlgorithm::Sha256),
+            "sha-512" => Ok(DigestAlgorithm::Sha512),
+            _ => crate::bail_status!(400, "unsupported digest algorithm `{}`", s),
+        }
+    }
+}
+
+/// The running state of a digest computation.
+#[derive(Debug)]
+enum Hasher {
+    Sha256(Sha256),
+    Sha512(Sha512),
+}
+
+impl Hasher {
+    fn new(algorithm: DigestAlgorithm) -> Self {
+        match algorithm {
+            DigestAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            DigestAlgorithm::Sha512 => Hasher::Sha512(Sha512::new()),
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        match self {
+            Hasher::Sha256(hasher) => hasher.update(bytes),
+            Hasher::Sha512(hasher) => hasher.update(bytes),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Sha256(hasher) => hasher.finalize().to_vec(),
+            Hasher::Sha512(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum DigestState {
+    Reading(Box<Hasher>),
+    Done(Vec<u8>),
+}
+
+/// A handle to the digest of a body, computed while the body is read.
+///
+/// Created by [`Body::with_digest`].
+#[derive(Debug, Clone)]
+pub struct DigestHandle {
+    algorithm: DigestAlgorithm,
+    state: Rc<RefCell<DigestState>>,
+}
+
+impl DigestHandle {
+    /// Get the algorithm used to compute the digest.
+    pub fn algorithm(&self) -> DigestAlgorithm {
+        self.algorithm
+    }
+
+    /// Get the digest of the body.
+    ///
+    /// Returns `None` if the body hasn't been read to the end yet.
+    pub fn finalize(&self) -> Option<Vec<u8>> {
+        match &*self.state.borrow() {
+            DigestState::Reading(_) => None,
+            DigestState::Done(digest) => Some(digest.clone()),
+        }
+    }
+}
+
+/// A reader which feeds every byte read from a body into a hasher.
+#[derive(Debug)]
+pub(crate) struct DigestReader {
+    inner: Body,
+    remaining: Option<u64>,
+    state: Rc<RefCell<DigestState>>,
+}
+
+impl DigestReader {
+    /// Wrap a body, of which `remaining` bytes are left to be read.
+    ///
+    /// Readers stop polling a body once its length has been read, so the
+    /// length is used to detect the end of the body as well.
+    pub(crate) fn new(
+        inner: Body,
+        remaining: Option<u64>,
+        algorithm: DigestAlgorithm,
+    ) -> (Self, DigestHandle) {
+        let hasher = Box::new(Hasher::new(algorithm));
+        let state = Rc::new(RefCell::new(DigestState::Reading(hasher)));
+        let handle = DigestHandle {
+            algorithm,
+            state: state.clone(),
+        };
+        let reader = Self {
+            inner,
+            remaining,
+            state,
+        };
+        (reader, handle)
+    }
+}
+
+impl AsyncRead for DigestReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let bytes = ready!(Pin::new(&mut self.inner).poll_read(cx, buf))?;
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(bytes as u64);
+        }
+        let at_end = (bytes == 0 && !buf.is_empty()) || self.remaining == Some(0);
+
+        let mut state = self.state.borrow_mut();
+        if let DigestState::Reading(hasher) = &mut *state {
+            hasher.update(&buf[..bytes]);
+            if at_end {
+                if let DigestState::Reading(hasher) =
+                    mem::replace(&mut *state, DigestState::Done(vec![]))
+                {
+                    *state = DigestState::Done(hasher.finalize());
+                }
+            }
+        }
+        Poll::Ready(Ok(bytes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn known_vector() {
+        assert_eq!(hex(&DigestAlgorithm::Sha256.digest(b"abc")), ABC_SHA256);
+    }
+
+    #[async_std::test]
+    async fn digest_while_reading() -> crate::Result<()> {
+        let body = Body::from_reader(async_std::io::Cursor::new("abc"), None);
+        let (body, handle) = body.with_digest(DigestAlgorithm::Sha256);
+        assert_eq!(handle.finalize(), None);
+
+        assert_eq!(body.into_string().await?, "abc");
+        assert_eq!(hex(&handle.finalize().unwrap()), ABC_SHA256);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn not_finalized_before_end() -> crate::Result<()> {
+        let input = vec![b'a'; 100_000];
+        let expected = DigestAlgorithm::Sha512.digest(&input);
+
+        let (mut body, handle) = Body::from(input).with_digest(DigestAlgorithm::Sha512);
+        let mut buf = [0; 2];
+        body.read_exact(&mut buf).await?;
+        assert_eq!(handle.finalize(), None);
+
+        let mut buf = vec![];
+        body.read_to_end(&mut buf).await?;
+        assert_eq!(handle.finalize(), Some(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_algorithm() -> crate::Result<()> {
+        assert_eq!(
+            "SHA-256".parse::<DigestAlgorithm>()?,
+            DigestAlgorithm::Sha256
+        );
+        assert_eq!(
+            "sha-512".parse::<DigestAlgorithm>()?,
+            DigestAlgorithm::Sha512
+        );
+        let err = "md5".parse::<DigestAlgorithm>().unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, LINK};
+use crate::parse_utils::{
+    format_quoted_string, is_quotable, parse_list, parse_params, parse_quoted_string, parse_token,
+    skip_ows,
+};
+
+use std::fmt::{self, Debug, Write};
+use std::iter::Iterator;
+use std::slice;
+
+/// Links the resource to other resources, such as the next page of results.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Link)
+///
+/// # Specifications
+///
+/// - [RFC 8288, section 3: Link Serialisation in HTTP Headers](https://tools.ietf.org/html/rfc8288#section-3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::{Link, LinkValue};
+/// use http_types::Response;
+///
+/// let mut link = Link::new();
+/// link.push(LinkValue::new("/items?page=3")?.with_rel("next")?);
+/// link.push(LinkValue::new("/items?page=1")?.with_rel("prev")?);
+///
+/// let mut res = Response::new(200);
+/// link.apply(&mut res);
+/// assert_eq!(
+///     res["Link"],
+///     r#"</items?page=3>; rel="next", </items?page=1>; rel="prev""#
+/// );
+///
+/// let link = Link::from_headers(res)?.unwrap();
+/// assert_eq!(link.find_rel("next").unwrap().target(), "/items?page=3");
+/// #
+/// # Ok(()) }
+/// ```
+pub struct Link {
+    links: Vec<LinkValue>,
+}
+
+impl Link {
+    /// Create a new instance of `Link`.
+    pub fn new() -> Self {
+        Self { links: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if a link is
+    /// malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(LINK) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut links = vec![];
+        for value in headers {
+            links.extend(parse_links(value.as_str())?);
+        }
+        Ok(Some(Self { links }))
+    }
+
+    /// Sets the `Link` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Push a link into the list of links.
+    pub fn push(&mut self, link: LinkValue) {
+        self.links.push(link);
+    }
+
+    /// Find the first link with the given relation type.
+    ///
+    /// Relation types are compared case-insensitively, and links with several
+    /// relation types match each of them.
+    pub fn find_rel(&self, rel: &str) -> Option<&LinkValue> {
+        self.links.iter().find(|link| link.has_rel(rel))
+    }
+
+    /// An iterator visiting all links.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.links.iter(),
+        }
+    }
+}
+
+impl Header for Link {
+    fn header_name(&self) -> HeaderName {
+        LINK
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, link) in self.links.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", link).unwrap(),
+                _ => write!(output, ", {}", link).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Link {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Link {
+    type Item = &'a LinkValue;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowing iterator over entries in `Link`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, LinkValue>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a LinkValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for link in &self.links {
+            list.entry(link);
+        }
+        list.finish()
+    }
+}
+
+/// A single link in the [`Link`] header, made up of a target URI and
+/// parameters such as `rel`, `type`, and `title`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkValue {
+    target: String,
+    params: Vec<(String, String)>,
+}
+
+impl LinkValue {
+    /// Create a new link to the target URI, which may be relative.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the target
+    /// contains characters which aren't allowed in a URI reference, such as
+    /// whitespace or angle brackets.
+    pub fn new(target: impl Into<String>) -> crate::Result<Self> {
+        let target = target.into();
+        if !is_uri_reference(&target) {
+            bail!(400, "invalid link target `{}`", target.escape_debug());
+        }
+        Ok(Self {
+            target,
+            params: vec![],
+        })
+    }
+
+    /// Set the relation type of the link, such as `next`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the relation
+    /// type contains control or non-ASCII characters.
+    pub fn with_rel(mut self, rel: &str) -> crate::Result<Self> {
+        self.set_param("rel", rel)?;
+        Ok(self)
+    }
+
+    /// Set the media type of the link target.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the media type
+    /// contains control or non-ASCII characters.
+    pub fn with_type(mut self, media_type: &str) -> crate::Result<Self> {
+        self.set_param("type", media_type)?;
+        Ok(self)
+    }
+
+    /// Set the title of the link.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the title
+    /// contains control or non-ASCII characters.
+    pub fn with_title(mut self, title: &str) -> crate::Result<Self> {
+        self.set_param("title", title)?;
+        Ok(self)
+    }
+
+    /// Get the target URI of the link.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Get the relation types of the link, separated by spaces.
+    pub fn rel(&self) -> Option<&str> {
+        self.param("rel")
+    }
+
+    /// Returns `true` if the link has the given relation type.
+    pub fn has_rel(&self, rel: &str) -> bool {
+        match self.rel() {
+            Some(rels) => rels.split_whitespace().any(|r| r.eq_ignore_ascii_case(rel)),
+            None => false,
+        }
+    }
+
+    /// Get the media type of the link target.
+    pub fn link_type(&self) -> Option<&str> {
+        self.param("type")
+    }
+
+    /// Get the title of the link.
+    pub fn title(&self) -> Option<&str> {
+        self.param("title")
+    }
+
+    /// Get the value of a parameter by its name.
+    ///
+    /// Returns `Some("")` for a parameter without a value.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set a parameter, replacing any existing parameter with the same name.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the name isn't
+    /// a valid token, or the value contains control or non-ASCII characters.
+    pub fn set_param(&mut self, name: &str, value: &str) -> crate::Result<()> {
+        if !matches!(parse_token(name), (Some(_), "")) {
+            bail!(400, "invalid link parameter name `{}`", name.escape_debug());
+        }
+        if !is_quotable(value) {
+            bail!(
+                400,
+                "invalid link parameter value `{}`",
+                value.escape_debug()
+            );
+        }
+        let name = name.to_ascii_lowercase();
+        let value = value.to_owned();
+        match self.params.iter_mut().find(|(param, _)| *param == name) {
+            Some(param) => param.1 = value,
+            None => self.params.push((name, value)),
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LinkValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.target)?;
+        for (name, value) in &self.params {
+            write!(f, "; {}={}", name, format_quoted_string(value))?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that a string only contains characters allowed in a URI reference.
+fn is_uri_reference(s: &str) -> bool {
+    s.bytes()
+        .all(|b| b.is_ascii_graphic() && !matches!(b, b'<' | b'>' | b'"'))
+}
+
+/// Parse a comma-separated list of links.
+fn parse_links(input: &str) -> crate::Result<Vec<LinkValue>> {
+    parse_list(input, "link", |input| {
+        let (target, rest) = match input.strip_prefix('<').and_then(|s| s.split_once('>')) {
+            Some((target, rest)) if is_uri_reference(target) => (target, rest),
+            _ => bail!(400, "invalid link target"),
+        };
+        let mut link = LinkValue {
+            target: target.to_owned(),
+            params: vec![],
+        };
+
+        let rest = parse_params(rest, |input| {
+            let (name, rest) = match parse_token(input) {
+                (Some(name), rest) => (name.to_ascii_lowercase(), skip_ows(rest)),
+                (None, _) => bail!(400, "invalid link parameter"),
+            };
+            let (value, rest) = match rest.strip_prefix('=').map(skip_ows) {
+                None => (String::new(), rest),
+                Some(rest) => match parse_token(rest) {
+                    (Some(value), rest) => (value.to_owned(), rest),
+                    (None, _) => match parse_quoted_string(rest) {
+                        (Some(value), rest) if is_quotable(&value) => (value.into_owned(), rest),
+                        _ => bail!(400, "invalid link parameter value"),
+                    },
+                },
+            };
+            // Only the first occurrence of a parameter is used.
+            if link.param(&name).is_none() {
+                link.params.push((name, value));
+            }
+            Ok(rest)
+        })?;
+        Ok((link, rest))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn parse_next_and_prev() -> crate::Result<()> {
+        let value = r#"<https://api.example.com/items?page=3>; rel="next"; type="application/json", <https://api.example.com/items?page=1>; rel="prev"; title="Previous, page""#;
+        let mut headers = Headers::new();
+        headers.insert(LINK, value)?;
+
+        let link = Link::from_headers(&headers)?.unwrap();
+        assert_eq!(link.iter().count(), 2);
+        let next = link.find_rel("next").unwrap();
+        assert_eq!(next.target(), "https://api.example.com/items?page=3");
+        assert_eq!(next.link_type(), Some("application/json"));
+        let prev = link.find_rel("PREV").unwrap();
+        assert_eq!(prev.target(), "https://api.example.com/items?page=1");
+        assert_eq!(prev.title(), Some("Previous, page"));
+
+        let mut output = Headers::new();
+        link.apply(&mut output);
+        assert_eq!(output[LINK], value);
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_rels_and_unquoted_params() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(LINK, "</style.css>;rel=preload;as=style;nopush")?;
+        let link = Link::from_headers(headers)?.unwrap();
+        let style = link.iter().next().unwrap();
+        assert_eq!(style.rel(), Some("preload"));
+        assert_eq!(style.param("as"), Some("style"));
+        assert_eq!(style.param("nopush"), Some(""));
+
+        let link = LinkValue::new("/")?.with_rel("start Index")?;
+        assert!(link.has_rel("index"));
+        assert_eq!(link.to_string(), r#"</>; rel="start Index""#);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_links() -> crate::Result<()> {
+        assert_eq!(LinkValue::new("/a\r\nb").unwrap_err().status(), 400);
+        assert_eq!(LinkValue::new("</a>").unwrap_err().status(), 400);
+
+        let mut link = LinkValue::new("/")?;
+        let err = link
+            .set_param("title", "a\r\nSet-Cookie: evil=1")
+            .unwrap_err();
+        assert_eq!(err.status(), 400);
+        assert!(link.set_param("a b", "c").is_err());
+        assert!(link.clone().with_title("café").is_err());
+        assert_eq!(link.title(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() -> crate::Result<()> {
+        for value in &["/no-brackets", "</a> rel=next", "</a>; =next", "</a b>"] {
+            let mut headers = Headers::new();
+            headers.insert(LINK, *value)?;
+            let err = Link::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+        Ok(())
+    }
+}
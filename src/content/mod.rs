@@ -35,22 +35,44 @@ pub mod accept;
 pub mod accept_encoding;
 pub mod content_encoding;
 
+mod accept_ranges;
+#[cfg(feature = "digest")]
+mod content_digest;
 mod content_length;
 mod content_location;
+#[cfg(feature = "md5")]
+mod content_md5;
+mod content_range;
 mod content_type;
+#[cfg(feature = "digest")]
+mod digest;
 mod encoding;
 mod encoding_proposal;
+mod link;
 mod media_type_proposal;
+mod range;
 
 #[doc(inline)]
 pub use accept::Accept;
 #[doc(inline)]
 pub use accept_encoding::AcceptEncoding;
+pub use accept_ranges::AcceptRanges;
+#[cfg(feature = "digest")]
+pub use content_digest::ContentDigest;
 #[doc(inline)]
 pub use content_encoding::ContentEncoding;
 pub use content_length::ContentLength;
 pub use content_location::ContentLocation;
+#[cfg(feature = "md5")]
+pub use content_md5::ContentMd5;
+pub use content_range::ContentRange;
 pub use content_type::ContentType;
+#[cfg(feature = "digest")]
+pub(crate) use digest::DigestReader;
+#[cfg(feature = "digest")]
+pub use digest::{DigestAlgorithm, DigestHandle};
 pub use encoding::Encoding;
 pub use encoding_proposal::EncodingProposal;
+pub use link::{Link, LinkValue};
 pub use media_type_proposal::MediaTypeProposal;
+pub use range::{ByteRange, Range};
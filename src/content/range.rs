@@ -0,0 +1,272 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, RANGE};
+use crate::StatusCode;
+
+use std::fmt::{self, Display, Write};
+use std::ops::RangeInclusive;
+use std::slice;
+
+/// Request only part of a representation, in bytes.
+///
+/// Ranges are resolved against the size of the representation with
+/// [`Range::satisfiable`], which returns an error with status
+/// `416: Requested Range Not Satisfiable` if none of the ranges overlap the
+/// representation.
+///
+/// # Specifications
+///
+/// - [RFC 7233, section 3.1: Range](https://tools.ietf.org/html/rfc7233#section-3.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::content::{ByteRange, Range};
+/// use http_types::{Method, Request};
+///
+/// let mut range = Range::new();
+/// range.push(ByteRange::FromTo(0, 499));
+/// range.push(ByteRange::Suffix(500));
+///
+/// let mut req = Request::new(Method::Get, "https://example.com/video.mp4");
+/// req.insert_header(&range, &range);
+/// assert_eq!(req["Range"], "bytes=0-499, -500");
+///
+/// let range = Range::from_headers(req)?.unwrap();
+/// assert_eq!(range.satisfiable(1200)?, vec![0..=499, 700..=1199]);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Range {
+    ranges: Vec<ByteRange>,
+}
+
+/// A single byte range in a `Range` header.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ByteRange {
+    /// The bytes from the first position up to and including the last
+    /// position, e.g. `500-999`.
+    FromTo(u64, u64),
+    /// The bytes from the position until the end, e.g. `500-`.
+    From(u64),
+    /// The final number of bytes, e.g. `-500`.
+    Suffix(u64),
+}
+
+impl ByteRange {
+    /// Resolve the range against the size of a representation, returning the
+    /// first and last position of the range, or `None` if the range is not
+    /// satisfiable.
+    pub fn resolve(&self, size: u64) -> Option<RangeInclusive<u64>> {
+        match *self {
+            ByteRange::FromTo(start, end) if start < size => Some(start..=end.min(size - 1)),
+            ByteRange::From(start) if start < size => Some(start..=size - 1),
+            ByteRange::Suffix(len) if len > 0 && size > 0 => {
+                Some(size.saturating_sub(len)..=size - 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse(s: &str) -> crate::Result<Self> {
+        let s = s.trim();
+        let (start, end) = match s.find('-') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => crate::bail_status!(400, "Invalid byte range {:?}", s),
+        };
+        let parse = |pos: &str| -> crate::Result<u64> {
+            crate::ensure_status!(
+                !pos.is_empty() && pos.bytes().all(|b| b.is_ascii_digit()),
+                400,
+                "Invalid byte range {:?}",
+                s
+            );
+            pos.parse().map_err(|_| {
+                crate::Error::from_str(StatusCode::BadRequest, "Byte range out of bounds")
+            })
+        };
+        match (start.is_empty(), end.is_empty()) {
+            (true, _) => Ok(ByteRange::Suffix(parse(end)?)),
+            (false, true) => Ok(ByteRange::From(parse(start)?)),
+            (false, false) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                crate::ensure_status!(start <= end, 400, "Invalid byte range {:?}", s);
+                Ok(ByteRange::FromTo(start, end))
+            }
+        }
+    }
+}
+
+impl Display for ByteRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ByteRange::FromTo(start, end) => write!(f, "{}-{}", start, end),
+            ByteRange::From(start) => write!(f, "{}-", start),
+            ByteRange::Suffix(len) => write!(f, "-{}", len),
+        }
+    }
+}
+
+impl Range {
+    /// Create a new instance of `Range`.
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header is
+    /// malformed, or uses a unit other than `bytes`.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(RANGE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        let ranges = match value.find('=') {
+            Some(i) if value[..i].trim().eq_ignore_ascii_case("bytes") => &value[i + 1..],
+            _ => crate::bail_status!(400, "Expected a byte range, found {:?}", value),
+        };
+
+        let ranges = ranges
+            .split(',')
+            .filter(|range| !range.trim().is_empty())
+            .map(ByteRange::parse)
+            .collect::<crate::Result<Vec<_>>>()?;
+        crate::ensure_status!(!ranges.is_empty(), 400, "Expected at least one byte range");
+        Ok(Some(Self { ranges }))
+    }
+
+    /// Push a byte range into the list of ranges.
+    pub fn push(&mut self, range: impl Into<ByteRange>) {
+        self.ranges.push(range.into());
+    }
+
+    /// An iterator visiting all byte ranges.
+    pub fn iter(&self) -> slice::Iter<'_, ByteRange> {
+        self.ranges.iter()
+    }
+
+    /// Resolve the ranges against the size of a representation.
+    ///
+    /// Ranges that are not satisfiable are skipped. The remaining ranges are
+    /// returned in the order they were requested; overlapping ranges are not
+    /// merged.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `416: Requested Range Not Satisfiable` is returned
+    /// if none of the ranges are satisfiable.
+    pub fn satisfiable(&self, size: u64) -> crate::Result<Vec<RangeInclusive<u64>>> {
+        let ranges: Vec<_> = self.ranges.iter().filter_map(|r| r.resolve(size)).collect();
+        crate::ensure_status!(
+            !ranges.is_empty(),
+            416,
+            "None of the requested ranges are satisfiable"
+        );
+        Ok(ranges)
+    }
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for Range {
+    fn header_name(&self) -> HeaderName {
+        RANGE
+    }
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::from("bytes=");
+        for (n, range) in self.ranges.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", range).unwrap(),
+                _ => write!(output, ", {}", range).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(value: &str) -> crate::Result<Range> {
+        let mut headers = Headers::new();
+        headers.insert(RANGE, value)?;
+        Ok(Range::from_headers(headers)?.unwrap())
+    }
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let range = parse("bytes=0-499,500-, -200")?;
+        let ranges: Vec<_> = range.iter().copied().collect();
+        assert_eq!(
+            ranges,
+            [
+                ByteRange::FromTo(0, 499),
+                ByteRange::From(500),
+                ByteRange::Suffix(200)
+            ]
+        );
+
+        let mut headers = Headers::new();
+        range.apply_header(&mut headers);
+        assert_eq!(headers[RANGE], "bytes=0-499, 500-, -200");
+        Ok(())
+    }
+
+    #[test]
+    fn suffix_ranges() -> crate::Result<()> {
+        let range = parse("bytes=-500")?;
+        assert_eq!(range.satisfiable(10_000)?, vec![9500..=9999]);
+        // A suffix longer than the representation selects all of it.
+        assert_eq!(range.satisfiable(100)?, vec![0..=99]);
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_ranges_are_not_merged() -> crate::Result<()> {
+        let range = parse("bytes=0-99,50-149,900-")?;
+        assert_eq!(range.satisfiable(1000)?, vec![0..=99, 50..=149, 900..=999]);
+        // Ranges running past the end are truncated.
+        assert_eq!(range.satisfiable(120)?, vec![0..=99, 50..=119]);
+        Ok(())
+    }
+
+    #[test]
+    fn unsatisfiable_range() -> crate::Result<()> {
+        let range = parse("bytes=1000-1999, -0")?;
+        let err = range.satisfiable(1000).unwrap_err();
+        assert_eq!(err.status(), 416);
+
+        let err = parse("bytes=-10")?.satisfiable(0).unwrap_err();
+        assert_eq!(err.status(), 416);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &[
+            "items=0-1",
+            "bytes=",
+            "bytes=5-1",
+            "bytes=a-b",
+            "bytes=1-2-3",
+        ] {
+            let err = parse(value).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::bail_status as bail;
+use crate::cookies::{is_cookie_name, is_cookie_value};
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, COOKIE};
+
+use std::fmt::Write;
+use std::iter::Iterator;
+use std::slice;
+
+/// The cookies sent by a client, as found in the `Cookie` request header.
+///
+/// # Specifications
+///
+/// - [RFC 6265, section 5.4: The Cookie Header](https://tools.ietf.org/html/rfc6265#section-5.4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::{Method, Request};
+/// use http_types::cookies::CookieHeader;
+///
+/// let mut cookies = CookieHeader::new();
+/// cookies.push("session", "8a7e6f")?;
+/// cookies.push("theme", "dark")?;
+///
+/// let mut req = Request::new(Method::Get, "https://example.com");
+/// req.insert_header(&cookies, &cookies)?;
+///
+/// let cookies = CookieHeader::from_headers(req)?.unwrap();
+/// assert_eq!(cookies.get("session"), Some("8a7e6f"));
+/// assert_eq!(cookies.get("theme"), Some("dark"));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CookieHeader {
+    entries: Vec<(String, String)>,
+}
+
+impl CookieHeader {
+    /// Create a new instance of `CookieHeader`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Clients should only ever send a single `Cookie` header, but if
+    /// multiple headers are found their pairs are combined.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(COOKIE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut entries = vec![];
+        for value in headers {
+            for pair in value.as_str().split(';') {
+                let pair = pair.trim();
+                if pair.is_empty() {
+                    continue;
+                }
+                let (name, value) = match pair.find('=') {
+                    Some(i) => (pair[..i].trim(), pair[i + 1..].trim()),
+                    None => bail!(400, "Expected a cookie-pair"),
+                };
+                if name.is_empty() {
+                    bail!(400, "Cookie names may not be empty");
+                }
+                entries.push((name.to_string(), value.to_string()));
+            }
+        }
+
+        Ok(Some(Self { entries }))
+    }
+
+    /// Sets the `Cookie` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Push a name/value pair into the list of cookies.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `500: Internal Server Error` is returned if the
+    /// name isn't a valid token, or the value contains characters which
+    /// aren't allowed in a cookie value.
+    pub fn push(&mut self, name: impl Into<String>, value: impl Into<String>) -> crate::Result<()> {
+        let (name, value) = (name.into(), value.into());
+        if !is_cookie_name(&name) {
+            bail!(500, "Invalid cookie name {:?}", name);
+        }
+        if !is_cookie_value(&value) {
+            bail!(500, "Invalid cookie value {:?}", value);
+        }
+        self.entries.push((name, value));
+        Ok(())
+    }
+
+    /// Get the value of the first cookie with the given name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// An iterator visiting all name/value pairs.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+}
+
+impl Header for CookieHeader {
+    fn header_name(&self) -> HeaderName {
+        COOKIE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, (name, value)) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}={}", name, value).unwrap(),
+                _ => write!(output, "; {}={}", name, value).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl<'a> IntoIterator for &'a CookieHeader {
+    type Item = (&'a str, &'a str);
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowing iterator over the name/value pairs in `CookieHeader`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, (String, String)>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(COOKIE, "SID=31d4d96e407aad42; lang=en-US")?;
+
+        let cookies = CookieHeader::from_headers(&headers)?.unwrap();
+        let mut iter = cookies.iter();
+        assert_eq!(iter.next(), Some(("SID", "31d4d96e407aad42")));
+        assert_eq!(iter.next(), Some(("lang", "en-US")));
+        assert_eq!(iter.next(), None);
+
+        let mut headers = Headers::new();
+        cookies.apply(&mut headers);
+        assert_eq!(headers[COOKIE], "SID=31d4d96e407aad42; lang=en-US");
+        Ok(())
+    }
+
+    #[test]
+    fn reexports_cookie_crate() {
+        let cookie = crate::cookies::Cookie::new("SID", "31d4d96e407aad42");
+        assert_eq!(cookie.name(), "SID");
+    }
+
+    #[test]
+    fn reject_invalid_cookies() {
+        let mut cookies = CookieHeader::new();
+        for (name, value) in &[
+            ("", "a"),
+            ("a b", "c"),
+            ("a", "b; c=d"),
+            ("a", "b\r\nSet-Cookie: evil=1"),
+            ("a", "café"),
+        ] {
+            let err = cookies.push(*name, *value).unwrap_err();
+            assert_eq!(err.status(), 500, "{:?}={:?}", name, value);
+        }
+        assert_eq!(cookies.iter().count(), 0);
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let mut headers = Headers::new();
+        headers.insert(COOKIE, "SID").unwrap();
+        let err = CookieHeader::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -0,0 +1,46 @@
+//! HTTP cookies.
+//!
+//! This module re-exports the [`cookie`](https://docs.rs/cookie) crate, and
+//! provides typed `Cookie` and `Set-Cookie` headers on top of it.
+//!
+//! # Specifications
+//!
+//! - [RFC 6265: HTTP State Management Mechanism](https://tools.ietf.org/html/rfc6265)
+
+pub use cookie::*;
+
+mod cookie_header;
+mod set_cookie;
+
+pub use cookie_header::CookieHeader;
+pub use set_cookie::SetCookie;
+
+use crate::parse_utils::parse_token;
+
+/// Check that a string is a valid cookie name, which is a token.
+///
+/// https://tools.ietf.org/html/rfc6265#section-4.1.1
+fn is_cookie_name(s: &str) -> bool {
+    matches!(parse_token(s), (Some(_), ""))
+}
+
+/// Check that a string is a valid cookie value, which may be wrapped in
+/// double quotes.
+///
+/// https://tools.ietf.org/html/rfc6265#section-4.1.1
+fn is_cookie_value(s: &str) -> bool {
+    let s = match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(s) => s,
+        None => s,
+    };
+    s.bytes()
+        .all(|b| matches!(b, 0x21 | 0x23..=0x2B | 0x2D..=0x3A | 0x3C..=0x5B | 0x5D..=0x7E))
+}
+
+/// Check that a string is a valid cookie attribute value, which may not
+/// contain control characters or `;`.
+///
+/// https://tools.ietf.org/html/rfc6265#section-4.1.1
+fn is_attribute_value(s: &str) -> bool {
+    s.bytes().all(|b| (0x20..0x7F).contains(&b) && b != b';')
+}
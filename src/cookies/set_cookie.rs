@@ -0,0 +1,386 @@
+use crate::bail_status as bail;
+use crate::cookies::{is_attribute_value, is_cookie_name, is_cookie_value, SameSite};
+use crate::ensure_status as ensure;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, SET_COOKIE};
+use crate::utils::{fmt_http_date, parse_http_date};
+use crate::Status;
+
+use std::fmt::Write;
+use std::time::{Duration, SystemTime};
+
+/// A cookie the server asks the client to store, as found in the `Set-Cookie`
+/// response header.
+///
+/// Setting `SameSite=None` forces the `Secure` attribute, since browsers
+/// reject cross-site cookies which aren't marked as secure.
+///
+/// # Specifications
+///
+/// - [RFC 6265, section 4.1: Set-Cookie](https://tools.ietf.org/html/rfc6265#section-4.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::cookies::{SameSite, SetCookie};
+/// use std::time::Duration;
+///
+/// let mut cookie = SetCookie::new("session", "8a7e6f")?;
+/// cookie
+///     .set_path("/")?
+///     .set_max_age(Duration::from_secs(3600))
+///     .set_http_only(true)
+///     .set_same_site(SameSite::Lax);
+///
+/// let mut res = Response::new(200);
+/// res.insert_header(&cookie, &cookie)?;
+/// assert_eq!(
+///     res["Set-Cookie"],
+///     "session=8a7e6f; Path=/; Max-Age=3600; HttpOnly; SameSite=Lax"
+/// );
+///
+/// let cookie = SetCookie::from_headers(res)?.unwrap();
+/// assert_eq!(cookie.name(), "session");
+/// assert_eq!(cookie.path(), Some("/"));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SetCookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    domain: Option<String>,
+    max_age: Option<Duration>,
+    expires: Option<SystemTime>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+impl SetCookie {
+    /// Create a new instance of `SetCookie`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `500: Internal Server Error` is returned if the
+    /// name isn't a valid token, or the value contains characters which
+    /// aren't allowed in a cookie value.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> crate::Result<Self> {
+        let (name, value) = (name.into(), value.into());
+        if !is_cookie_name(&name) {
+            bail!(500, "Invalid cookie name {:?}", name);
+        }
+        if !is_cookie_value(&value) {
+            bail!(500, "Invalid cookie value {:?}", value);
+        }
+        Ok(Self {
+            name,
+            value,
+            path: None,
+            domain: None,
+            max_age: None,
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        })
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Only the last `Set-Cookie` header is parsed. Unknown attributes are
+    /// ignored, and a cookie with `SameSite=None` but without `Secure` is
+    /// rejected.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(SET_COOKIE) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap();
+
+        let mut parts = value.as_str().split(';');
+        let (name, value) = match parts.next().unwrap().split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => (name.trim(), value.trim()),
+            _ => bail!(400, "Expected a cookie-pair"),
+        };
+
+        let mut cookie = Self::new(name, value).map_err(|mut err| {
+            err.set_status(400);
+            err
+        })?;
+        for attr in parts {
+            let (key, value) = match attr.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => (attr.trim(), None),
+            };
+            match (key.to_ascii_lowercase().as_str(), value) {
+                ("path", Some(path)) => cookie.path = Some(path.to_string()),
+                ("domain", Some(domain)) => cookie.domain = Some(domain.to_string()),
+                ("max-age", Some(secs)) => {
+                    // Zero or negative values expire the cookie immediately.
+                    let secs: i64 = secs.parse().status(400)?;
+                    cookie.max_age = Some(Duration::from_secs(secs.max(0) as u64));
+                }
+                ("expires", Some(date)) => cookie.expires = Some(parse_http_date(date)?),
+                ("secure", _) => cookie.secure = true,
+                ("httponly", _) => cookie.http_only = true,
+                ("samesite", Some(same_site)) => {
+                    cookie.same_site = match same_site.to_ascii_lowercase().as_str() {
+                        "strict" => Some(SameSite::Strict),
+                        "lax" => Some(SameSite::Lax),
+                        "none" => Some(SameSite::None),
+                        _ => bail!(400, "Invalid SameSite attribute"),
+                    }
+                }
+                _ => { /* unknown attributes are ignored */ }
+            }
+        }
+
+        ensure!(
+            cookie.same_site != Some(SameSite::None) || cookie.secure,
+            400,
+            "Cookies with SameSite=None must also set Secure"
+        );
+
+        Ok(Some(cookie))
+    }
+
+    /// Sets the `Set-Cookie` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Get the cookie name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the cookie value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Get the `Path` attribute.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Set the `Path` attribute.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `500: Internal Server Error` is returned if the
+    /// path contains control characters, non-ASCII characters, or `;`.
+    pub fn set_path(&mut self, path: impl Into<String>) -> crate::Result<&mut Self> {
+        let path = path.into();
+        if !is_attribute_value(&path) {
+            bail!(500, "Invalid cookie path {:?}", path);
+        }
+        self.path = Some(path);
+        Ok(self)
+    }
+
+    /// Get the `Domain` attribute.
+    pub fn domain(&self) -> Option<&str> {
+        self.domain.as_deref()
+    }
+
+    /// Set the `Domain` attribute.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `500: Internal Server Error` is returned if the
+    /// domain contains control characters, non-ASCII characters, or `;`.
+    pub fn set_domain(&mut self, domain: impl Into<String>) -> crate::Result<&mut Self> {
+        let domain = domain.into();
+        if !is_attribute_value(&domain) {
+            bail!(500, "Invalid cookie domain {:?}", domain);
+        }
+        self.domain = Some(domain);
+        Ok(self)
+    }
+
+    /// Get the `Max-Age` attribute.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Set the `Max-Age` attribute. The duration is truncated to whole seconds.
+    pub fn set_max_age(&mut self, max_age: Duration) -> &mut Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Get the `Expires` attribute.
+    pub fn expires(&self) -> Option<SystemTime> {
+        self.expires
+    }
+
+    /// Set the `Expires` attribute.
+    pub fn set_expires(&mut self, expires: SystemTime) -> &mut Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Returns `true` if the `Secure` attribute is set.
+    ///
+    /// This is always the case when `SameSite=None`.
+    pub fn secure(&self) -> bool {
+        self.secure || self.same_site == Some(SameSite::None)
+    }
+
+    /// Set the `Secure` attribute.
+    pub fn set_secure(&mut self, secure: bool) -> &mut Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Returns `true` if the `HttpOnly` attribute is set.
+    pub fn http_only(&self) -> bool {
+        self.http_only
+    }
+
+    /// Set the `HttpOnly` attribute.
+    pub fn set_http_only(&mut self, http_only: bool) -> &mut Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Get the `SameSite` attribute.
+    pub fn same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+
+    /// Set the `SameSite` attribute.
+    ///
+    /// Setting `SameSite::None` also sets the `Secure` attribute.
+    pub fn set_same_site(&mut self, same_site: SameSite) -> &mut Self {
+        if same_site == SameSite::None {
+            self.secure = true;
+        }
+        self.same_site = Some(same_site);
+        self
+    }
+}
+
+impl Header for SetCookie {
+    fn header_name(&self) -> HeaderName {
+        SET_COOKIE
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = format!("{}={}", self.name, self.value);
+        if let Some(path) = &self.path {
+            write!(output, "; Path={}", path).unwrap();
+        }
+        if let Some(domain) = &self.domain {
+            write!(output, "; Domain={}", domain).unwrap();
+        }
+        if let Some(max_age) = self.max_age {
+            write!(output, "; Max-Age={}", max_age.as_secs()).unwrap();
+        }
+        if let Some(expires) = self.expires {
+            write!(output, "; Expires={}", fmt_http_date(expires)).unwrap();
+        }
+        if self.secure() {
+            output.push_str("; Secure");
+        }
+        if self.http_only {
+            output.push_str("; HttpOnly");
+        }
+        if let Some(same_site) = self.same_site {
+            write!(output, "; SameSite={}", same_site).unwrap();
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn round_trip_all_attributes() -> crate::Result<()> {
+        let mut cookie = SetCookie::new("SID", "31d4d96e407aad42")?;
+        cookie
+            .set_path("/docs")?
+            .set_domain("example.com")?
+            .set_max_age(Duration::from_secs(3600))
+            .set_expires(UNIX_EPOCH + Duration::from_secs(1_286_835_360))
+            .set_secure(true)
+            .set_http_only(true)
+            .set_same_site(SameSite::Strict);
+
+        let mut headers = Headers::new();
+        cookie.apply(&mut headers);
+        assert_eq!(
+            headers[SET_COOKIE],
+            "SID=31d4d96e407aad42; Path=/docs; Domain=example.com; Max-Age=3600; \
+             Expires=Mon, 11 Oct 2010 22:16:00 GMT; Secure; HttpOnly; SameSite=Strict"
+        );
+
+        let parsed = SetCookie::from_headers(headers)?.unwrap();
+        assert_eq!(parsed, cookie);
+        Ok(())
+    }
+
+    #[test]
+    fn same_site_none_forces_secure() -> crate::Result<()> {
+        let mut cookie = SetCookie::new("id", "a3fWa")?;
+        cookie.set_same_site(SameSite::None).set_secure(false);
+        assert!(cookie.secure());
+
+        let mut headers = Headers::new();
+        cookie.apply(&mut headers);
+        assert_eq!(headers[SET_COOKIE], "id=a3fWa; Secure; SameSite=None");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_cookies() -> crate::Result<()> {
+        for (name, value) in &[("", "a"), ("a b", "c"), ("a", "b\r\nSet-Cookie: evil=1")] {
+            let err = SetCookie::new(*name, *value).unwrap_err();
+            assert_eq!(err.status(), 500, "{:?}={:?}", name, value);
+        }
+
+        let mut cookie = SetCookie::new("id", "\"a3fWa\"")?;
+        let err = cookie.set_path("/; Domain=evil.com").unwrap_err();
+        assert_eq!(err.status(), 500);
+        let err = cookie
+            .set_domain("example.com\r\nX-Injected: 1")
+            .unwrap_err();
+        assert_eq!(err.status(), 500);
+        assert!(cookie.set_domain("exämple.com").is_err());
+        assert_eq!(cookie.path(), None);
+        assert_eq!(cookie.domain(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_insecure_same_site_none() {
+        let mut headers = Headers::new();
+        headers
+            .insert(SET_COOKIE, "id=a3fWa; SameSite=None")
+            .unwrap();
+        let err = SetCookie::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let mut headers = Headers::new();
+        headers.insert(SET_COOKIE, "id; Secure").unwrap();
+        let err = SetCookie::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
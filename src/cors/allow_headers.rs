@@ -0,0 +1,136 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCESS_CONTROL_ALLOW_HEADERS};
+
+use std::fmt::Write;
+use std::slice;
+use std::str::FromStr;
+
+/// List the request headers allowed in response to a preflight request.
+///
+/// The same format is used by the `Access-Control-Request-Headers` and
+/// `Access-Control-Expose-Headers` headers, which can be read and written
+/// using [`AccessControlAllowHeaders::from_headers_named`] and
+/// [`AccessControlAllowHeaders::apply_named`].
+///
+/// # Specifications
+///
+/// - [WhatWG Fetch Standard: `Access-Control-Allow-Headers` header](https://fetch.spec.whatwg.org/#http-access-control-allow-headers)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::AccessControlAllowHeaders;
+/// use http_types::Response;
+///
+/// let mut allow_headers = AccessControlAllowHeaders::new();
+/// allow_headers.push("X-Requested-With");
+/// allow_headers.push("Content-Type");
+///
+/// let mut res = Response::new(204);
+/// allow_headers.apply(&mut res);
+/// assert_eq!(
+///     res["Access-Control-Allow-Headers"],
+///     "x-requested-with, content-type"
+/// );
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccessControlAllowHeaders {
+    headers: Vec<HeaderName>,
+}
+
+impl AccessControlAllowHeaders {
+    /// Create a new instance of `AccessControlAllowHeaders`.
+    pub fn new() -> Self {
+        Self { headers: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        Self::from_headers_named(headers, ACCESS_CONTROL_ALLOW_HEADERS)
+    }
+
+    /// Create a new instance from a header with the same format, such as
+    /// `Access-Control-Request-Headers`.
+    pub fn from_headers_named(
+        headers: impl AsRef<Headers>,
+        name: HeaderName,
+    ) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(name) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut list = Self::new();
+        for value in headers {
+            for part in value.as_str().split(',') {
+                let part = part.trim();
+                if !part.is_empty() {
+                    list.push(HeaderName::from_str(part)?);
+                }
+            }
+        }
+        Ok(Some(list))
+    }
+
+    /// Push a header name into the list. Names which are already listed are
+    /// ignored.
+    pub fn push(&mut self, name: impl Into<HeaderName>) {
+        let name = name.into();
+        if !self.contains(&name) {
+            self.headers.push(name);
+        }
+    }
+
+    /// Returns `true` if the header name is listed.
+    pub fn contains(&self, name: &HeaderName) -> bool {
+        self.headers.contains(name)
+    }
+
+    /// Returns `true` if no header names are listed.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// An iterator visiting all header names.
+    pub fn iter(&self) -> slice::Iter<'_, HeaderName> {
+        self.headers.iter()
+    }
+
+    /// Sets the `Access-Control-Allow-Headers` header.
+    pub fn apply(&self, headers: impl AsMut<Headers>) {
+        self.apply_named(headers, ACCESS_CONTROL_ALLOW_HEADERS);
+    }
+
+    /// Sets a header with the same format, such as
+    /// `Access-Control-Expose-Headers`.
+    pub fn apply_named(&self, mut headers: impl AsMut<Headers>, name: HeaderName) {
+        headers.as_mut().insert(name, self.header_value()).unwrap();
+    }
+}
+
+impl Default for AccessControlAllowHeaders {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for AccessControlAllowHeaders {
+    fn header_name(&self) -> HeaderName {
+        ACCESS_CONTROL_ALLOW_HEADERS
+    }
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, name) in self.headers.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", name).unwrap(),
+                _ => write!(output, ", {}", name).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCESS_CONTROL_ALLOW_METHODS};
+use crate::Method;
+
+use std::fmt::Write;
+use std::slice;
+use std::str::FromStr;
+
+/// List the methods allowed in response to a preflight request.
+///
+/// # Specifications
+///
+/// - [WhatWG Fetch Standard: `Access-Control-Allow-Methods` header](https://fetch.spec.whatwg.org/#http-access-control-allow-methods)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::AccessControlAllowMethods;
+/// use http_types::{Method, Response};
+///
+/// let mut methods = AccessControlAllowMethods::new();
+/// methods.push(Method::Get);
+/// methods.push(Method::Put);
+///
+/// let mut res = Response::new(204);
+/// methods.apply(&mut res);
+/// assert_eq!(res["Access-Control-Allow-Methods"], "GET, PUT");
+///
+/// let methods = AccessControlAllowMethods::from_headers(res)?.unwrap();
+/// assert!(methods.contains(Method::Put));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AccessControlAllowMethods {
+    methods: Vec<Method>,
+}
+
+impl AccessControlAllowMethods {
+    /// Create a new instance of `AccessControlAllowMethods`.
+    pub fn new() -> Self {
+        Self { methods: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(ACCESS_CONTROL_ALLOW_METHODS) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut methods = Self::new();
+        for value in headers {
+            for part in value.as_str().split(',') {
+                let part = part.trim();
+                if !part.is_empty() {
+                    methods.push(Method::from_str(part)?);
+                }
+            }
+        }
+        Ok(Some(methods))
+    }
+
+    /// Push a method into the list. Methods which are already listed are
+    /// ignored.
+    pub fn push(&mut self, method: Method) {
+        if !self.contains(method) {
+            self.methods.push(method);
+        }
+    }
+
+    /// Returns `true` if the method is listed.
+    pub fn contains(&self, method: Method) -> bool {
+        self.methods.contains(&method)
+    }
+
+    /// An iterator visiting all methods.
+    pub fn iter(&self) -> slice::Iter<'_, Method> {
+        self.methods.iter()
+    }
+
+    /// Sets the `Access-Control-Allow-Methods` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+}
+
+impl Default for AccessControlAllowMethods {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for AccessControlAllowMethods {
+    fn header_name(&self) -> HeaderName {
+        ACCESS_CONTROL_ALLOW_METHODS
+    }
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, method) in self.methods.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", method).unwrap(),
+                _ => write!(output, ", {}", method).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
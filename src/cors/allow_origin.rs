@@ -0,0 +1,112 @@
+use crate::cors::Origin;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ACCESS_CONTROL_ALLOW_ORIGIN};
+
+/// Indicate whether a response can be shared with the requesting origin.
+///
+/// # Specifications
+///
+/// - [WhatWG Fetch Standard: `Access-Control-Allow-Origin` header](https://fetch.spec.whatwg.org/#http-access-control-allow-origin)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::{AccessControlAllowOrigin, Origin};
+/// use http_types::Response;
+///
+/// let origin: Origin = "https://example.com".parse()?;
+/// let allow_origin = AccessControlAllowOrigin::Origin(origin);
+///
+/// let mut res = Response::new(200);
+/// allow_origin.apply(&mut res);
+/// assert_eq!(res["Access-Control-Allow-Origin"], "https://example.com");
+///
+/// let allow_origin = AccessControlAllowOrigin::from_headers(res)?.unwrap();
+/// assert!(!allow_origin.is_any());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AccessControlAllowOrigin {
+    /// The response can be shared with any origin, sent as `*`.
+    ///
+    /// The wildcard can't be used for requests with credentials.
+    Any,
+    /// The response can be shared with this origin only.
+    Origin(Origin),
+}
+
+impl AccessControlAllowOrigin {
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(ACCESS_CONTROL_ALLOW_ORIGIN) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        match value {
+            "*" => Ok(Some(Self::Any)),
+            value => Ok(Some(Self::Origin(value.parse()?))),
+        }
+    }
+
+    /// Returns `true` if any origin is allowed.
+    pub fn is_any(&self) -> bool {
+        matches!(self, Self::Any)
+    }
+
+    /// Returns `true` if the origin is allowed to read the response.
+    pub fn allows(&self, origin: &Origin) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Origin(allowed) => allowed == origin,
+        }
+    }
+
+    /// Sets the `Access-Control-Allow-Origin` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+}
+
+impl From<Origin> for AccessControlAllowOrigin {
+    fn from(origin: Origin) -> Self {
+        Self::Origin(origin)
+    }
+}
+
+impl Header for AccessControlAllowOrigin {
+    fn header_name(&self) -> HeaderName {
+        ACCESS_CONTROL_ALLOW_ORIGIN
+    }
+    fn header_value(&self) -> HeaderValue {
+        let output = match self {
+            Self::Any => "*".to_string(),
+            Self::Origin(origin) => origin.to_string(),
+        };
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn wildcard() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        AccessControlAllowOrigin::Any.apply(&mut headers);
+        assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+
+        let allow_origin = AccessControlAllowOrigin::from_headers(headers)?.unwrap();
+        assert!(allow_origin.is_any());
+        assert!(allow_origin.allows(&Origin::null()));
+        Ok(())
+    }
+}
@@ -0,0 +1,411 @@
+use crate::conditional::Vary;
+use crate::cors::{
+    AccessControlAllowHeaders, AccessControlAllowMethods, AccessControlAllowOrigin, Origin,
+};
+use crate::headers::{
+    HeaderName, Headers, ACCEPT, ACCEPT_LANGUAGE, ACCESS_CONTROL_ALLOW_CREDENTIALS,
+    ACCESS_CONTROL_EXPOSE_HEADERS, ACCESS_CONTROL_MAX_AGE, ACCESS_CONTROL_REQUEST_HEADERS,
+    ACCESS_CONTROL_REQUEST_METHOD, CONTENT_LANGUAGE, CONTENT_TYPE, ORIGIN,
+};
+use crate::{Method, Request, Response, StatusCode};
+
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Request headers which are always allowed in cross-origin requests.
+const SAFELISTED_HEADERS: [HeaderName; 4] =
+    [ACCEPT, ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_TYPE];
+
+/// A Cross-Origin Resource Sharing policy.
+///
+/// `Cors` computes the response headers for preflight requests with
+/// [`Cors::preflight`], and for the actual cross-origin requests with
+/// [`Cors::apply`].
+///
+/// By default any origin is allowed to make `GET`, `HEAD`, and `POST`
+/// requests. Once an origin is added with [`Cors::allow_origin`], only the
+/// listed origins are allowed.
+///
+/// Requests with credentials can't be answered with the `*` wildcard. When
+/// credentials are allowed, the origins must be listed explicitly: the
+/// request's origin is echoed back only if it's listed. Without a list no
+/// origin is allowed, and the `null` origin is never allowed with credentials.
+///
+/// Once origins are listed, every response gets `Vary: Origin`, whether the
+/// origin is allowed or not, so caches key the response by origin.
+///
+/// # Specifications
+///
+/// - [WhatWG Fetch Standard: CORS protocol](https://fetch.spec.whatwg.org/#http-cors-protocol)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::{Cors, Origin};
+/// use http_types::{Method, Request, StatusCode};
+///
+/// let mut cors = Cors::new();
+/// cors.allow_origin("https://example.com".parse::<Origin>()?)
+///     .allow_method(Method::Put)
+///     .allow_header("X-Requested-With");
+///
+/// let mut req = Request::new(Method::Options, "https://api.example.com/items/1");
+/// req.insert_header("Origin", "https://example.com")?;
+/// req.insert_header("Access-Control-Request-Method", "PUT")?;
+/// assert!(Cors::is_preflight(&req));
+///
+/// let res = cors.preflight(&req)?;
+/// assert_eq!(res.status(), StatusCode::NoContent);
+/// assert_eq!(res["Access-Control-Allow-Origin"], "https://example.com");
+/// assert_eq!(res["Access-Control-Allow-Methods"], "GET, HEAD, POST, PUT");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cors {
+    origins: Option<Vec<Origin>>,
+    methods: AccessControlAllowMethods,
+    headers: AccessControlAllowHeaders,
+    expose_headers: AccessControlAllowHeaders,
+    credentials: bool,
+    max_age: Option<Duration>,
+}
+
+impl Cors {
+    /// Create a new instance of `Cors`.
+    pub fn new() -> Self {
+        let mut methods = AccessControlAllowMethods::new();
+        methods.push(Method::Get);
+        methods.push(Method::Head);
+        methods.push(Method::Post);
+        Self {
+            origins: None,
+            methods,
+            headers: AccessControlAllowHeaders::new(),
+            expose_headers: AccessControlAllowHeaders::new(),
+            credentials: false,
+            max_age: None,
+        }
+    }
+
+    /// Allow an origin. Once an origin has been added, any origin which isn't
+    /// listed is rejected.
+    pub fn allow_origin(&mut self, origin: impl Into<Origin>) -> &mut Self {
+        self.origins
+            .get_or_insert_with(Vec::new)
+            .push(origin.into());
+        self
+    }
+
+    /// Allow a method.
+    pub fn allow_method(&mut self, method: Method) -> &mut Self {
+        self.methods.push(method);
+        self
+    }
+
+    /// Allow a request header. The CORS-safelisted request headers are always
+    /// allowed.
+    pub fn allow_header(&mut self, name: impl Into<HeaderName>) -> &mut Self {
+        self.headers.push(name);
+        self
+    }
+
+    /// Expose a response header to the requesting script.
+    pub fn expose_header(&mut self, name: impl Into<HeaderName>) -> &mut Self {
+        self.expose_headers.push(name);
+        self
+    }
+
+    /// Allow requests with credentials, such as cookies.
+    ///
+    /// Credentialed requests are only allowed from origins added with
+    /// [`Cors::allow_origin`].
+    pub fn allow_credentials(&mut self, credentials: bool) -> &mut Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Set how long the results of a preflight request can be cached. The
+    /// duration is truncated to whole seconds.
+    pub fn max_age(&mut self, max_age: Duration) -> &mut Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns `true` if the request is a CORS preflight request.
+    pub fn is_preflight(req: &Request) -> bool {
+        req.method() == Method::Options
+            && req.header(ORIGIN).is_some()
+            && req.header(ACCESS_CONTROL_REQUEST_METHOD).is_some()
+    }
+
+    /// Create the response to a preflight request.
+    ///
+    /// If the origin, method, or any of the request headers are not allowed,
+    /// the response has status `403: Forbidden` and no CORS headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the request is
+    /// not a valid preflight request.
+    pub fn preflight(&self, req: &Request) -> crate::Result<Response> {
+        let origin = match Origin::from_headers(req)? {
+            Some(origin) => origin,
+            None => crate::bail_status!(400, "Missing the Origin header"),
+        };
+        let method = match req.header(ACCESS_CONTROL_REQUEST_METHOD) {
+            Some(method) => Method::from_str(method.last().as_str().trim()).map_err(|mut e| {
+                e.set_status(StatusCode::BadRequest);
+                e
+            })?,
+            None => crate::bail_status!(400, "Missing the Access-Control-Request-Method header"),
+        };
+
+        let requested =
+            AccessControlAllowHeaders::from_headers_named(req, ACCESS_CONTROL_REQUEST_HEADERS)?
+                .unwrap_or_default();
+
+        let mut res = Response::new(StatusCode::NoContent);
+        self.vary_origin(&mut res)?;
+        let allow_origin = match self.allowed_origin(&origin) {
+            Some(allow_origin) => allow_origin,
+            None => {
+                res.set_status(StatusCode::Forbidden);
+                return Ok(res);
+            }
+        };
+        let headers_allowed = requested
+            .iter()
+            .all(|name| self.headers.contains(name) || SAFELISTED_HEADERS.contains(name));
+        if !self.methods.contains(method) || !headers_allowed {
+            res.set_status(StatusCode::Forbidden);
+            return Ok(res);
+        }
+
+        self.apply_origin(&mut res, allow_origin)?;
+        self.methods.apply(&mut res);
+        if !self.headers.is_empty() {
+            self.headers.apply(&mut res);
+        }
+        if let Some(max_age) = self.max_age {
+            res.insert_header(ACCESS_CONTROL_MAX_AGE, max_age.as_secs().to_string())?;
+        }
+        Ok(res)
+    }
+
+    /// Sets the CORS headers on the response to an actual cross-origin
+    /// request.
+    ///
+    /// Apart from `Vary: Origin`, nothing is set if the request has no
+    /// `Origin` header, or if its origin is not allowed; browsers then don't
+    /// share the response with the requesting script.
+    pub fn apply(
+        &self,
+        req: impl AsRef<Headers>,
+        mut res: impl AsMut<Headers>,
+    ) -> crate::Result<()> {
+        let res = res.as_mut();
+        self.vary_origin(&mut *res)?;
+        let origin = match Origin::from_headers(req)? {
+            Some(origin) => origin,
+            None => return Ok(()),
+        };
+        let allow_origin = match self.allowed_origin(&origin) {
+            Some(allow_origin) => allow_origin,
+            None => return Ok(()),
+        };
+
+        self.apply_origin(&mut *res, allow_origin)?;
+        if !self.expose_headers.is_empty() {
+            self.expose_headers
+                .apply_named(res, ACCESS_CONTROL_EXPOSE_HEADERS);
+        }
+        Ok(())
+    }
+
+    /// Get the `Access-Control-Allow-Origin` value for an origin, or `None`
+    /// if the origin isn't allowed.
+    fn allowed_origin(&self, origin: &Origin) -> Option<AccessControlAllowOrigin> {
+        match &self.origins {
+            None if !self.credentials => Some(AccessControlAllowOrigin::Any),
+            // Echoing any origin with credentials would let every site read
+            // responses with the user's cookies.
+            None => None,
+            Some(_) if self.credentials && origin.is_null() => None,
+            Some(origins) if origins.contains(origin) => Some(origin.clone().into()),
+            Some(_) => None,
+        }
+    }
+
+    /// Add `Vary: Origin` if the response depends on the request's origin,
+    /// which is the case once origins are listed.
+    fn vary_origin(&self, mut headers: impl AsMut<Headers>) -> crate::Result<()> {
+        if self.origins.is_some() {
+            let headers = headers.as_mut();
+            let mut vary = Vary::from_headers(&*headers)?.unwrap_or_else(Vary::new);
+            vary.push(ORIGIN)?;
+            vary.apply(headers);
+        }
+        Ok(())
+    }
+
+    fn apply_origin(
+        &self,
+        mut headers: impl AsMut<Headers>,
+        allow_origin: AccessControlAllowOrigin,
+    ) -> crate::Result<()> {
+        let headers = headers.as_mut();
+        allow_origin.apply(&mut *headers);
+        if self.credentials {
+            headers.insert(ACCESS_CONTROL_ALLOW_CREDENTIALS, "true")?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::ACCESS_CONTROL_ALLOW_ORIGIN;
+    use crate::headers::{ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, VARY};
+
+    fn preflight_request(method: &str, headers: &str) -> crate::Result<Request> {
+        let mut req = Request::new(Method::Options, "https://api.example.com/items");
+        req.insert_header(ORIGIN, "https://app.example.com")?;
+        req.insert_header(ACCESS_CONTROL_REQUEST_METHOD, method)?;
+        req.insert_header(ACCESS_CONTROL_REQUEST_HEADERS, headers)?;
+        Ok(req)
+    }
+
+    #[test]
+    fn preflight() -> crate::Result<()> {
+        let mut cors = Cors::new();
+        cors.allow_origin(Origin::from_str("https://app.example.com")?)
+            .allow_method(Method::Delete)
+            .allow_header("X-Api-Key")
+            .max_age(Duration::from_secs(600));
+
+        let req = preflight_request("DELETE", "x-api-key, content-type")?;
+        assert!(Cors::is_preflight(&req));
+
+        let res = cors.preflight(&req)?;
+        assert_eq!(res.status(), StatusCode::NoContent);
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example.com");
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_METHODS], "GET, HEAD, POST, DELETE");
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_HEADERS], "x-api-key");
+        assert_eq!(res[ACCESS_CONTROL_MAX_AGE], "600");
+        assert_eq!(res[VARY], "origin");
+        assert!(res.header(ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn preflight_rejected() -> crate::Result<()> {
+        let mut cors = Cors::new();
+        cors.allow_origin(Origin::from_str("https://app.example.com")?);
+
+        let req = preflight_request("PATCH", "")?;
+        assert_eq!(cors.preflight(&req)?.status(), StatusCode::Forbidden);
+
+        let req = preflight_request("GET", "x-api-key")?;
+        assert_eq!(cors.preflight(&req)?.status(), StatusCode::Forbidden);
+
+        let mut req = preflight_request("GET", "")?;
+        req.insert_header(ORIGIN, "https://evil.example.com")?;
+        let res = cors.preflight(&req)?;
+        assert_eq!(res.status(), StatusCode::Forbidden);
+        assert!(res.header(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+        assert_eq!(res[VARY], "origin");
+        Ok(())
+    }
+
+    #[test]
+    fn credentialed_simple_request() -> crate::Result<()> {
+        let mut cors = Cors::new();
+        cors.allow_origin(Origin::from_str("https://app.example.com")?)
+            .allow_credentials(true)
+            .expose_header("X-Request-Id");
+
+        let mut req = Request::new(Method::Get, "https://api.example.com/items");
+        req.insert_header(ORIGIN, "https://app.example.com")?;
+
+        let mut res = Response::new(StatusCode::Ok);
+        res.insert_header(VARY, "Accept-Encoding")?;
+        cors.apply(&req, &mut res)?;
+
+        // Credentials forbid the wildcard, so the listed origin is echoed back.
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example.com");
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+        assert_eq!(res[ACCESS_CONTROL_EXPOSE_HEADERS], "x-request-id");
+        assert_eq!(res[VARY], "accept-encoding, origin");
+        Ok(())
+    }
+
+    #[test]
+    fn credentials_require_allowlist() -> crate::Result<()> {
+        let mut cors = Cors::new();
+        cors.allow_credentials(true);
+
+        let mut req = Request::new(Method::Get, "https://api.example.com/items");
+        req.insert_header(ORIGIN, "https://attacker.example")?;
+        let mut res = Response::new(StatusCode::Ok);
+        cors.apply(&req, &mut res)?;
+        assert!(res.header(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+        assert!(res.header(ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+
+        let mut req = preflight_request("GET", "")?;
+        req.insert_header(ORIGIN, "https://attacker.example")?;
+        assert_eq!(cors.preflight(&req)?.status(), StatusCode::Forbidden);
+
+        // `null` is never echoed, even if it's listed.
+        cors.allow_origin(Origin::null());
+        let mut req = Request::new(Method::Get, "https://api.example.com/items");
+        req.insert_header(ORIGIN, "null")?;
+        let mut res = Response::new(StatusCode::Ok);
+        cors.apply(&req, &mut res)?;
+        assert!(res.header(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn rejected_origin_varies() -> crate::Result<()> {
+        let mut cors = Cors::new();
+        cors.allow_origin(Origin::from_str("https://app.example.com")?);
+
+        let mut req = Request::new(Method::Get, "https://api.example.com/items");
+        let mut res = Response::new(StatusCode::Ok);
+        cors.apply(&req, &mut res)?;
+        assert_eq!(res[VARY], "origin");
+
+        req.insert_header(ORIGIN, "https://evil.example.com")?;
+        let mut res = Response::new(StatusCode::Ok);
+        cors.apply(&req, &mut res)?;
+        assert!(res.header(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+        assert_eq!(res[VARY], "origin");
+        Ok(())
+    }
+
+    #[test]
+    fn wildcard_simple_request() -> crate::Result<()> {
+        let cors = Cors::new();
+
+        let mut req = Request::new(Method::Get, "https://api.example.com/items");
+        let mut res = Response::new(StatusCode::Ok);
+        cors.apply(&req, &mut res)?;
+        assert!(res.header(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+        req.insert_header(ORIGIN, "null")?;
+        cors.apply(&req, &mut res)?;
+        assert_eq!(res[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+        assert!(res.header(VARY).is_none());
+        Ok(())
+    }
+}
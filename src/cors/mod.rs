@@ -0,0 +1,46 @@
+//! Cross-Origin Resource Sharing (CORS).
+//!
+//! Browsers only share responses to cross-origin requests with scripts if the
+//! server opts in using the `Access-Control-*` headers. Requests which could
+//! have side effects are preceded by a "preflight" `OPTIONS` request asking
+//! the server for permission.
+//!
+//! # Further Reading
+//!
+//! - [MDN: Cross-Origin Resource Sharing](https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS)
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() -> http_types::Result<()> {
+//! #
+//! use http_types::cors::{Cors, Origin};
+//! use http_types::{Method, Request, Response};
+//!
+//! let mut cors = Cors::new();
+//! cors.allow_origin("https://example.com".parse::<Origin>()?)
+//!     .allow_credentials(true);
+//!
+//! let mut req = Request::new(Method::Get, "https://api.example.com");
+//! req.insert_header("Origin", "https://example.com")?;
+//!
+//! let mut res = Response::new(200);
+//! cors.apply(&req, &mut res)?;
+//! assert_eq!(res["Access-Control-Allow-Origin"], "https://example.com");
+//! assert_eq!(res["Access-Control-Allow-Credentials"], "true");
+//! #
+//! # Ok(()) }
+//! ```
+
+mod allow_headers;
+mod allow_methods;
+mod allow_origin;
+#[allow(clippy::module_inception)]
+mod cors;
+mod origin;
+
+pub use allow_headers::AccessControlAllowHeaders;
+pub use allow_methods::AccessControlAllowMethods;
+pub use allow_origin::AccessControlAllowOrigin;
+pub use cors::Cors;
+pub use origin::{origin_allowed, Origin};
@@ -0,0 +1,228 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, ORIGIN};
+use crate::Url;
+
+use std::fmt::{self, Display};
+
+/// The origin that caused a request.
+///
+/// An origin is either a scheme, host and port serialized as
+/// `https://example.com:8080`, or the opaque `null` origin sent by sandboxed
+/// documents and for privacy-sensitive redirects.
+///
+/// # Specifications
+///
+/// - [RFC 6454, section 7: The HTTP Origin Header Field](https://tools.ietf.org/html/rfc6454#section-7)
+/// - [WhatWG Fetch Standard: `Origin` header](https://fetch.spec.whatwg.org/#origin-header)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::Origin;
+/// use http_types::{Method, Request, Url};
+///
+/// let origin = Origin::new(&Url::parse("https://example.com/index.html")?);
+///
+/// let mut req = Request::new(Method::Get, "https://api.example.com");
+/// origin.apply(&mut req);
+/// assert_eq!(req["Origin"], "https://example.com");
+///
+/// let origin = Origin::from_headers(req)?.unwrap();
+/// assert_eq!(origin.as_str(), "https://example.com");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Origin {
+    origin: String,
+}
+
+impl Origin {
+    /// Create a new instance from the origin of a URL.
+    pub fn new(url: &Url) -> Self {
+        Self {
+            origin: url.origin().ascii_serialization(),
+        }
+    }
+
+    /// Create a new instance of the opaque `null` origin.
+    pub fn null() -> Self {
+        Self {
+            origin: "null".to_string(),
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header is
+    /// not a serialized origin.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(ORIGIN) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        value.parse().map(Some)
+    }
+
+    /// Get the serialized origin.
+    pub fn as_str(&self) -> &str {
+        &self.origin
+    }
+
+    /// Returns `true` if this is the opaque `null` origin.
+    pub fn is_null(&self) -> bool {
+        self.origin == "null"
+    }
+
+    /// Sets the `Origin` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+}
+
+/// Returns `true` if the origin is the origin of one of the `allowed` URLs.
+///
+/// Origins are compared by scheme, host, and port, with default ports
+/// omitted. The opaque `null` origin is never allowed, as it can be sent by
+/// any sandboxed document. This can be used to check the origin of
+/// state-changing requests, to protect against cross-site request forgery.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::cors::{origin_allowed, Origin};
+/// use http_types::Url;
+///
+/// let allowed = [Url::parse("https://example.com")?];
+/// assert!(origin_allowed(Url::parse("https://example.com:443/form")?, &allowed));
+/// assert!(!origin_allowed(Url::parse("http://example.com")?, &allowed));
+/// assert!(!origin_allowed(Origin::null(), &allowed));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn origin_allowed(origin: impl Into<Origin>, allowed: &[Url]) -> bool {
+    let origin = origin.into();
+    if origin.is_null() {
+        return false;
+    }
+    allowed.iter().any(|url| Origin::new(url) == origin)
+}
+
+impl std::str::FromStr for Origin {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "null" {
+            return Ok(Self::null());
+        }
+        // A serialized origin has no path, so it should be unchanged by a
+        // round trip through `Url`.
+        let origin = match Url::parse(s) {
+            Ok(url) if url.origin().is_tuple() => Self::new(&url),
+            _ => crate::bail_status!(400, "Invalid origin {:?}", s),
+        };
+        crate::ensure_status!(
+            origin.origin.eq_ignore_ascii_case(s),
+            400,
+            "Invalid origin {:?}",
+            s
+        );
+        Ok(origin)
+    }
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.origin)
+    }
+}
+
+impl From<Url> for Origin {
+    fn from(url: Url) -> Self {
+        Self::new(&url)
+    }
+}
+
+impl From<&Url> for Origin {
+    fn from(url: &Url) -> Self {
+        Self::new(url)
+    }
+}
+
+impl Header for Origin {
+    fn header_name(&self) -> HeaderName {
+        ORIGIN
+    }
+    fn header_value(&self) -> HeaderValue {
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(self.origin.clone().into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let origin = Origin::new(&Url::parse("http://localhost:8080/a?b#c")?);
+        assert_eq!(origin.as_str(), "http://localhost:8080");
+
+        let mut headers = Headers::new();
+        origin.apply(&mut headers);
+        assert_eq!(Origin::from_headers(headers)?.unwrap(), origin);
+
+        // Default ports are omitted.
+        let origin: Origin = "https://example.com".parse()?;
+        assert_eq!(origin, Origin::new(&Url::parse("https://example.com:443")?));
+
+        let origin: Origin = "null".parse()?;
+        assert!(origin.is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn allowed() -> crate::Result<()> {
+        let allowed = [
+            Url::parse("https://example.com")?,
+            Url::parse("http://localhost:8080")?,
+        ];
+        let url = Url::parse("https://example.com/a")?;
+        assert!(origin_allowed(&url, &allowed));
+        let origin: Origin = "http://localhost:8080".parse()?;
+        assert!(origin_allowed(origin, &allowed));
+
+        // The port is part of the origin.
+        let url = Url::parse("https://example.com:8443")?;
+        assert!(!origin_allowed(&url, &allowed));
+        let origin: Origin = "http://localhost:3000".parse()?;
+        assert!(!origin_allowed(origin, &allowed));
+
+        // The `null` origin is never allowed, even if it's listed.
+        assert!(!origin_allowed(Origin::null(), &allowed));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &[
+            "example.com",
+            "https://example.com/path",
+            "data:text/plain,hi",
+        ] {
+            let mut headers = Headers::new();
+            headers.insert(ORIGIN, *value).unwrap();
+            let err = Origin::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
@@ -36,6 +36,18 @@ impl Error {
     /// The error type must be threadsafe and 'static, so that the Error will be
     /// as well. If the error type does not provide a backtrace, a backtrace will
     /// be created here to ensure that a backtrace exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Error, StatusCode};
+    /// use std::io;
+    ///
+    /// let source = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    /// let err = Error::new(StatusCode::NotFound, source);
+    /// assert_eq!(err.status(), StatusCode::NotFound);
+    /// assert_eq!(err.to_string(), "no such file");
+    /// ```
     pub fn new<S, E>(status: S, error: E) -> Self
     where
         S: TryInto<StatusCode>,
@@ -51,7 +63,17 @@ impl Error {
         }
     }
 
-    /// Create a new error object from static string.
+    /// Create a new error object from a message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Error, StatusCode};
+    ///
+    /// let err = Error::from_str(StatusCode::NotFound, "No user with id 12");
+    /// assert_eq!(err.status(), StatusCode::NotFound);
+    /// assert_eq!(err.to_string(), "No user with id 12");
+    /// ```
     pub fn from_str<S, M>(status: S, msg: M) -> Self
     where
         S: TryInto<StatusCode>,
@@ -66,6 +88,7 @@ impl Error {
             type_name: None,
         }
     }
+
     /// Create a new error from a message.
     pub(crate) fn new_adhoc<M>(message: M) -> Error
     where
@@ -130,6 +153,24 @@ impl Error {
     }
 
     /// Attempt to downcast the error object to a concrete type.
+    ///
+    /// If the error isn't of type `E` the original error is returned, status
+    /// code included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Error, StatusCode};
+    /// use std::io;
+    ///
+    /// let err = Error::new(StatusCode::NotFound, io::Error::from(io::ErrorKind::NotFound));
+    ///
+    /// let err = err.downcast::<std::fmt::Error>().unwrap_err();
+    /// assert_eq!(err.status(), StatusCode::NotFound);
+    ///
+    /// let err: io::Error = err.downcast().unwrap();
+    /// assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    /// ```
     pub fn downcast<E>(self) -> std::result::Result<E, Self>
     where
         E: Display + Debug + Send + Sync + 'static,
@@ -142,6 +183,19 @@ impl Error {
     }
 
     /// Downcast this error object by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Error;
+    /// use std::io;
+    ///
+    /// let err: Error = io::Error::from(io::ErrorKind::TimedOut).into();
+    /// match err.downcast_ref::<io::Error>() {
+    ///     Some(err) => assert_eq!(err.kind(), io::ErrorKind::TimedOut),
+    ///     None => panic!("expected an io::Error"),
+    /// }
+    /// ```
     pub fn downcast_ref<E>(&self) -> Option<&E>
     where
         E: Display + Debug + Send + Sync + 'static,
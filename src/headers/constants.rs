@@ -1,5 +1,9 @@
 use super::HeaderName;
 
+/// The `Content-Digest` Header
+pub const CONTENT_DIGEST: HeaderName = HeaderName::from_lowercase_str("content-digest");
+/// The `Content-Disposition` Header
+pub const CONTENT_DISPOSITION: HeaderName = HeaderName::from_lowercase_str("content-disposition");
 /// The `Content-Encoding` Header
 pub const CONTENT_ENCODING: HeaderName = HeaderName::from_lowercase_str("content-encoding");
 /// The `Content-Language` Header
@@ -117,9 +121,15 @@ pub const IF_RANGE: HeaderName = HeaderName::from_lowercase_str("if-range");
 ///  The `If-Unmodified-Since` Header
 pub const IF_UNMODIFIED_SINCE: HeaderName = HeaderName::from_lowercase_str("if-unmodified-since");
 
+///  The `Keep-Alive` Header
+pub const KEEP_ALIVE: HeaderName = HeaderName::from_lowercase_str("keep-alive");
+
 ///  The `Last-Modified` Header
 pub const LAST_MODIFIED: HeaderName = HeaderName::from_lowercase_str("last-modified");
 
+///  The `Link` Header
+pub const LINK: HeaderName = HeaderName::from_lowercase_str("link");
+
 ///  The `Location` Header
 pub const LOCATION: HeaderName = HeaderName::from_lowercase_str("location");
 
@@ -129,6 +139,15 @@ pub const MAX_FORWARDS: HeaderName = HeaderName::from_lowercase_str("max-forward
 ///  The `Pragma` Header
 pub const PRAGMA: HeaderName = HeaderName::from_lowercase_str("pragma");
 
+///  The `Prefer` Header
+pub const PREFER: HeaderName = HeaderName::from_lowercase_str("prefer");
+
+///  The `Preference-Applied` Header
+pub const PREFERENCE_APPLIED: HeaderName = HeaderName::from_lowercase_str("preference-applied");
+
+///  The `Priority` Header
+pub const PRIORITY: HeaderName = HeaderName::from_lowercase_str("priority");
+
 ///  The `Proxy-Authenticate` Header
 pub const PROXY_AUTHENTICATE: HeaderName = HeaderName::from_lowercase_str("proxy-authenticate");
 
@@ -138,12 +157,29 @@ pub const PROXY_AUTHORIZATION: HeaderName = HeaderName::from_lowercase_str("prox
 /// The `Proxy-Connection` Header
 pub const PROXY_CONNECTION: HeaderName = HeaderName::from_lowercase_str("proxy-connection");
 
+///  The `Range` Header
+pub const RANGE: HeaderName = HeaderName::from_lowercase_str("range");
+
 ///  The `Referer` Header
 pub const REFERER: HeaderName = HeaderName::from_lowercase_str("referer");
 
 ///  The `Retry-After` Header
 pub const RETRY_AFTER: HeaderName = HeaderName::from_lowercase_str("retry-after");
 
+///  The `Sec-WebSocket-Accept` Header
+pub const SEC_WEBSOCKET_ACCEPT: HeaderName = HeaderName::from_lowercase_str("sec-websocket-accept");
+
+///  The `Sec-WebSocket-Key` Header
+pub const SEC_WEBSOCKET_KEY: HeaderName = HeaderName::from_lowercase_str("sec-websocket-key");
+
+///  The `Sec-WebSocket-Protocol` Header
+pub const SEC_WEBSOCKET_PROTOCOL: HeaderName =
+    HeaderName::from_lowercase_str("sec-websocket-protocol");
+
+///  The `Sec-WebSocket-Version` Header
+pub const SEC_WEBSOCKET_VERSION: HeaderName =
+    HeaderName::from_lowercase_str("sec-websocket-version");
+
 ///  The `Server` Header
 pub const SERVER: HeaderName = HeaderName::from_lowercase_str("server");
 
@@ -153,6 +189,10 @@ pub const SERVER_TIMING: HeaderName = HeaderName::from_lowercase_str("server-tim
 ///  The `SourceMap` Header
 pub const SOURCE_MAP: HeaderName = HeaderName::from_lowercase_str("sourcemap");
 
+///  The `Strict-Transport-Security` Header
+pub const STRICT_TRANSPORT_SECURITY: HeaderName =
+    HeaderName::from_lowercase_str("strict-transport-security");
+
 ///  The `Te` Header
 pub const TE: HeaderName = HeaderName::from_lowercase_str("te");
 
@@ -162,6 +202,9 @@ pub const TIMING_ALLOW_ORIGIN: HeaderName = HeaderName::from_lowercase_str("timi
 ///  The `Traceparent` Header
 pub const TRACEPARENT: HeaderName = HeaderName::from_lowercase_str("traceparent");
 
+///  The `Tracestate` Header
+pub const TRACESTATE: HeaderName = HeaderName::from_lowercase_str("tracestate");
+
 ///  The `Trailer` Header
 pub const TRAILER: HeaderName = HeaderName::from_lowercase_str("trailer");
 
@@ -8,8 +8,11 @@ use std::ops::Index;
 use std::str::FromStr;
 
 use crate::headers::{
-    HeaderName, HeaderValues, IntoIter, Iter, IterMut, Names, ToHeaderValues, Values,
+    HeaderName, HeaderValue, HeaderValues, IntoIter, Iter, IterMut, Names, ToHeaderValues, Values,
+    CONNECTION, KEEP_ALIVE, PROXY_AUTHENTICATE, PROXY_AUTHORIZATION, TE, TRAILER,
+    TRANSFER_ENCODING, UPGRADE,
 };
+use crate::parse_utils::parse_token;
 
 /// A collection of HTTP Headers.
 ///
@@ -42,9 +45,10 @@ impl Headers {
 
     /// Insert a header into the headers.
     ///
-    /// Not that this will replace all header values for a given header name.
-    /// If you wish to add header values for a header name that already exists
-    /// use `Headers::append`
+    /// Note that this will replace all header values for a given header name,
+    /// returning the previous values if there were any. If you wish to add
+    /// header values for a header name that already exists use
+    /// `Headers::append`.
     pub fn insert(
         &mut self,
         name: impl Into<HeaderName>,
@@ -59,6 +63,28 @@ impl Headers {
     ///
     /// Unlike `insert` this function will not override the contents of a header, but insert a
     /// header if there aren't any. Or else append to the existing list of headers.
+    ///
+    /// This is the method to use for headers which may be sent multiple times,
+    /// such as `Vary`, `Via`, and `Set-Cookie`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// use http_types::headers::Headers;
+    /// use http_types::Response;
+    ///
+    /// let mut res = Response::new(200);
+    /// let headers: &mut Headers = res.as_mut();
+    /// headers.append("Vary", "Accept")?;
+    /// headers.append("Vary", "Accept-Encoding")?;
+    /// assert_eq!(headers["Vary"], ["Accept", "Accept-Encoding"][..]);
+    ///
+    /// // `insert` replaces all existing values instead.
+    /// headers.insert("Vary", "Origin")?;
+    /// assert_eq!(headers["Vary"], "Origin");
+    /// # Ok(()) }
+    /// ```
     pub fn append(
         &mut self,
         name: impl Into<HeaderName>,
@@ -82,6 +108,42 @@ impl Headers {
         self.headers.get(&name.into())
     }
 
+    /// Get a reference to a header by its name as a string slice.
+    ///
+    /// Names are matched case-insensitively, and surrounding whitespace is
+    /// ignored. Returns `None` if the name is not a valid header name.
+    pub fn get_str(&self, name: &str) -> Option<&HeaderValues> {
+        match parse_token(name.trim()) {
+            (Some(name), "") => self.headers.get(&HeaderName::from_str(name).ok()?),
+            _ => None,
+        }
+    }
+
+    /// An iterator visiting all values of a header, in the order they were
+    /// added.
+    ///
+    /// The iterator is empty if the header isn't present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// use http_types::Response;
+    ///
+    /// let mut res = Response::new(200);
+    /// res.append_header("Set-Cookie", "a=1")?;
+    /// res.append_header("Set-Cookie", "b=2")?;
+    ///
+    /// let headers: &http_types::headers::Headers = res.as_ref();
+    /// let cookies: Vec<_> = headers.get_all("Set-Cookie").map(|v| v.as_str()).collect();
+    /// assert_eq!(cookies, vec!["a=1", "b=2"]);
+    /// assert_eq!(headers.get_all("Cookie").count(), 0);
+    /// # Ok(()) }
+    /// ```
+    pub fn get_all(&self, name: impl Into<HeaderName>) -> impl Iterator<Item = &HeaderValue> {
+        self.get(name).into_iter().flat_map(|values| values.iter())
+    }
+
     /// Get a mutable reference to a header.
     pub fn get_mut(&mut self, name: impl Into<HeaderName>) -> Option<&mut HeaderValues> {
         self.headers.get_mut(&name.into())
@@ -92,13 +154,159 @@ impl Headers {
         self.headers.remove(&name.into())
     }
 
+    /// Remove all hop-by-hop headers.
+    ///
+    /// This removes the standard hop-by-hop headers, as well as any header
+    /// named in the `Connection` header. Proxies must do this before
+    /// forwarding a message.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7230, section 6.1: Connection](https://tools.ietf.org/html/rfc7230#section-6.1)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// req.insert_header("Connection", "keep-alive, X-Hop")?;
+    /// req.insert_header("X-Hop", "1")?;
+    /// req.insert_header("Accept", "*/*")?;
+    ///
+    /// let headers: &mut http_types::headers::Headers = req.as_mut();
+    /// headers.remove_hop_by_hop();
+    /// assert!(req.header("Connection").is_none());
+    /// assert!(req.header("X-Hop").is_none());
+    /// assert_eq!(req["Accept"], "*/*");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn remove_hop_by_hop(&mut self) {
+        if let Some(values) = self.remove(CONNECTION) {
+            for value in &values {
+                for name in value.as_str().split(',') {
+                    if let Ok(name) = HeaderName::from_str(name.trim()) {
+                        self.remove(name);
+                    }
+                }
+            }
+        }
+
+        for name in &[
+            KEEP_ALIVE,
+            PROXY_AUTHENTICATE,
+            PROXY_AUTHORIZATION,
+            TE,
+            TRAILER,
+            TRANSFER_ENCODING,
+            UPGRADE,
+        ] {
+            self.remove(name);
+        }
+    }
+
     /// An iterator visiting all header pairs in arbitrary order.
+    ///
+    /// The order is neither the insertion order, nor guaranteed to be the same
+    /// between runs. Use [`iter_sorted`](Headers::iter_sorted) when a stable
+    /// order is needed. The values of each header keep their insertion order.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
             inner: self.headers.iter(),
         }
     }
 
+    /// An iterator visiting all header pairs, sorted by header name.
+    ///
+    /// Header names are lowercase, so this sorts case-insensitively. The
+    /// values of each header keep their insertion order. This is useful when
+    /// the output has to be deterministic, such as when signing requests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// use http_types::headers::Headers;
+    /// use http_types::{Method, Request, Url};
+    ///
+    /// let mut req = Request::new(Method::Get, Url::parse("https://example.com")?);
+    /// req.insert_header("X-Date", "20150830T123600Z");
+    /// req.insert_header("Host", "example.com");
+    ///
+    /// let headers: &Headers = req.as_ref();
+    /// let names: Vec<_> = headers.iter_sorted().map(|(name, _)| name.as_str()).collect();
+    /// assert_eq!(names, ["host", "x-date"]);
+    /// # Ok(()) }
+    /// ```
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValues)> {
+        let mut headers: Vec<_> = self.headers.iter().collect();
+        headers.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        headers.into_iter()
+    }
+
+    /// Serialize the given headers in the canonical form used to sign requests.
+    ///
+    /// Each header is written on its own line as `name:value`, followed by a
+    /// newline, ordered by name. Names are lowercase, and values are trimmed,
+    /// with runs of whitespace collapsed to a single space. Multiple values
+    /// of a header are joined with commas. Headers in `signed` which aren't
+    /// present are skipped.
+    ///
+    /// # Specifications
+    ///
+    /// - [AWS Signature Version 4: Canonical Request](https://docs.aws.amazon.com/IAM/latest/UserGuide/create-signed-request.html#create-canonical-request)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// use http_types::headers::{Headers, HOST};
+    /// use http_types::{Method, Request, Url};
+    ///
+    /// let mut req = Request::new(Method::Get, Url::parse("https://example.com")?);
+    /// req.insert_header("Host", "example.com");
+    /// req.insert_header("X-Amz-Date", "  20150830T123600Z ");
+    ///
+    /// let headers: &Headers = req.as_ref();
+    /// let signed = [HOST, "x-amz-date".parse()?];
+    /// assert_eq!(
+    ///     headers.canonical_string(&signed),
+    ///     "host:example.com\nx-amz-date:20150830T123600Z\n"
+    /// );
+    /// # Ok(()) }
+    /// ```
+    pub fn canonical_string(&self, signed: &[HeaderName]) -> String {
+        let mut names: Vec<&HeaderName> = signed.iter().collect();
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+
+        let mut output = String::new();
+        for name in names {
+            let values = match self.get(name) {
+                Some(values) => values,
+                None => continue,
+            };
+            output.push_str(name.as_str());
+            output.push(':');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                for (j, word) in value.as_str().split_whitespace().enumerate() {
+                    if j > 0 {
+                        output.push(' ');
+                    }
+                    output.push_str(word);
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+
     /// An iterator visiting all header pairs in arbitrary order, with mutable references to the
     /// values.
     pub fn iter_mut(&mut self) -> IterMut<'_> {
@@ -200,6 +408,91 @@ impl AsMut<Headers> for Headers {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde {
+    use super::Headers;
+    use crate::headers::{HeaderName, HeaderValue};
+    use crate::parse_utils::parse_token;
+    use serde_crate::de::{Error as DeError, MapAccess, Unexpected, Visitor};
+    use serde_crate::ser::SerializeMap;
+    use serde_crate::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    /// Headers are serialized as a map from header name to a list of values.
+    impl Serialize for Headers {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.headers.len()))?;
+            for (name, values) in self.iter() {
+                let values: Vec<&str> = values.iter().map(|value| value.as_str()).collect();
+                map.serialize_entry(name.as_str(), &values)?;
+            }
+            map.end()
+        }
+    }
+
+    struct HeadersVisitor;
+
+    impl<'de> Visitor<'de> for HeadersVisitor {
+        type Value = Headers;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a map of header names to lists of values")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut headers = Headers::new();
+            while let Some((name, values)) = access.next_entry::<String, Vec<String>>()? {
+                let name = match parse_token(&name) {
+                    (Some(token), "") => HeaderName::from_str(token).map_err(DeError::custom)?,
+                    _ => {
+                        return Err(DeError::invalid_value(
+                            Unexpected::Str(&name),
+                            &"a header name",
+                        ))
+                    }
+                };
+
+                let mut parsed = Vec::with_capacity(values.len());
+                for value in &values {
+                    // Allow visible ASCII, spaces and tabs, as per RFC 7230 `field-value`.
+                    let valid = value
+                        .chars()
+                        .all(|c| c == '\t' || c == ' ' || c.is_ascii_graphic());
+                    if !valid {
+                        return Err(DeError::invalid_value(
+                            Unexpected::Str(value),
+                            &"a header value",
+                        ));
+                    }
+                    parsed.push(HeaderValue::from_str(value).map_err(DeError::custom)?);
+                }
+
+                if parsed.is_empty() {
+                    return Err(DeError::invalid_length(0, &"at least one header value"));
+                }
+                headers.append(name, &parsed[..]).map_err(DeError::custom)?;
+            }
+            Ok(headers)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Headers {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_map(HeadersVisitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +517,186 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn iter_sorted() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("X-Amz-Date", "20150830T123600Z")?;
+        headers.append("Accept", "text/html")?;
+        headers.insert("Host", "example.com")?;
+        headers.append("Accept", "application/json")?;
+
+        let sorted: Vec<_> = headers
+            .iter_sorted()
+            .map(|(name, values)| (name.as_str(), values.iter().map(|v| v.as_str()).collect()))
+            .collect();
+        let expected: Vec<(&str, Vec<&str>)> = vec![
+            ("accept", vec!["text/html", "application/json"]),
+            ("host", vec!["example.com"]),
+            ("x-amz-date", vec!["20150830T123600Z"]),
+        ];
+        assert_eq!(sorted, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn canonical_string() -> crate::Result<()> {
+        // The example from the AWS Signature Version 4 documentation.
+        let mut headers = Headers::new();
+        headers.insert("Host", "iam.amazonaws.com")?;
+        headers.insert(
+            "Content-Type",
+            "application/x-www-form-urlencoded; charset=utf-8",
+        )?;
+        headers.insert("My-header1", "    a   b   c  ")?;
+        headers.insert("X-Amz-Date", "20150830T123600Z")?;
+        headers.insert("My-Header2", "    \"a   b   c\"  ")?;
+        headers.insert("X-Unsigned", "1")?;
+
+        let signed = [
+            "host",
+            "content-type",
+            "my-header1",
+            "x-amz-date",
+            "My-Header2",
+            "X-Missing",
+        ];
+        let signed: Vec<HeaderName> = signed
+            .iter()
+            .map(|name| name.parse())
+            .collect::<crate::Result<_>>()?;
+        assert_eq!(
+            headers.canonical_string(&signed),
+            "content-type:application/x-www-form-urlencoded; charset=utf-8\n\
+             host:iam.amazonaws.com\n\
+             my-header1:a b c\n\
+             my-header2:\"a b c\"\n\
+             x-amz-date:20150830T123600Z\n"
+        );
+
+        headers.append("My-header1", " d  e ")?;
+        assert_eq!(
+            headers.canonical_string(&signed[2..3]),
+            "my-header1:a b c,d e\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn remove_hop_by_hop() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("Connection", "close, X-Custom-Hop")?;
+        headers.insert("X-Custom-Hop", "1")?;
+        headers.insert("Transfer-Encoding", "chunked")?;
+        headers.insert("Proxy-Authorization", "Basic Zm9vOmJhcg==")?;
+        headers.insert("Content-Type", "text/plain")?;
+        headers.insert("X-End-To-End", "1")?;
+
+        headers.remove_hop_by_hop();
+
+        let mut names: Vec<_> = headers.names().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["content-type", "x-end-to-end"]);
+        Ok(())
+    }
+
+    #[test]
+    fn get_all_in_insertion_order() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.append("set-cookie", "a=1")?;
+        headers.append("set-cookie", "b=2")?;
+        headers.append("set-cookie", "c=3")?;
+
+        let values: Vec<_> = headers.get_all("set-cookie").map(|v| v.as_str()).collect();
+        assert_eq!(values, vec!["a=1", "b=2", "c=3"]);
+
+        let values: Vec<_> = headers["set-cookie"].iter().map(|v| v.as_str()).collect();
+        assert_eq!(values, vec!["a=1", "b=2", "c=3"]);
+
+        assert_eq!(headers.get_all("cookie").count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn append_keeps_existing_values() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("via", "1.0 fred")?;
+        headers.append("via", "1.1 p.example.net")?;
+
+        let values: Vec<_> = headers.get_all("via").map(|v| v.as_str()).collect();
+        assert_eq!(values, vec!["1.0 fred", "1.1 p.example.net"]);
+
+        let previous = headers.insert("via", "1.1 nowhere.com")?.unwrap();
+        assert_eq!(previous, ["1.0 fred", "1.1 p.example.net"][..]);
+        assert_eq!(headers.get_all("via").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn get_str_is_case_insensitive() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("Content-Type", "text/plain")?;
+
+        let lower = headers.get_str("content-type").unwrap();
+        let upper = headers.get_str("Content-Type").unwrap();
+        assert_eq!(lower, "text/plain");
+        assert!(std::ptr::eq(lower, upper));
+        assert!(std::ptr::eq(
+            headers.get_str(" CONTENT-TYPE ").unwrap(),
+            lower
+        ));
+
+        assert!(headers.get_str("content-length").is_none());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("content-type", "text/plain")?;
+        headers.append("set-cookie", "a=1")?;
+        headers.append("set-cookie", "b=2")?;
+
+        let json = serde_json::to_value(&headers)?;
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "content-type": ["text/plain"],
+                "set-cookie": ["a=1", "b=2"],
+            })
+        );
+
+        let parsed: Headers = serde_json::from_value(json)?;
+        assert_eq!(parsed["content-type"], "text/plain");
+        assert_eq!(parsed["set-cookie"], ["a=1", "b=2"][..]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_invalid_headers() {
+        let res: Result<Headers, _> = serde_json::from_str(r#"{"content type": ["a"]}"#);
+        assert!(res.is_err());
+
+        let res: Result<Headers, _> = serde_json::from_str(r#"{"x-evil": ["a\r\nb"]}"#);
+        assert!(res.is_err());
+
+        let res: Result<Headers, _> = serde_json::from_str(r#"{"x-empty": []}"#);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn get_str_rejects_invalid_names() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert("content-type", "text/plain")?;
+
+        assert!(headers.get_str("").is_none());
+        assert!(headers.get_str("content type").is_none());
+        assert!(headers.get_str("content-type:").is_none());
+        assert!(headers.get_str("cöntent-type").is_none());
+        Ok(())
+    }
+
     #[test]
     fn index_into_headers() {
         let mut headers = Headers::new();
@@ -101,11 +101,11 @@
 #![doc(html_favicon_url = "https://yoshuawuyts.com/assets/http-rs/favicon.ico")]
 #![doc(html_logo_url = "https://yoshuawuyts.com/assets/http-rs/logo-rounded.png")]
 
-/// HTTP cookies.
 #[cfg(feature = "cookies")]
-pub mod cookies {
-    pub use cookie::*;
-}
+pub mod cookies;
+
+#[cfg(feature = "multipart")]
+pub mod multipart;
 
 /// URL records.
 pub mod url {
@@ -122,11 +122,14 @@ pub mod auth;
 pub mod cache;
 pub mod conditional;
 pub mod content;
+pub mod cors;
 pub mod headers;
 pub mod mime;
 pub mod other;
 pub mod proxies;
 pub mod server;
+pub mod sse;
+pub mod structured;
 pub mod trace;
 pub mod transfer;
 pub mod upgrade;
@@ -138,7 +141,9 @@ mod macros;
 mod method;
 mod parse_utils;
 mod request;
+mod request_builder;
 mod response;
+mod response_builder;
 mod status;
 mod status_code;
 mod version;
@@ -147,9 +152,12 @@ pub use body::Body;
 pub use error::{Error, Result};
 pub use method::Method;
 pub use request::Request;
+pub use request_builder::RequestBuilder;
 pub use response::Response;
+pub use response_builder::ResponseBuilder;
 pub use status::Status;
 pub use status_code::StatusCode;
+pub use utils::HttpDate;
 pub use version::Version;
 
 #[doc(inline)]
@@ -354,6 +354,22 @@ impl Method {
                 | Method::Trace
         )
     }
+
+    /// Whether a method is considered "idempotent", meaning the request has
+    /// the same result if executed multiple times.
+    ///
+    /// All safe methods are idempotent. Clients may automatically retry
+    /// idempotent requests after a connection failure.
+    ///
+    /// See [the spec](https://tools.ietf.org/html/rfc7231#section-4.2.2) and the
+    /// [IANA method registry](https://www.iana.org/assignments/http-methods/http-methods.xhtml)
+    /// for more details.
+    pub fn is_idempotent(&self) -> bool {
+        !matches!(
+            self,
+            Method::Connect | Method::Lock | Method::Patch | Method::Post
+        )
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -531,6 +547,22 @@ mod test {
         serde_json::from_str::<Method>("\"ABC\"").expect_err("Did deserialize from invalid string");
     }
 
+    #[test]
+    fn safe_and_idempotent() {
+        assert!(!Method::Post.is_safe());
+        assert!(!Method::Post.is_idempotent());
+
+        assert!(!Method::Delete.is_safe());
+        assert!(Method::Delete.is_idempotent());
+        assert!(!Method::Put.is_safe());
+        assert!(Method::Put.is_idempotent());
+
+        for method in &[Method::Get, Method::Head, Method::Options, Method::Trace] {
+            assert!(method.is_safe());
+            assert!(method.is_idempotent());
+        }
+    }
+
     #[test]
     fn names() -> Result<(), crate::Error> {
         let method_names = [
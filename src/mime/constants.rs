@@ -67,7 +67,38 @@ mime_const!(BYTE_STREAM, "byte streams", "application", "octet-stream");
 mime_const!(FORM, "forms", "application", "x-www-form-urlencoded");
 mime_const!(MULTIPART_FORM, "multipart forms", "multipart", "form-data");
 mime_const!(WASM, "webassembly", "application", "wasm");
+mime_const!(WEBP, "WebP images", "image", "webp");
+mime_const!(AVIF, "AVIF images", "image", "avif");
+mime_const!(NDJSON, "newline delimited JSON", "application", "x-ndjson");
+mime_const!(MANIFEST, "app manifests", "application", "manifest+json");
 // There are multiple `.ico` mime types known, but `image/x-icon`
 // is what most browser use. See:
 // https://en.wikipedia.org/wiki/ICO_%28file_format%29#MIME_type
 mime_const!(ICO, "ICO icons", "image", "x-icon");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn essence() {
+        assert_eq!(WASM.essence(), "application/wasm");
+        assert_eq!(WEBP.essence(), "image/webp");
+        assert_eq!(AVIF.essence(), "image/avif");
+        assert_eq!(NDJSON.essence(), "application/x-ndjson");
+        assert_eq!(SSE.essence(), "text/event-stream");
+        assert_eq!(MANIFEST.essence(), "application/manifest+json");
+    }
+
+    #[test]
+    fn parse_round_trip() -> crate::Result<()> {
+        for mime in &[WASM, WEBP, AVIF, NDJSON, SSE, MANIFEST] {
+            let parsed = Mime::from_str(&mime.to_string())?;
+            assert_eq!(&parsed, mime);
+            assert_eq!(parsed.basetype(), mime.basetype());
+            assert_eq!(parsed.subtype(), mime.subtype());
+        }
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ use std::option;
 use std::str::FromStr;
 
 use crate::headers::{HeaderValue, ToHeaderValues};
+use crate::parse_utils::parse_token;
 
 use infer::Infer;
 
@@ -91,6 +92,61 @@ impl Mime {
         self.params.iter().find(|(k, _)| k == &name).map(|(_, v)| v)
     }
 
+    /// Get the value of the `charset` param, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::mime;
+    ///
+    /// assert_eq!(mime::HTML.charset(), Some("utf-8"));
+    /// assert_eq!(mime::JSON.charset(), None);
+    /// ```
+    pub fn charset(&self) -> Option<&str> {
+        self.param("charset").map(|value| value.as_str())
+    }
+
+    /// Set the `charset` param, replacing any existing charset.
+    ///
+    /// Charsets are compared case-insensitively, so `UTF-8` is stored as
+    /// `utf-8`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the charset
+    /// isn't a valid token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::mime;
+    ///
+    /// let mime = mime::PLAIN.with_charset("iso-8859-1")?;
+    /// assert_eq!(mime.to_string(), "text/plain;charset=iso-8859-1");
+    ///
+    /// let mime = mime::HTML.with_charset("gbk")?;
+    /// assert_eq!(mime.charset(), Some("gbk"));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn with_charset(mut self, charset: &str) -> crate::Result<Self> {
+        match parse_token(charset) {
+            (Some(_), "") => {}
+            _ => crate::bail_status!(400, "invalid charset {:?}", charset),
+        }
+        self.remove_param("charset");
+        if charset.eq_ignore_ascii_case("utf-8") {
+            self.is_utf8 = true;
+        } else {
+            let name = ParamName(Cow::Borrowed("charset"));
+            let value = ParamValue(Cow::Owned(charset.to_owned()));
+            self.params.push((name, value));
+        }
+        Ok(self)
+    }
+
     /// Remove a param from the set. Returns the `ParamValue` if it was contained within the set.
     pub fn remove_param(&mut self, name: impl Into<ParamName>) -> Option<ParamValue> {
         let name: ParamName = name.into();
@@ -209,3 +265,54 @@ impl PartialEq<str> for ParamValue {
         self.0 == other
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn add_charset() -> crate::Result<()> {
+        let mime = JSON.with_charset("utf-8")?;
+        assert_eq!(mime.charset(), Some("utf-8"));
+        assert_eq!(mime.to_string(), "application/json;charset=utf-8");
+
+        let mime = JSON.with_charset("UTF-8")?;
+        assert_eq!(mime.to_string(), "application/json;charset=utf-8");
+
+        let mime = JSON.with_charset("shift_jis")?;
+        assert_eq!(mime.charset(), Some("shift_jis"));
+        assert_eq!(mime.to_string(), "application/json;charset=shift_jis");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_charset() {
+        for charset in &["", "x\r\nSet-Cookie: evil=1", "utf 8", "\"utf-8\""] {
+            let err = JSON.with_charset(charset).unwrap_err();
+            assert_eq!(err.status(), 400, "{:?}", charset);
+        }
+    }
+
+    #[test]
+    fn read_charset() -> crate::Result<()> {
+        assert_eq!(PLAIN.charset(), Some("utf-8"));
+        assert_eq!(PNG.charset(), None);
+
+        let mime = Mime::from_str("text/html; charset=gbk; level=1")?;
+        assert_eq!(mime.charset(), Some("gbk"));
+        Ok(())
+    }
+
+    #[test]
+    fn replace_charset() -> crate::Result<()> {
+        let mime = Mime::from_str("text/html;charset=gbk;level=1")?;
+        let mime = mime.with_charset("iso-8859-1")?;
+        assert_eq!(mime.charset(), Some("iso-8859-1"));
+        assert_eq!(mime.to_string(), "text/html;level=1;charset=iso-8859-1");
+
+        let mime = HTML.with_charset("gbk")?;
+        assert_eq!(mime.to_string(), "text/html;charset=gbk");
+        assert_eq!(mime.with_charset("utf-8")?, HTML);
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+use crate::ensure_status as ensure;
+use crate::mime::Mime;
+use crate::parse_utils::format_quoted_string;
+use crate::Body;
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+/// A `multipart/form-data` body encoder.
+///
+/// Each part is streamed from its own `Body`, so files don't need to be read
+/// into memory up front. The length of the encoded body is known if the
+/// lengths of all parts are known.
+///
+/// # Specifications
+///
+/// - [RFC 7578: Returning Values from Forms: multipart/form-data](https://tools.ietf.org/html/rfc7578)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::multipart::MultipartBuilder;
+/// use http_types::{mime, Method, Request};
+///
+/// let mut form = MultipartBuilder::new();
+/// form.field("title", "Nori")
+///     .file("photo", "nori.txt", mime::PLAIN, "meow");
+///
+/// let mut req = Request::new(Method::Post, "https://example.com/upload");
+/// req.set_body(form.build());
+///
+/// let content_type = req.content_type().unwrap();
+/// assert_eq!(content_type.essence(), "multipart/form-data");
+/// assert!(content_type.param("boundary").is_some());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct MultipartBuilder {
+    boundary: String,
+    parts: Vec<(String, Body)>,
+}
+
+impl MultipartBuilder {
+    /// Create a new instance of `MultipartBuilder` with a random boundary.
+    pub fn new() -> Self {
+        Self {
+            boundary: format!("http-types-{:032x}", fastrand::u128(..)),
+            parts: vec![],
+        }
+    }
+
+    /// Create a new instance of `MultipartBuilder` with the given boundary.
+    ///
+    /// The boundary must not occur in the contents of any of the parts.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the boundary is not 1 to 70 characters long,
+    /// contains characters other than letters, digits, spaces and
+    /// `'()+_,-./:=?`, or ends with a space.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 2046, section 5.1.1: Common Syntax](https://tools.ietf.org/html/rfc2046#section-5.1.1)
+    pub fn with_boundary(boundary: impl Into<String>) -> crate::Result<Self> {
+        let boundary = boundary.into();
+        ensure!(
+            is_boundary(&boundary),
+            500,
+            "Invalid multipart boundary: {:?}",
+            boundary
+        );
+        Ok(Self {
+            boundary,
+            parts: vec![],
+        })
+    }
+
+    /// Get the boundary separating the parts.
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// Add a text field.
+    pub fn field(&mut self, name: &str, value: impl Into<String>) -> &mut Self {
+        let headers = format!(
+            "Content-Disposition: form-data; name={}\r\n",
+            escape_param(name)
+        );
+        self.parts.push((headers, Body::from_string(value.into())));
+        self
+    }
+
+    /// Add a file.
+    pub fn file(
+        &mut self,
+        name: &str,
+        filename: &str,
+        mime: Mime,
+        body: impl Into<Body>,
+    ) -> &mut Self {
+        let mut headers = format!(
+            "Content-Disposition: form-data; name={}; filename={}\r\n",
+            escape_param(name),
+            escape_param(filename)
+        );
+        write!(headers, "Content-Type: {}\r\n", mime).unwrap();
+        self.parts.push((headers, body.into()));
+        self
+    }
+
+    /// Encode the parts into a `Body`.
+    ///
+    /// The body's mime type is set to `multipart/form-data` with the
+    /// `boundary` parameter, which is used as the `Content-Type` once the body
+    /// is set on a `Request`.
+    pub fn build(self) -> Body {
+        let mut body = Body::empty();
+        for (headers, part) in self.parts {
+            let head = format!("--{}\r\n{}\r\n", self.boundary, headers);
+            body = body
+                .chain(Body::from_string(head))
+                .chain(part)
+                .chain(Body::from_bytes(b"\r\n".to_vec()));
+        }
+        body = body.chain(Body::from_string(format!("--{}--\r\n", self.boundary)));
+
+        let mime = format!(
+            "multipart/form-data; boundary={}",
+            format_quoted_string(&self.boundary)
+        );
+        let mime = Mime::from_str(&mime).expect("the boundary is validated");
+        body.set_mime(Some(mime));
+        body
+    }
+}
+
+impl Default for MultipartBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<MultipartBuilder> for Body {
+    fn from(builder: MultipartBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// https://tools.ietf.org/html/rfc2046#section-5.1.1
+fn is_boundary(boundary: &str) -> bool {
+    (1..=70).contains(&boundary.len())
+        && !boundary.ends_with(' ')
+        && boundary
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b" '()+_,-./:=?".contains(&b))
+}
+
+/// Quote a `Content-Disposition` parameter. Line breaks can't be represented
+/// in a quoted-string, so they're percent-encoded as browsers do.
+fn escape_param(value: &str) -> String {
+    format_quoted_string(&value.replace('\r', "%0D").replace('\n', "%0A"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mime;
+    use crate::multipart::Multipart;
+    use futures_lite::stream::StreamExt;
+
+    #[async_std::test]
+    async fn wire_format() -> crate::Result<()> {
+        let mut form = MultipartBuilder::with_boundary("AaB03x")?;
+        form.field("submit-name", "Larry")
+            .file("files", "file1.txt", mime::PLAIN, "contents");
+
+        let expected = "--AaB03x\r\n\
+            Content-Disposition: form-data; name=\"submit-name\"\r\n\
+            \r\n\
+            Larry\r\n\
+            --AaB03x\r\n\
+            Content-Disposition: form-data; name=\"files\"; filename=\"file1.txt\"\r\n\
+            Content-Type: text/plain;charset=utf-8\r\n\
+            \r\n\
+            contents\r\n\
+            --AaB03x--\r\n";
+
+        let body = form.build();
+        assert_eq!(body.len(), Some(expected.len() as u64));
+        assert_eq!(body.into_string().await?, expected);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn round_trip() -> crate::Result<()> {
+        let reader = async_std::io::Cursor::new("streamed");
+        let mut form = MultipartBuilder::new();
+        form.field("title", "Nori\r\nand Chashu").file(
+            "upload",
+            "say \"meow\".txt",
+            mime::BYTE_STREAM,
+            Body::from_reader(reader, None),
+        );
+
+        let body = form.build();
+        assert_eq!(body.len(), None);
+        assert_eq!(body.mime().unwrap().essence(), "multipart/form-data");
+
+        let mut multipart = Multipart::from_body(body)?;
+        let mut part = multipart.next().await.unwrap()?;
+        assert_eq!(part.name(), Some("title"));
+        assert_eq!(part.body_string().await?, "Nori\r\nand Chashu");
+
+        let mut part = multipart.next().await.unwrap()?;
+        assert_eq!(part.name(), Some("upload"));
+        assert_eq!(part.filename(), Some("say \"meow\".txt"));
+        assert_eq!(part.content_type(), Some(&mime::BYTE_STREAM));
+        assert_eq!(part.body_string().await?, "streamed");
+
+        assert!(multipart.next().await.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn boundaries() -> crate::Result<()> {
+        let form = MultipartBuilder::with_boundary("gc0p4Jq0M2Yt08jU534c0p")?;
+        let body = form.build();
+        assert_eq!(
+            body.mime().unwrap().param("boundary").unwrap(),
+            "gc0p4Jq0M2Yt08jU534c0p"
+        );
+
+        let form = MultipartBuilder::with_boundary("simple boundary")?;
+        let body = form.build();
+        assert_eq!(
+            body.mime().unwrap().param("boundary").unwrap(),
+            "simple boundary"
+        );
+
+        for boundary in ["", "trailing ", "a\"b", "a\r\nb", &"x".repeat(71)] {
+            let err = MultipartBuilder::with_boundary(boundary).unwrap_err();
+            assert_eq!(err.status(), 500);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn escape_params() {
+        assert_eq!(escape_param("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(escape_param("a\r\nb"), r#""a%0D%0Ab""#);
+    }
+}
@@ -0,0 +1,18 @@
+//! Multipart form data.
+//!
+//! `multipart/form-data` is used to submit forms containing files, or binary
+//! data. The payload is made up of parts separated by a boundary, each carrying
+//! its own headers and body. Payloads are parsed with [`Multipart`], and
+//! encoded with [`MultipartBuilder`].
+//!
+//! # Specifications
+//!
+//! - [RFC 7578: Returning Values from Forms: multipart/form-data](https://tools.ietf.org/html/rfc7578)
+
+mod builder;
+mod parser;
+mod part;
+
+pub use builder::MultipartBuilder;
+pub use parser::Multipart;
+pub use part::Part;
@@ -0,0 +1,409 @@
+use futures_lite::{io, prelude::*, ready};
+
+use crate::headers::{HeaderName, Headers, CONTENT_DISPOSITION};
+use crate::multipart::Part;
+use crate::Body;
+
+use std::cell::RefCell;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::task::{Context, Poll};
+
+/// The maximum size of the headers of a single part.
+const MAX_HEADERS_SIZE: usize = 8 * 1024;
+
+/// A streaming `multipart/form-data` parser.
+///
+/// `Multipart` is a `Stream` of [`Part`]s. Each part's body is read directly
+/// from the underlying payload, so the payload is never fully buffered. This
+/// also means that a part's body must be read before the next part is
+/// requested; any unread contents are skipped once the stream is polled again.
+///
+/// # Specifications
+///
+/// - [RFC 7578: Returning Values from Forms: multipart/form-data](https://tools.ietf.org/html/rfc7578)
+/// - [RFC 2046, section 5.1: Multipart Media Type](https://tools.ietf.org/html/rfc2046#section-5.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// #
+/// use futures_lite::stream::StreamExt;
+/// use http_types::multipart::Multipart;
+/// use http_types::Body;
+///
+/// let payload = "--boundary\r\n\
+///     Content-Disposition: form-data; name=\"title\"\r\n\
+///     \r\n\
+///     Hello Nori\r\n\
+///     --boundary--\r\n";
+///
+/// let mut multipart = Multipart::new(Body::from(payload), "boundary");
+/// let mut part = multipart.next().await.unwrap()?;
+/// assert_eq!(part.name(), Some("title"));
+/// assert_eq!(part.body_string().await?, "Hello Nori");
+/// assert!(multipart.next().await.is_none());
+/// #
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug)]
+pub struct Multipart {
+    inner: Rc<RefCell<Inner>>,
+}
+
+impl Multipart {
+    /// Create a new parser from a body and the `boundary` parameter of its
+    /// `Content-Type`.
+    pub fn new(body: impl Into<Body>, boundary: impl AsRef<str>) -> Self {
+        let mut delimiter = b"\r\n--".to_vec();
+        delimiter.extend_from_slice(boundary.as_ref().as_bytes());
+        Self {
+            inner: Rc::new(RefCell::new(Inner {
+                body: body.into(),
+                delimiter,
+                // The first delimiter isn't preceded by a line break. Adding one
+                // lets us treat the preamble like the body of an ignored part.
+                buf: b"\r\n".to_vec(),
+                part: 0,
+                part_done: false,
+                finished: false,
+            })),
+        }
+    }
+
+    /// Create a new parser from a body, reading the boundary from the body's
+    /// `multipart/form-data` mime type.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the body has no
+    /// `boundary` parameter.
+    pub fn from_body(body: Body) -> crate::Result<Self> {
+        let boundary = match body.mime().and_then(|mime| mime.param("boundary")) {
+            Some(boundary) => boundary.as_str().to_owned(),
+            None => crate::bail_status!(400, "Expected a multipart boundary"),
+        };
+        Ok(Self::new(body, boundary))
+    }
+}
+
+impl Stream for Multipart {
+    type Item = crate::Result<Part>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let (headers, disposition) = match ready!(self.inner.borrow_mut().poll_next_headers(cx)) {
+            Ok(Some(headers)) => headers,
+            Ok(None) => return Poll::Ready(None),
+            Err(err) => return Poll::Ready(Some(Err(crate::Error::new(400, err)))),
+        };
+
+        let part = self.inner.borrow().part;
+        let reader = io::BufReader::new(PartReader {
+            inner: self.inner.clone(),
+            part,
+        });
+        let body = Body::from_reader(reader, None);
+        Poll::Ready(Some(Part::new(headers, disposition, body)))
+    }
+}
+
+/// The state shared between the parser and the body of the current part.
+#[derive(Debug)]
+struct Inner {
+    body: Body,
+    /// The delimiter preceding each boundary: `\r\n--<boundary>`.
+    delimiter: Vec<u8>,
+    /// Bytes read from the body which haven't been handled yet.
+    buf: Vec<u8>,
+    /// The index of the current part.
+    part: usize,
+    /// Whether the body of the current part has been read up to the delimiter.
+    part_done: bool,
+    /// Whether the closing delimiter has been read.
+    finished: bool,
+}
+
+impl Inner {
+    /// Read more bytes from the body into the buffer, returning an error at the
+    /// end of the body.
+    fn poll_fill(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut chunk = [0; 4096];
+        let len = ready!(Pin::new(&mut self.body).poll_read(cx, &mut chunk))?;
+        if len == 0 {
+            let kind = io::ErrorKind::UnexpectedEof;
+            return Poll::Ready(Err(io::Error::new(
+                kind,
+                "Unexpected end of multipart body",
+            )));
+        }
+        self.buf.extend_from_slice(&chunk[..len]);
+        Poll::Ready(Ok(()))
+    }
+
+    /// Read from the body of the current part, stopping at the next delimiter.
+    fn poll_read_part(&mut self, cx: &mut Context<'_>, out: &mut [u8]) -> Poll<io::Result<usize>> {
+        loop {
+            if self.part_done || out.is_empty() {
+                return Poll::Ready(Ok(0));
+            }
+
+            // Everything up to the delimiter belongs to the current part. If
+            // there's no delimiter in the buffer, hold back enough bytes to
+            // detect one which is split across reads.
+            let available = match find(&self.buf, &self.delimiter) {
+                Some(0) => {
+                    self.buf.drain(..self.delimiter.len());
+                    self.part_done = true;
+                    return Poll::Ready(Ok(0));
+                }
+                Some(index) => index,
+                None => self.buf.len().saturating_sub(self.delimiter.len() - 1),
+            };
+
+            if available > 0 {
+                let len = available.min(out.len());
+                out[..len].copy_from_slice(&self.buf[..len]);
+                self.buf.drain(..len);
+                return Poll::Ready(Ok(len));
+            }
+
+            ready!(self.poll_fill(cx))?;
+        }
+    }
+
+    /// Skip to the next part and parse its headers. Returns `None` once the
+    /// closing delimiter has been reached.
+    fn poll_next_headers(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<PartHeaders>>> {
+        loop {
+            if self.finished {
+                return Poll::Ready(Ok(None));
+            }
+
+            if !self.part_done {
+                let mut scratch = [0; 1024];
+                ready!(self.poll_read_part(cx, &mut scratch))?;
+                continue;
+            }
+
+            // The delimiter is followed by `--` for the closing delimiter, or
+            // by optional whitespace and a line break otherwise.
+            if self.buf.len() < 2 {
+                ready!(self.poll_fill(cx))?;
+                continue;
+            }
+            if self.buf.starts_with(b"--") {
+                self.finished = true;
+                self.buf.clear();
+                return Poll::Ready(Ok(None));
+            }
+
+            let end = match find(&self.buf, b"\r\n\r\n") {
+                Some(end) => end,
+                None if self.buf.len() > MAX_HEADERS_SIZE => {
+                    return Poll::Ready(Err(invalid_data("Multipart headers are too large")));
+                }
+                None => {
+                    ready!(self.poll_fill(cx))?;
+                    continue;
+                }
+            };
+
+            let headers = parse_headers(&self.buf[..end])?;
+            self.buf.drain(..end + 4);
+            self.part += 1;
+            self.part_done = false;
+            return Poll::Ready(Ok(Some(headers)));
+        }
+    }
+}
+
+/// The headers of a part, and its decoded `Content-Disposition` header.
+type PartHeaders = (Headers, Option<String>);
+
+/// Parse the header block following a delimiter, including the remainder of
+/// the delimiter line.
+///
+/// The `Content-Disposition` header is returned separately, since its
+/// `filename` parameter may contain UTF-8 which isn't allowed in `Headers`.
+///
+/// https://tools.ietf.org/html/rfc7578#section-4.2
+fn parse_headers(block: &[u8]) -> io::Result<PartHeaders> {
+    let block =
+        std::str::from_utf8(block).map_err(|_| invalid_data("Invalid multipart headers"))?;
+    let mut lines = block.split("\r\n");
+
+    // Transport padding may follow the boundary on the same line.
+    let padding = lines.next().unwrap_or_default();
+    if !padding.chars().all(|c| c == ' ' || c == '\t') {
+        return Err(invalid_data("Expected a line break after the boundary"));
+    }
+
+    let mut headers = Headers::new();
+    let mut disposition = None;
+    for line in lines {
+        let (name, value) = match line.split_once(':') {
+            Some((name, value)) => (name.trim(), value.trim()),
+            None => return Err(invalid_data("Expected a multipart header")),
+        };
+        let name = HeaderName::from_str(name).map_err(|_| invalid_data("Invalid header name"))?;
+        if name == CONTENT_DISPOSITION {
+            disposition = Some(value.to_owned());
+            if !value.is_ascii() {
+                continue;
+            }
+        }
+        headers
+            .append(name, value)
+            .map_err(|_| invalid_data("Invalid header value"))?;
+    }
+    Ok((headers, disposition))
+}
+
+/// Reads the body of a single part.
+#[derive(Debug)]
+struct PartReader {
+    inner: Rc<RefCell<Inner>>,
+    part: usize,
+}
+
+impl AsyncRead for PartReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let mut inner = self.inner.borrow_mut();
+        // The parser has moved on to a later part.
+        if inner.part != self.part {
+            return Poll::Ready(Ok(0));
+        }
+        inner.poll_read_part(cx, buf)
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAYLOAD: &str = "preamble\r\n\
+        --AaB03x\r\n\
+        Content-Disposition: form-data; name=\"submit-name\"\r\n\
+        \r\n\
+        Larry\r\n\
+        --AaB03x\r\n\
+        Content-Disposition: form-data; name=\"files\"; filename=\"file1.txt\"\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        ... contents of file1.txt ...\r\n\
+        with a second line\r\n\
+        --AaB03x--\r\n\
+        epilogue";
+
+    #[async_std::test]
+    async fn two_parts() -> crate::Result<()> {
+        let mut multipart = Multipart::new(PAYLOAD, "AaB03x");
+
+        let mut part = multipart.next().await.unwrap()?;
+        assert_eq!(part.name(), Some("submit-name"));
+        assert_eq!(part.filename(), None);
+        assert_eq!(part.content_type(), None);
+        assert_eq!(part.body_string().await?, "Larry");
+
+        let mut part = multipart.next().await.unwrap()?;
+        assert_eq!(part.name(), Some("files"));
+        assert_eq!(part.filename(), Some("file1.txt"));
+        assert_eq!(part.content_type().unwrap().essence(), "text/plain");
+        assert_eq!(
+            part.body_string().await?,
+            "... contents of file1.txt ...\r\nwith a second line"
+        );
+
+        assert!(multipart.next().await.is_none());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn small_reads() -> crate::Result<()> {
+        // Feed the payload a byte at a time, so delimiters are split across reads.
+        let reader = io::BufReader::with_capacity(1, io::Cursor::new(PAYLOAD));
+        let mut multipart = Multipart::new(Body::from_reader(reader, None), "AaB03x");
+
+        let mut names = vec![];
+        let mut bodies = vec![];
+        while let Some(part) = multipart.next().await {
+            let mut part = part?;
+            names.push(part.name().unwrap().to_string());
+            bodies.push(part.body_string().await?);
+        }
+        assert_eq!(names, vec!["submit-name", "files"]);
+        assert_eq!(bodies[0], "Larry");
+        assert_eq!(bodies[1].len(), 49);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn skips_unread_parts() -> crate::Result<()> {
+        let mut multipart = Multipart::new(PAYLOAD, "AaB03x");
+        let mut first = multipart.next().await.unwrap()?;
+        let mut second = multipart.next().await.unwrap()?;
+
+        // The first part's body is no longer readable once the parser moved on.
+        assert_eq!(first.body_string().await?, "");
+        assert_eq!(second.filename(), Some("file1.txt"));
+        assert!(second.body_string().await?.starts_with("... contents"));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn from_body() -> crate::Result<()> {
+        let mut body = Body::from(PAYLOAD);
+        body.set_mime(Some("multipart/form-data; boundary=AaB03x".parse()?));
+        let mut multipart = Multipart::from_body(body)?;
+        assert_eq!(multipart.next().await.unwrap()?.name(), Some("submit-name"));
+
+        let err = Multipart::from_body(Body::from(PAYLOAD)).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn utf8_filename() -> crate::Result<()> {
+        let payload = "--AaB03x\r\n\
+            Content-Disposition: form-data; name=\"file\"; filename=\"résumé 履歴書.txt\"\r\n\
+            \r\n\
+            contents\r\n\
+            --AaB03x--\r\n";
+        let mut multipart = Multipart::new(payload, "AaB03x");
+        let mut part = multipart.next().await.unwrap()?;
+        assert_eq!(part.name(), Some("file"));
+        assert_eq!(part.filename(), Some("résumé 履歴書.txt"));
+        assert_eq!(part.body_string().await?, "contents");
+        assert!(multipart.next().await.is_none());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn bad_request_on_truncated_body() {
+        let payload = "--AaB03x\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nLarry";
+        let mut multipart = Multipart::new(payload, "AaB03x");
+        let mut part = multipart.next().await.unwrap().unwrap();
+        assert!(part.body_string().await.is_err());
+
+        let mut multipart = Multipart::new(payload, "AaB03x");
+        multipart.next().await.unwrap().unwrap();
+        let err = multipart.next().await.unwrap().unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
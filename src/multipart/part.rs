@@ -0,0 +1,179 @@
+use crate::headers::{HeaderName, HeaderValues, Headers, CONTENT_TYPE};
+use crate::mime::Mime;
+use crate::parse_utils::{parse_quoted_string, parse_token};
+use crate::Body;
+
+use std::mem;
+use std::str::FromStr;
+
+/// A single part of a `multipart/form-data` payload.
+///
+/// The body of a part is streamed from the underlying payload, and must be
+/// read before moving on to the next part. Any unread contents are discarded
+/// once the next part is requested.
+#[derive(Debug)]
+pub struct Part {
+    headers: Headers,
+    name: Option<String>,
+    filename: Option<String>,
+    body: Body,
+}
+
+impl Part {
+    /// Create a new part from its headers and body.
+    ///
+    /// The field name and filename are read from the `Content-Disposition`
+    /// header, which is passed separately since it may contain UTF-8. The
+    /// body's mime type is set from `Content-Type`.
+    pub(crate) fn new(
+        headers: Headers,
+        disposition: Option<String>,
+        mut body: Body,
+    ) -> crate::Result<Self> {
+        let (name, filename) = match disposition {
+            Some(disposition) => parse_content_disposition(&disposition)?,
+            None => (None, None),
+        };
+
+        if let Some(values) = headers.get(CONTENT_TYPE) {
+            body.set_mime(Some(Mime::from_str(values.last().as_str())?));
+        }
+
+        Ok(Self {
+            headers,
+            name,
+            filename,
+            body,
+        })
+    }
+
+    /// Get the field name, as set by the `name` parameter of the
+    /// `Content-Disposition` header.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get the filename, as set by the `filename` parameter of the
+    /// `Content-Disposition` header.
+    ///
+    /// This is only present for file uploads, and may contain UTF-8.
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// Get the mime type of the part's content, if one was provided.
+    pub fn content_type(&self) -> Option<&Mime> {
+        self.headers.get(CONTENT_TYPE)?;
+        self.body.mime()
+    }
+
+    /// Get an HTTP header of the part.
+    ///
+    /// A `Content-Disposition` header containing UTF-8 isn't available here;
+    /// use [`name`](Self::name) and [`filename`](Self::filename) instead.
+    pub fn header(&self, name: impl Into<HeaderName>) -> Option<&HeaderValues> {
+        self.headers.get(name)
+    }
+
+    /// Take the body of the part, leaving an empty body in its place.
+    pub fn take_body(&mut self) -> Body {
+        mem::replace(&mut self.body, Body::empty())
+    }
+
+    /// Read the body of the part as a string.
+    pub async fn body_string(&mut self) -> crate::Result<String> {
+        self.take_body().into_string().await
+    }
+
+    /// Read the body of the part as bytes.
+    pub async fn body_bytes(&mut self) -> crate::Result<Vec<u8>> {
+        self.take_body().into_bytes().await
+    }
+}
+
+impl AsRef<Headers> for Part {
+    fn as_ref(&self) -> &Headers {
+        &self.headers
+    }
+}
+
+/// Parse the `name` and `filename` parameters out of a `form-data`
+/// `Content-Disposition` header.
+///
+/// https://tools.ietf.org/html/rfc7578#section-4.2
+fn parse_content_disposition(input: &str) -> crate::Result<(Option<String>, Option<String>)> {
+    let (disposition, mut rest) = parse_token(input.trim());
+    crate::ensure_status!(
+        matches!(disposition, Some(d) if d.eq_ignore_ascii_case("form-data")),
+        400,
+        "Expected a form-data content disposition"
+    );
+
+    let (mut name, mut filename) = (None, None);
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+        rest = match rest.strip_prefix(';') {
+            Some(rest) => rest.trim_start(),
+            None => crate::bail_status!(400, "Expected a `;` between parameters"),
+        };
+
+        let (key, tail) = match parse_token(rest) {
+            (Some(key), tail) => (key, tail),
+            (None, _) => crate::bail_status!(400, "Expected a parameter name"),
+        };
+        let tail = match tail.strip_prefix('=') {
+            Some(tail) => tail,
+            None => crate::bail_status!(400, "Expected a parameter value"),
+        };
+        let (value, tail) = match parse_quoted_string(tail) {
+            (Some(value), tail) => (value.into_owned(), tail),
+            (None, _) => match parse_token(tail) {
+                (Some(value), tail) => (value.to_string(), tail),
+                (None, _) => crate::bail_status!(400, "Expected a parameter value"),
+            },
+        };
+        rest = tail;
+
+        match key.to_ascii_lowercase().as_str() {
+            "name" => name = Some(value),
+            "filename" => filename = Some(value),
+            _ => {}
+        }
+    }
+
+    Ok((name, filename))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn content_disposition() -> crate::Result<()> {
+        let (name, filename) = parse_content_disposition(r#"form-data; name="field1""#)?;
+        assert_eq!(name.as_deref(), Some("field1"));
+        assert_eq!(filename, None);
+
+        let (name, filename) = parse_content_disposition(
+            r#"form-data; name=upload; filename="semi; \"quoted\".txt""#,
+        )?;
+        assert_eq!(name.as_deref(), Some("upload"));
+        assert_eq!(filename.as_deref(), Some(r#"semi; "quoted".txt"#));
+
+        let (_, filename) = parse_content_disposition(r#"form-data; filename="résumé.txt""#)?;
+        assert_eq!(filename.as_deref(), Some("résumé.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let err = parse_content_disposition("attachment; name=\"a\"").unwrap_err();
+        assert_eq!(err.status(), 400);
+
+        let err = parse_content_disposition("form-data; name").unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
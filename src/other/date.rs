@@ -1,5 +1,6 @@
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, DATE};
-use crate::utils::HttpDate;
+use crate::utils::{fmt_http_date, parse_http_date};
+use crate::HttpDate;
 
 use std::time::SystemTime;
 
@@ -23,7 +24,7 @@ use std::time::SystemTime;
 /// let date = Date::new(now);
 ///
 /// let mut res = Response::new(200);
-/// res.insert_header(&date, &date);
+/// date.apply(&mut res);
 ///
 /// let date = Date::from_headers(res)?.unwrap();
 ///
@@ -50,7 +51,22 @@ impl Date {
         }
     }
 
+    /// Get the date as an `HttpDate`.
+    pub fn date(&self) -> HttpDate {
+        self.at.into()
+    }
+
+    /// Sets the `Date` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the date can't
+    /// be parsed.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let headers = match headers.as_ref().get(DATE) {
             Some(headers) => headers,
@@ -60,15 +76,7 @@ impl Date {
         // If we successfully parsed the header then there's always at least one
         // entry. We want the last entry.
         let value = headers.iter().last().unwrap();
-        let date: HttpDate = value
-            .as_str()
-            .trim()
-            .parse()
-            .map_err(|mut e: crate::Error| {
-                e.set_status(400);
-                e
-            })?;
-        let at = date.into();
+        let at = parse_http_date(value.as_str())?;
         Ok(Some(Self { at }))
     }
 }
@@ -79,8 +87,7 @@ impl Header for Date {
     }
 
     fn header_value(&self) -> HeaderValue {
-        let date: HttpDate = self.at.into();
-        let output = format!("{}", date);
+        let output = fmt_http_date(self.at);
 
         // SAFETY: the internal string is validated to be ASCII.
         unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
@@ -99,6 +106,12 @@ impl From<SystemTime> for Date {
     }
 }
 
+impl From<HttpDate> for Date {
+    fn from(date: HttpDate) -> Self {
+        Self { at: date.into() }
+    }
+}
+
 impl PartialEq<SystemTime> for Date {
     fn eq(&self, other: &SystemTime) -> bool {
         &self.at == other
@@ -109,7 +122,7 @@ impl PartialEq<SystemTime> for Date {
 mod test {
     use super::*;
     use crate::headers::Headers;
-    use std::time::Duration;
+    use std::time::{Duration, UNIX_EPOCH};
 
     #[test]
     fn smoke() -> crate::Result<()> {
@@ -126,6 +139,20 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn apply_formats_imf_fixdate() -> crate::Result<()> {
+        let date = Date::new(UNIX_EPOCH + Duration::from_secs(784111777));
+
+        let mut headers = Headers::new();
+        date.apply(&mut headers);
+        assert_eq!(headers[DATE], "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        let parsed = Date::from_headers(headers)?.unwrap();
+        assert_eq!(parsed, SystemTime::from(date));
+        assert_eq!(parsed.date().to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+        Ok(())
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
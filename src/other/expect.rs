@@ -1,10 +1,17 @@
 use crate::headers::{HeaderName, HeaderValue, Headers, EXPECT};
-use crate::{ensure_eq_status, headers::Header};
+use crate::{ensure_status, headers::Header};
 
 use std::fmt::Debug;
 
 /// HTTP `Expect` header
 ///
+/// The only expectation defined is `100-continue`, which clients send to check
+/// whether a request will be accepted before sending its body. Servers should
+/// reply with an interim [`Response::continue_response`] before reading the
+/// body.
+///
+/// [`Response::continue_response`]: crate::Response::continue_response
+///
 /// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Expect)
 ///
 /// # Specifications
@@ -41,6 +48,11 @@ impl Expect {
     }
 
     /// Create an instance of `Expect` from a `Headers` instance.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `417: Expectation Failed` is returned if the
+    /// header contains any expectation other than `100-continue`.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let headers = match headers.as_ref().get(EXPECT) {
             Some(headers) => headers,
@@ -50,10 +62,26 @@ impl Expect {
         // If we successfully parsed the header then there's always at least one
         // entry. We want the last entry.
         let header = headers.iter().last().unwrap();
-        ensure_eq_status!(header, "100-continue", 400, "malformed `Expect` header");
+        ensure_status!(
+            header.as_str().trim().eq_ignore_ascii_case("100-continue"),
+            417,
+            "unsupported expectation `{}`",
+            header
+        );
 
         Ok(Some(Self { _priv: () }))
     }
+
+    /// Returns `true` if the client expects a `100 Continue` response before
+    /// sending the body.
+    pub fn is_continue(&self) -> bool {
+        true
+    }
+
+    /// Sets the `Expect` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
 }
 
 impl Header for Expect {
@@ -85,10 +113,22 @@ mod test {
     }
 
     #[test]
-    fn bad_request_on_parse_error() {
+    fn continue_token() -> crate::Result<()> {
         let mut headers = Headers::new();
-        headers.insert(EXPECT, "<nori ate the tag. yum.>").unwrap();
-        let err = Expect::from_headers(headers).unwrap_err();
-        assert_eq!(err.status(), 400);
+        headers.insert(EXPECT, "100-Continue")?;
+
+        let expect = Expect::from_headers(headers)?.unwrap();
+        assert!(expect.is_continue());
+        Ok(())
+    }
+
+    #[test]
+    fn expectation_failed_on_unknown_expectation() {
+        for value in &["<nori ate the tag. yum.>", "200-ok", "100-continue, foo"] {
+            let mut headers = Headers::new();
+            headers.insert(EXPECT, *value).unwrap();
+            let err = Expect::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 417, "{}", value);
+        }
     }
 }
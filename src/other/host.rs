@@ -0,0 +1,259 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, HOST};
+use crate::{bail_status as bail, ensure_status as ensure};
+
+use std::fmt::{self, Display};
+use std::net::Ipv6Addr;
+use std::str::FromStr;
+
+/// The host and port number of the server to which the request is sent.
+///
+/// IPv6 addresses are written in brackets, for example `[::1]:8080`. The
+/// brackets are not part of the [`hostname`](Host::hostname).
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Host)
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 5.4: Host](https://tools.ietf.org/html/rfc7230#section-5.4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Request;
+/// use http_types::other::Host;
+///
+/// let host = Host::new("example.com", Some(8080))?;
+///
+/// let mut req = Request::get("https://example.com:8080");
+/// host.apply(&mut req);
+/// assert_eq!(req["Host"], "example.com:8080");
+///
+/// let host = Host::from_headers(req)?.unwrap();
+/// assert_eq!(host.hostname(), "example.com");
+/// assert_eq!(host.port(), Some(8080));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Host {
+    hostname: String,
+    port: Option<u16>,
+}
+
+impl Host {
+    /// Create a new instance of `Host`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if `hostname` is
+    /// not a valid hostname, IPv4 address, or (unbracketed) IPv6 address.
+    pub fn new(hostname: impl Into<String>, port: Option<u16>) -> crate::Result<Self> {
+        let hostname = hostname.into();
+        ensure!(
+            is_reg_name(&hostname) || hostname.parse::<Ipv6Addr>().is_ok(),
+            400,
+            "invalid hostname `{}`",
+            hostname.escape_debug()
+        );
+        Ok(Self { hostname, port })
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(HOST) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let header = headers.iter().last().unwrap();
+        Ok(Some(header.as_str().parse()?))
+    }
+
+    /// Sets the `Host` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Get the hostname.
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    /// Get the port, if one was specified.
+    pub fn port(&self) -> Option<u16> {
+        self.port
+    }
+}
+
+impl FromStr for Host {
+    type Err = crate::Error;
+
+    /// Parse a `host [ ":" port ]` string.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the string is
+    /// not a valid host.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (hostname, port) = if let Some(rest) = s.strip_prefix('[') {
+            let (hostname, rest) = match rest.split_once(']') {
+                Some(parts) => parts,
+                None => bail!(400, "unterminated IPv6 address in host `{}`", s),
+            };
+            ensure!(
+                hostname.parse::<Ipv6Addr>().is_ok(),
+                400,
+                "invalid IPv6 address in host `{}`",
+                s
+            );
+            match rest {
+                "" => (hostname, None),
+                _ => match rest.strip_prefix(':') {
+                    Some(port) => (hostname, Some(port)),
+                    None => bail!(400, "unexpected characters after IPv6 address in `{}`", s),
+                },
+            }
+        } else {
+            match s.split_once(':') {
+                Some((hostname, port)) => (hostname, Some(port)),
+                None => (s, None),
+            }
+        };
+
+        ensure!(
+            is_reg_name(hostname) || s.starts_with('['),
+            400,
+            "invalid hostname in `{}`",
+            s
+        );
+
+        // An empty port is allowed, and means the default port is used.
+        let port = match port {
+            None | Some("") => None,
+            Some(port) => match port.parse::<u16>() {
+                Ok(n) if port.bytes().all(|b| b.is_ascii_digit()) => Some(n),
+                _ => bail!(400, "invalid port in host `{}`", s),
+            },
+        };
+
+        Ok(Self {
+            hostname: hostname.to_owned(),
+            port,
+        })
+    }
+}
+
+impl Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hostname.contains(':') {
+            write!(f, "[{}]", self.hostname)?;
+        } else {
+            f.write_str(&self.hostname)?;
+        }
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        Ok(())
+    }
+}
+
+impl Header for Host {
+    fn header_name(&self) -> HeaderName {
+        HOST
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let output = self.to_string();
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+/// Checks whether a string is a non-empty `reg-name` as defined in RFC 3986,
+/// which also covers IPv4 addresses.
+fn is_reg_name(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-._~%!$&'()*+,;=".contains(&b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn explicit_port() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(HOST, "example.com:8080")?;
+
+        let host = Host::from_headers(&headers)?.unwrap();
+        assert_eq!(host.hostname(), "example.com");
+        assert_eq!(host.port(), Some(8080));
+
+        host.apply(&mut headers);
+        assert_eq!(headers[HOST], "example.com:8080");
+        Ok(())
+    }
+
+    #[test]
+    fn no_port() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(HOST, "127.0.0.1")?;
+
+        let host = Host::from_headers(&headers)?.unwrap();
+        assert_eq!(host.hostname(), "127.0.0.1");
+        assert_eq!(host.port(), None);
+        assert_eq!(host.to_string(), "127.0.0.1");
+        Ok(())
+    }
+
+    #[test]
+    fn ipv6() -> crate::Result<()> {
+        let host: Host = "[::1]:8080".parse()?;
+        assert_eq!(host.hostname(), "::1");
+        assert_eq!(host.port(), Some(8080));
+        assert_eq!(host.to_string(), "[::1]:8080");
+
+        let host: Host = "[2001:db8::1]".parse()?;
+        assert_eq!(host.hostname(), "2001:db8::1");
+        assert_eq!(host.port(), None);
+
+        let host = Host::new("::1", None)?;
+        assert_eq!(host.to_string(), "[::1]");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &[
+            "",
+            ":80",
+            "::1",
+            "[::1",
+            "[not-ipv6]:80",
+            "[::1]80",
+            "example.com:port",
+            "example.com:+80",
+            "example.com:65536",
+            "user@example.com",
+            "example.com/path",
+        ] {
+            let err = value.parse::<Host>().unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+    #[test]
+    fn reject_invalid_hostname() {
+        for hostname in &["", "example.com:80", "[::1]", "evil.com\r\nX-Injected: 1"] {
+            let err = Host::new(*hostname, None).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", hostname);
+        }
+    }
+}
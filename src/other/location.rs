@@ -0,0 +1,190 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, LOCATION};
+use crate::{Status, Url};
+
+/// The URL to redirect a page to.
+///
+/// The value may be relative, in which case it has to be resolved against the
+/// URL of the request which produced the response.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Location)
+///
+/// # Specifications
+///
+/// - [RFC 7231, section 7.1.2: Location](https://tools.ietf.org/html/rfc7231#section-7.1.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::{Response, Url};
+/// use http_types::other::Location;
+///
+/// let mut res = Response::new(302);
+/// res.insert_header("Location", "../login")?;
+///
+/// let location = Location::from_headers(res)?.unwrap();
+/// assert_eq!(location.as_str(), "../login");
+///
+/// let base_url = Url::parse("https://example.net/app/admin/")?;
+/// assert_eq!(
+///     location.resolve(&base_url)?,
+///     Url::parse("https://example.net/app/login")?
+/// );
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    value: String,
+}
+
+impl Location {
+    /// Create a new instance of `Location` header pointing to an absolute url.
+    pub fn new(location: Url) -> Self {
+        Self {
+            value: location.into(),
+        }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(LOCATION) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap().as_str().trim();
+        crate::ensure_status!(!value.is_empty(), 400, "Location header is empty");
+
+        Ok(Some(Self {
+            value: value.to_owned(),
+        }))
+    }
+
+    /// Get the raw, possibly relative, value of the header.
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Resolve the location against the url of the request it responds to.
+    ///
+    /// Relative and protocol-relative (`//host/path`) locations are joined
+    /// onto `base_url`. If the location has no fragment, the fragment of
+    /// `base_url` is carried over to the resolved url.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the location
+    /// cannot be resolved to a valid url.
+    pub fn resolve(&self, base_url: &Url) -> crate::Result<Url> {
+        let mut url = base_url.join(&self.value).status(400)?;
+        if !self.value.contains('#') {
+            url.set_fragment(base_url.fragment());
+        }
+        Ok(url)
+    }
+
+    /// Sets the `Location` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+}
+
+impl Header for Location {
+    fn header_name(&self) -> HeaderName {
+        LOCATION
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(self.value.clone().into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let location = Location::new(Url::parse("https://example.net/test.json")?);
+
+        let mut headers = Headers::new();
+        location.apply(&mut headers);
+
+        let location = Location::from_headers(headers)?.unwrap();
+        assert_eq!(location.as_str(), "https://example.net/test.json");
+        let base_url = Url::parse("https://example.com/")?;
+        assert_eq!(
+            location.resolve(&base_url)?,
+            Url::parse("https://example.net/test.json")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_relative() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "../b")?;
+
+        let location = Location::from_headers(headers)?.unwrap();
+        assert_eq!(location.as_str(), "../b");
+        let base_url = Url::parse("https://example.net/x/y/a?q=1")?;
+        assert_eq!(
+            location.resolve(&base_url)?,
+            Url::parse("https://example.net/x/b")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_protocol_relative() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "//cdn.example.com/path")?;
+
+        let location = Location::from_headers(headers)?.unwrap();
+        let base_url = Url::parse("http://example.net/a/b")?;
+        assert_eq!(
+            location.resolve(&base_url)?,
+            Url::parse("http://cdn.example.com/path")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_fragment() -> crate::Result<()> {
+        let base_url = Url::parse("https://example.net/a#top")?;
+
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "/b")?;
+        let location = Location::from_headers(headers)?.unwrap();
+        assert_eq!(
+            location.resolve(&base_url)?,
+            Url::parse("https://example.net/b#top")?
+        );
+
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "/b#bottom")?;
+        let location = Location::from_headers(headers)?.unwrap();
+        assert_eq!(
+            location.resolve(&base_url)?,
+            Url::parse("https://example.net/b#bottom")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(LOCATION, "http://[::1")?;
+        let location = Location::from_headers(headers)?.unwrap();
+        let base_url = Url::parse("https://example.net/")?;
+        let err = location.resolve(&base_url).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+}
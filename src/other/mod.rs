@@ -2,12 +2,22 @@
 
 mod date;
 mod expect;
+mod host;
+mod location;
+mod prefer;
+mod preference_applied;
+mod priority;
 mod referer;
 mod retry_after;
 mod source_map;
 
 pub use date::Date;
 pub use expect::Expect;
+pub use host::Host;
+pub use location::Location;
+pub use prefer::{Prefer, Preference};
+pub use preference_applied::PreferenceApplied;
+pub use priority::Priority;
 pub use referer::Referer;
 pub use retry_after::RetryAfter;
 pub use source_map::SourceMap;
@@ -0,0 +1,417 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, PREFER};
+use crate::parse_utils::{
+    format_quoted_string, is_quotable, parse_list, parse_params, parse_quoted_string, parse_token,
+    skip_ows,
+};
+
+use std::fmt::{self, Debug, Write};
+use std::iter::Iterator;
+use std::slice;
+use std::time::Duration;
+
+/// Indicates the behaviors preferred by the client when processing a request.
+///
+/// Servers may ignore any preference, and list the preferences they honored
+/// in the [`PreferenceApplied`](super::PreferenceApplied) header of the
+/// response. Preferences the server doesn't know about are preserved.
+///
+/// # Specifications
+///
+/// - [RFC 7240: Prefer Header for HTTP](https://tools.ietf.org/html/rfc7240)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::other::{Prefer, Preference};
+/// use http_types::Request;
+/// use std::time::Duration;
+///
+/// let mut prefer = Prefer::new();
+/// prefer.push(Preference::new("respond-async")?);
+/// prefer.push(Preference::with_value("wait", "10")?);
+///
+/// let mut req = Request::get("https://example.com");
+/// prefer.apply(&mut req);
+/// assert_eq!(req["Prefer"], "respond-async, wait=10");
+///
+/// let prefer = Prefer::from_headers(req)?.unwrap();
+/// assert!(prefer.respond_async());
+/// assert_eq!(prefer.wait(), Some(Duration::from_secs(10)));
+/// #
+/// # Ok(()) }
+/// ```
+pub struct Prefer {
+    preferences: Vec<Preference>,
+}
+
+impl Prefer {
+    /// Create a new instance of `Prefer`.
+    pub fn new() -> Self {
+        Self {
+            preferences: vec![],
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if a preference is
+    /// malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(PREFER) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut preferences = vec![];
+        for value in headers {
+            preferences.extend(parse_preferences(value.as_str())?);
+        }
+        Ok(Some(Self { preferences }))
+    }
+
+    /// Sets the `Prefer` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Push a preference into the list of preferences.
+    pub fn push(&mut self, preference: Preference) {
+        self.preferences.push(preference);
+    }
+
+    /// Get a preference by its name.
+    ///
+    /// Names are case-insensitive. If a preference is listed more than once,
+    /// only the first one is returned.
+    pub fn get(&self, name: &str) -> Option<&Preference> {
+        self.preferences
+            .iter()
+            .find(|pref| pref.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns `true` if the `respond-async` preference is set.
+    ///
+    /// This indicates the client prefers the server to respond with `202:
+    /// Accepted` rather than to wait for the request to be processed.
+    pub fn respond_async(&self) -> bool {
+        self.get("respond-async").is_some()
+    }
+
+    /// Get the value of the `return` preference, such as `minimal` or
+    /// `representation`.
+    pub fn return_value(&self) -> Option<&str> {
+        self.get("return").and_then(|pref| pref.value())
+    }
+
+    /// Get the value of the `wait` preference, which is the time the client
+    /// is willing to wait for the request to be processed.
+    ///
+    /// Returns `None` if the preference isn't set, or its value isn't a
+    /// number of seconds.
+    pub fn wait(&self) -> Option<Duration> {
+        let wait = self.get("wait")?.value()?;
+        wait.parse().ok().map(Duration::from_secs)
+    }
+
+    /// An iterator visiting all preferences.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.preferences.iter(),
+        }
+    }
+}
+
+impl Header for Prefer {
+    fn header_name(&self) -> HeaderName {
+        PREFER
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, pref) in self.preferences.iter().enumerate() {
+            if n > 0 {
+                output.push_str(", ");
+            }
+            pref.write_to(&mut output, true);
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Prefer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Prefer {
+    type Item = &'a Preference;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowing iterator over entries in `Prefer`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, Preference>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Preference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for Prefer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for pref in &self.preferences {
+            list.entry(pref);
+        }
+        list.finish()
+    }
+}
+
+/// A single preference, as listed in the [`Prefer`] and
+/// [`PreferenceApplied`](super::PreferenceApplied) headers.
+///
+/// A preference has a case-insensitive name, and optionally a value and
+/// parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preference {
+    name: String,
+    value: Option<String>,
+    params: Vec<(String, Option<String>)>,
+}
+
+impl Preference {
+    /// Create a new preference without a value, such as `respond-async`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the name isn't
+    /// a valid token.
+    pub fn new(name: &str) -> crate::Result<Self> {
+        if !is_token(name) {
+            bail!(400, "invalid preference name `{}`", name);
+        }
+        Ok(Self {
+            name: name.to_ascii_lowercase(),
+            value: None,
+            params: vec![],
+        })
+    }
+
+    /// Create a new preference with a value, such as `return=minimal`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the name isn't
+    /// a valid token, or the value contains control or non-ASCII characters.
+    pub fn with_value(name: &str, value: &str) -> crate::Result<Self> {
+        let mut pref = Self::new(name)?;
+        if !is_quotable(value) {
+            bail!(400, "invalid preference value `{}`", value.escape_debug());
+        }
+        pref.value = Some(value.to_owned());
+        Ok(pref)
+    }
+
+    /// Add a parameter to the preference.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the name isn't
+    /// a valid token, or the value contains control or non-ASCII characters.
+    pub fn push_param(&mut self, name: &str, value: Option<&str>) -> crate::Result<()> {
+        if !is_token(name) {
+            bail!(400, "invalid parameter name `{}`", name);
+        }
+        if let Some(value) = value {
+            if !is_quotable(value) {
+                bail!(400, "invalid parameter value `{}`", value.escape_debug());
+            }
+        }
+        self.params
+            .push((name.to_ascii_lowercase(), value.map(ToOwned::to_owned)));
+        Ok(())
+    }
+
+    /// Get the name of the preference, in lowercase.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the value of the preference.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Get the value of a parameter by its name.
+    ///
+    /// Returns `Some("")` for a parameter without a value.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_deref().unwrap_or(""))
+    }
+
+    /// Write the preference to a string, with or without its parameters.
+    pub(super) fn write_to(&self, output: &mut String, with_params: bool) {
+        output.push_str(&self.name);
+        if let Some(value) = &self.value {
+            write_value(output, value);
+        }
+        if with_params {
+            for (name, value) in &self.params {
+                write!(output, "; {}", name).unwrap();
+                if let Some(value) = value {
+                    write_value(output, value);
+                }
+            }
+        }
+    }
+}
+
+/// Write `=value`, quoting the value if it isn't a token.
+fn write_value(output: &mut String, value: &str) {
+    output.push('=');
+    if is_token(value) {
+        output.push_str(value);
+    } else {
+        output.push_str(&format_quoted_string(value));
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    matches!(parse_token(s), (Some(_), ""))
+}
+
+/// Parse an optional `=value`, where the value is a token or quoted-string.
+fn parse_value(input: &str) -> crate::Result<(Option<String>, &str)> {
+    let rest = match skip_ows(input).strip_prefix('=') {
+        Some(rest) => skip_ows(rest),
+        None => return Ok((None, input)),
+    };
+    if let (Some(token), rest) = parse_token(rest) {
+        return Ok((Some(token.to_owned()), rest));
+    }
+    match parse_quoted_string(rest) {
+        (Some(value), rest) if is_quotable(&value) => Ok((Some(value.into_owned()), rest)),
+        _ => bail!(400, "invalid preference value"),
+    }
+}
+
+/// Parse a comma-separated list of preferences.
+pub(super) fn parse_preferences(input: &str) -> crate::Result<Vec<Preference>> {
+    parse_list(input, "preference", |input| {
+        let (name, rest) = match parse_token(input) {
+            (Some(name), rest) => (name, rest),
+            (None, _) => bail!(400, "invalid preference name"),
+        };
+        let (value, rest) = parse_value(rest)?;
+        let mut pref = Preference {
+            name: name.to_ascii_lowercase(),
+            value,
+            params: vec![],
+        };
+
+        let rest = parse_params(rest, |input| {
+            if input.is_empty() || input.starts_with(',') || input.starts_with(';') {
+                return Ok(input);
+            }
+            let (name, rest) = match parse_token(input) {
+                (Some(name), rest) => (name, rest),
+                (None, _) => bail!(400, "invalid preference parameter"),
+            };
+            let (value, rest) = parse_value(rest)?;
+            pref.params.push((name.to_ascii_lowercase(), value));
+            Ok(rest)
+        })?;
+        Ok((pref, rest))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn parse_with_params() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(PREFER, "return=representation; handling=lenient")?;
+        headers.append(PREFER, "Respond-Async, wait=10, x-custom=\"a, b\"")?;
+
+        let prefer = Prefer::from_headers(headers)?.unwrap();
+        assert_eq!(prefer.iter().count(), 4);
+        assert_eq!(prefer.return_value(), Some("representation"));
+        let ret = prefer.get("return").unwrap();
+        assert_eq!(ret.param("handling"), Some("lenient"));
+        assert!(prefer.respond_async());
+        assert_eq!(prefer.wait(), Some(Duration::from_secs(10)));
+        assert_eq!(prefer.get("X-Custom").unwrap().value(), Some("a, b"));
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> crate::Result<()> {
+        let mut pref = Preference::with_value("return", "minimal")?;
+        pref.push_param("foo", None)?;
+        pref.push_param("bar", Some("a b"))?;
+        let mut prefer = Prefer::new();
+        prefer.push(pref.clone());
+
+        let mut headers = Headers::new();
+        prefer.apply(&mut headers);
+        assert_eq!(headers[PREFER], "return=minimal; foo; bar=\"a b\"");
+
+        let prefer = Prefer::from_headers(headers)?.unwrap();
+        assert_eq!(prefer.get("return"), Some(&pref));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_preferences() -> crate::Result<()> {
+        assert_eq!(Preference::new("a b").unwrap_err().status(), 400);
+        let err = Preference::with_value("return", "a\r\nSet-Cookie: evil=1").unwrap_err();
+        assert_eq!(err.status(), 400);
+        assert!(Preference::with_value("return", "café").is_err());
+
+        let mut pref = Preference::new("return")?;
+        assert!(pref.push_param("a=b", None).is_err());
+        assert!(pref.push_param("foo", Some("\n")).is_err());
+        assert!(pref.param("foo").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() -> crate::Result<()> {
+        for value in &["return=", "=minimal", "wait=10 20", "return=\"unterminated"] {
+            let mut headers = Headers::new();
+            headers.insert(PREFER, *value)?;
+            let err = Prefer::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, PREFERENCE_APPLIED};
+use crate::other::prefer::parse_preferences;
+use crate::other::Preference;
+
+use std::fmt::{self, Debug};
+use std::iter::Iterator;
+use std::slice;
+
+/// Indicates which of the preferences sent in the [`Prefer`](super::Prefer)
+/// header were honored by the server.
+///
+/// Parameters of preferences aren't part of this header, and are left out
+/// when it's serialized.
+///
+/// # Specifications
+///
+/// - [RFC 7240, section 3: The Preference-Applied Response Header Field](https://tools.ietf.org/html/rfc7240#section-3)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::other::{Preference, PreferenceApplied};
+/// use http_types::Response;
+///
+/// let mut applied = PreferenceApplied::new();
+/// applied.push(Preference::with_value("return", "minimal")?);
+///
+/// let mut res = Response::new(200);
+/// applied.apply(&mut res);
+/// assert_eq!(res["Preference-Applied"], "return=minimal");
+///
+/// let applied = PreferenceApplied::from_headers(res)?.unwrap();
+/// assert!(applied.contains("return"));
+/// #
+/// # Ok(()) }
+/// ```
+pub struct PreferenceApplied {
+    preferences: Vec<Preference>,
+}
+
+impl PreferenceApplied {
+    /// Create a new instance of `PreferenceApplied`.
+    pub fn new() -> Self {
+        Self {
+            preferences: vec![],
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if a preference is
+    /// malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(PREFERENCE_APPLIED) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut preferences = vec![];
+        for value in headers {
+            preferences.extend(parse_preferences(value.as_str())?);
+        }
+        Ok(Some(Self { preferences }))
+    }
+
+    /// Sets the `Preference-Applied` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Mark a preference as applied.
+    pub fn push(&mut self, preference: Preference) {
+        self.preferences.push(preference);
+    }
+
+    /// Returns `true` if a preference with the given name was applied.
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// Get an applied preference by its name.
+    pub fn get(&self, name: &str) -> Option<&Preference> {
+        self.preferences
+            .iter()
+            .find(|pref| pref.name().eq_ignore_ascii_case(name))
+    }
+
+    /// An iterator visiting all applied preferences.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.preferences.iter(),
+        }
+    }
+}
+
+impl Header for PreferenceApplied {
+    fn header_name(&self) -> HeaderName {
+        PREFERENCE_APPLIED
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, pref) in self.preferences.iter().enumerate() {
+            if n > 0 {
+                output.push_str(", ");
+            }
+            pref.write_to(&mut output, false);
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for PreferenceApplied {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a PreferenceApplied {
+    type Item = &'a Preference;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowing iterator over entries in `PreferenceApplied`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, Preference>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Preference;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for PreferenceApplied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for pref in &self.preferences {
+            list.entry(pref);
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{Headers, PREFER};
+    use crate::other::Prefer;
+
+    #[test]
+    fn emit_applied() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(PREFER, "return=representation; handling=lenient, x-unknown")?;
+        let prefer = Prefer::from_headers(headers)?.unwrap();
+
+        let mut applied = PreferenceApplied::new();
+        applied.push(prefer.get("return").unwrap().clone());
+
+        let mut headers = Headers::new();
+        applied.apply(&mut headers);
+        assert_eq!(headers[PREFERENCE_APPLIED], "return=representation");
+
+        let applied = PreferenceApplied::from_headers(headers)?.unwrap();
+        assert!(applied.contains("Return"));
+        assert!(!applied.contains("x-unknown"));
+        Ok(())
+    }
+}
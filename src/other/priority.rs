@@ -0,0 +1,189 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, PRIORITY};
+use crate::structured::{self, BareItem, Dictionary, Item};
+
+/// The priority a client would like a response to be sent with.
+///
+/// The urgency ranges from `0` (most urgent) to `7` (least urgent), and
+/// defaults to `3`. Incremental responses can be processed as their data
+/// arrives, and may be interleaved with other responses of the same urgency.
+///
+/// # Specifications
+///
+/// - [RFC 9218, section 4: Priority Parameters](https://tools.ietf.org/html/rfc9218#section-4)
+/// - [RFC 9218, section 5: The Priority HTTP Header Field](https://tools.ietf.org/html/rfc9218#section-5)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::other::Priority;
+/// use http_types::Request;
+///
+/// let mut req = Request::get("https://example.com/style.css");
+/// req.insert_header("Priority", "u=0")?;
+///
+/// let priority = Priority::from_headers(&req)?.unwrap();
+/// assert_eq!(priority.urgency(), 0);
+/// assert!(!priority.incremental());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Priority {
+    urgency: u8,
+    incremental: bool,
+}
+
+impl Priority {
+    /// The default urgency.
+    pub const DEFAULT_URGENCY: u8 = 3;
+
+    /// The lowest urgency.
+    pub const MAX_URGENCY: u8 = 7;
+
+    /// Create a new instance of `Priority`.
+    ///
+    /// Urgencies above `7` are clamped to `7`.
+    pub fn new(urgency: u8, incremental: bool) -> Self {
+        Self {
+            urgency: urgency.min(Self::MAX_URGENCY),
+            incremental,
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Out of range urgencies are clamped, and parameters with a value of the
+    /// wrong type or an unknown name are ignored.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header
+    /// isn't a valid structured dictionary.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(PRIORITY) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let values: Vec<_> = headers.iter().map(|value| value.as_str()).collect();
+        let dict = structured::parse_dictionary(&values.join(", "))?;
+
+        let mut priority = Self::default();
+        let item = |key| dict.get(key).and_then(|member| member.as_item());
+        if let Some(urgency) = item("u").and_then(|item| item.value.as_integer()) {
+            priority.urgency = urgency.clamp(0, Self::MAX_URGENCY as i64) as u8;
+        }
+        if let Some(incremental) = item("i").and_then(|item| item.value.as_boolean()) {
+            priority.incremental = incremental;
+        }
+        Ok(Some(priority))
+    }
+
+    /// Sets the `Priority` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Get the urgency, from `0` (most urgent) to `7` (least urgent).
+    pub fn urgency(&self) -> u8 {
+        self.urgency
+    }
+
+    /// Set the urgency.
+    ///
+    /// Urgencies above `7` are clamped to `7`.
+    pub fn set_urgency(&mut self, urgency: u8) {
+        self.urgency = urgency.min(Self::MAX_URGENCY);
+    }
+
+    /// Returns `true` if the response can be processed incrementally.
+    pub fn incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Set whether the response can be processed incrementally.
+    pub fn set_incremental(&mut self, incremental: bool) {
+        self.incremental = incremental;
+    }
+}
+
+impl Header for Priority {
+    fn header_name(&self) -> HeaderName {
+        PRIORITY
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut dict = Dictionary::new();
+        dict.insert("u", Item::new(BareItem::Integer(self.urgency.into())));
+        if self.incremental {
+            dict.insert("i", Item::new(BareItem::Boolean(true)));
+        }
+        let output = structured::serialize_dictionary(&dict)
+            .expect("priority parameters are always serializable");
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_URGENCY, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let priority = Priority::new(5, true);
+
+        let mut headers = Headers::new();
+        priority.apply(&mut headers);
+        assert_eq!(headers[PRIORITY], "u=5, i");
+
+        let priority = Priority::from_headers(headers)?.unwrap();
+        assert_eq!(priority, Priority::new(5, true));
+        Ok(())
+    }
+
+    #[test]
+    fn parse() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(PRIORITY, "u=2, i")?;
+        let priority = Priority::from_headers(headers)?.unwrap();
+        assert_eq!(priority.urgency(), 2);
+        assert!(priority.incremental());
+
+        let mut headers = Headers::new();
+        headers.insert(PRIORITY, "i=?0, foo=bar")?;
+        let priority = Priority::from_headers(headers)?.unwrap();
+        assert_eq!(priority, Priority::default());
+        Ok(())
+    }
+
+    #[test]
+    fn clamp_urgency() -> crate::Result<()> {
+        for (value, urgency) in &[("u=9", 7), ("u=-1", 0), ("u=1.5", 3), ("u=\"1\"", 3)] {
+            let mut headers = Headers::new();
+            headers.insert(PRIORITY, *value)?;
+            let priority = Priority::from_headers(headers)?.unwrap();
+            assert_eq!(priority.urgency(), *urgency, "{}", value);
+        }
+        assert_eq!(Priority::new(200, false).urgency(), 7);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_malformed_header() {
+        let mut headers = Headers::new();
+        headers.insert(PRIORITY, "u=2,").unwrap();
+        let err = Priority::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+}
@@ -58,16 +58,24 @@ impl RetryAfter {
     }
 
     /// Create a new instance from headers.
+    ///
+    /// The header may hold either a delay in seconds, or an HTTP date.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the header is
+    /// neither a non-negative number of seconds nor a valid date.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
         let header = match headers.as_ref().get(RETRY_AFTER) {
             Some(headers) => headers.last(),
             None => return Ok(None),
         };
 
-        let inner = match header.as_str().parse::<u64>() {
+        let value = header.as_str().trim();
+        let inner = match value.parse::<u64>() {
             Ok(dur) => RetryDirective::Duration(Duration::from_secs(dur)),
             Err(_) => {
-                let at = parse_http_date(header.as_str())?;
+                let at = parse_http_date(value)?;
                 RetryDirective::SystemTime(at)
             }
         };
@@ -87,6 +95,39 @@ impl RetryAfter {
 
         at.duration_since(earlier)
     }
+
+    /// Returns how long to wait, measured from `now`.
+    ///
+    /// A delay is returned as-is, while a date is converted into the time
+    /// remaining until that date. Dates in the past return a zero duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::other::RetryAfter;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let now = SystemTime::now();
+    /// let retry = RetryAfter::new(Duration::from_secs(30));
+    /// assert_eq!(retry.duration_from(now), Duration::from_secs(30));
+    ///
+    /// let retry = RetryAfter::new_at(now + Duration::from_secs(30));
+    /// assert_eq!(retry.duration_from(now), Duration::from_secs(30));
+    ///
+    /// let retry = RetryAfter::new_at(now - Duration::from_secs(30));
+    /// assert_eq!(retry.duration_from(now), Duration::from_secs(0));
+    /// ```
+    pub fn duration_from(&self, now: SystemTime) -> Duration {
+        match self.inner {
+            RetryDirective::Duration(dur) => dur,
+            RetryDirective::SystemTime(at) => at.duration_since(now).unwrap_or_default(),
+        }
+    }
+
+    /// Sets the `Retry-After` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
 }
 
 impl Header for RetryAfter {
@@ -162,4 +203,46 @@ mod test {
         assert!(delta <= Duration::from_secs(10));
         Ok(())
     }
+
+    #[test]
+    fn numeric() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "120")?;
+
+        let retry = RetryAfter::from_headers(&headers)?.unwrap();
+        let now = SystemTime::now();
+        assert_eq!(retry.duration_from(now), Duration::from_secs(120));
+
+        retry.apply(&mut headers);
+        assert_eq!(headers[RETRY_AFTER], "120");
+        Ok(())
+    }
+
+    #[test]
+    fn date() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "Fri, 31 Dec 1999 23:59:59 GMT")?;
+
+        let retry = RetryAfter::from_headers(&headers)?.unwrap();
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(946_684_799);
+        assert_eq!(SystemTime::from(retry.clone()), at);
+        assert_eq!(
+            retry.duration_from(at - Duration::from_secs(60)),
+            Duration::from_secs(60)
+        );
+
+        retry.apply(&mut headers);
+        assert_eq!(headers[RETRY_AFTER], "Fri, 31 Dec 1999 23:59:59 GMT");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["-10", "1.5", "tomorrow"] {
+            let mut headers = Headers::new();
+            headers.insert(RETRY_AFTER, *value).unwrap();
+            let err = RetryAfter::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
 }
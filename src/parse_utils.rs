@@ -44,7 +44,7 @@ fn tchar(c: char) -> bool {
 
 /// https://tools.ietf.org/html/rfc7230#section-3.2.6
 fn vchar(c: char) -> bool {
-    matches!(c as u8, b'\t' | 32..=126 | 128..=255)
+    !c.is_ascii() || matches!(c as u8, b'\t' | 32..=126)
 }
 
 /// https://tools.ietf.org/html/rfc7230#section-3.2.6
@@ -64,6 +64,8 @@ pub(crate) fn parse_quoted_string(input: &str) -> (Option<Cow<'_, str>>, &str) {
                 return (None, input);
             }
         // otherwise, we skip over this character while parsing
+        } else if !c.is_ascii() {
+            // obs-text, which may be UTF-8 encoded
         } else {
             match c as u8 {
                 // we have reached a quoted-pair
@@ -78,7 +80,7 @@ pub(crate) fn parse_quoted_string(input: &str) -> (Option<Cow<'_, str>>, &str) {
                 }
 
                 // qdtext
-                b'\t' | b' ' | 15 | 35..=91 | 93..=126 | 128..=255 => {}
+                b'\t' | b' ' | 15 | 35..=91 | 93..=126 => {}
 
                 // unexpected character, bail
                 _ => return (None, input),
@@ -117,6 +119,113 @@ pub(crate) fn parse_quoted_string(input: &str) -> (Option<Cow<'_, str>>, &str) {
     }
 }
 
+/// Check that a string can be formatted as a quoted-string: it may only
+/// contain tabs, spaces, and visible ASCII characters.
+///
+/// https://tools.ietf.org/html/rfc7230#section-3.2.6
+pub(crate) fn is_quotable(input: &str) -> bool {
+    input
+        .bytes()
+        .all(|b| b == b'\t' || (b' '..=b'~').contains(&b))
+}
+
+/// Split a comma-separated list into its elements, without splitting the
+/// quoted-strings inside of them. Elements are neither trimmed nor skipped
+/// when empty.
+///
+/// https://tools.ietf.org/html/rfc7230#section-7
+pub(crate) fn split_list(input: &str) -> Vec<&str> {
+    let mut elements = vec![];
+    let mut start = 0;
+    let mut rest = input;
+    while let Some(i) = rest.find([',', '"']) {
+        if rest[i..].starts_with(',') {
+            let end = input.len() - rest.len() + i;
+            elements.push(&input[start..end]);
+            start = end + 1;
+            rest = &input[start..];
+        } else {
+            // An unterminated quoted-string extends to the end of the input.
+            rest = match parse_quoted_string(&rest[i..]) {
+                (Some(_), rest) => rest,
+                (None, _) => "",
+            };
+        }
+    }
+    elements.push(&input[start..]);
+    elements
+}
+
+/// Skip optional whitespace.
+///
+/// https://tools.ietf.org/html/rfc7230#section-3.2.3
+pub(crate) fn skip_ows(input: &str) -> &str {
+    input.trim_start_matches([' ', '\t'])
+}
+
+/// Parse a comma-separated list, skipping empty elements. Each element is
+/// parsed from the start of the remaining input by `parse_element`, and must
+/// be followed by a comma or the end of the input.
+///
+/// An error with status `400: Bad Request` is returned for an element which
+/// isn't followed by a comma, using `name` to describe it.
+///
+/// https://tools.ietf.org/html/rfc7230#section-7
+pub(crate) fn parse_list<'a, T>(
+    mut input: &'a str,
+    name: &str,
+    mut parse_element: impl FnMut(&'a str) -> crate::Result<(T, &'a str)>,
+) -> crate::Result<Vec<T>> {
+    let mut elements = vec![];
+    loop {
+        input = skip_ows(input);
+        if let Some(rest) = input.strip_prefix(',') {
+            input = rest;
+            continue;
+        }
+        if input.is_empty() {
+            return Ok(elements);
+        }
+
+        let (element, rest) = parse_element(input)?;
+        input = skip_ows(rest);
+        if !input.is_empty() && !input.starts_with(',') {
+            crate::bail_status!(400, "invalid {}", name);
+        }
+        elements.push(element);
+    }
+}
+
+/// Parse the `;`-separated parameters of a list element. Each parameter is
+/// parsed from the start of the remaining input by `parse_param`, and the
+/// input following the last parameter is returned.
+pub(crate) fn parse_params<'a>(
+    input: &'a str,
+    mut parse_param: impl FnMut(&'a str) -> crate::Result<&'a str>,
+) -> crate::Result<&'a str> {
+    let mut input = skip_ows(input);
+    while let Some(rest) = input.strip_prefix(';') {
+        input = skip_ows(parse_param(skip_ows(rest))?);
+    }
+    Ok(input)
+}
+
+/// Format a string as a quoted-string, escaping quotes and backslashes.
+///
+/// https://tools.ietf.org/html/rfc7230#section-3.2.6
+pub(crate) fn format_quoted_string(input: &str) -> String {
+    let mut string = String::with_capacity(input.len() + 2);
+    string.push('"');
+    for ch in input.chars() {
+        if let '\\' | '"' = ch {
+            string.push('\\');
+        }
+        string.push(ch);
+    }
+    string.push('"');
+    string
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -161,6 +270,47 @@ mod test {
         );
     }
 
+    #[test]
+    fn split_lists() {
+        assert_eq!(split_list("a, b,,c"), ["a", " b", "", "c"]);
+        assert_eq!(
+            split_list(r#"no-cache="Set-Cookie, Foo", private"#),
+            [r#"no-cache="Set-Cookie, Foo""#, " private"]
+        );
+        assert_eq!(split_list(r#"a="\", b", c"#), [r#"a="\", b""#, " c"]);
+        assert_eq!(split_list(r#"a="b, c"#), [r#"a="b, c"#]);
+        assert_eq!(split_list(""), [""]);
+    }
+
+    #[test]
+    fn parse_lists() -> crate::Result<()> {
+        let parse_element = |input| match parse_token(input) {
+            (Some(token), rest) => {
+                let mut params = vec![];
+                let rest = parse_params(rest, |input| match parse_token(input) {
+                    (Some(param), rest) => {
+                        params.push(param);
+                        Ok(rest)
+                    }
+                    (None, _) => crate::bail_status!(400, "invalid param"),
+                })?;
+                Ok(((token, params), rest))
+            }
+            (None, _) => crate::bail_status!(400, "invalid token"),
+        };
+
+        let list = parse_list(" ,a ; b;c , ,d,", "element", parse_element)?;
+        assert_eq!(list, [("a", vec!["b", "c"]), ("d", vec![])]);
+        assert_eq!(parse_list("", "element", parse_element)?, []);
+
+        let err = parse_list("a b", "element", parse_element).unwrap_err();
+        assert_eq!(err.status(), 400);
+        assert_eq!(err.to_string(), "invalid element");
+        let err = parse_list("a;", "element", parse_element).unwrap_err();
+        assert_eq!(err.to_string(), "invalid param");
+        Ok(())
+    }
+
     #[test]
     fn qstring_unsuccessful_parses() {
         assert_eq!(parse_quoted_string(r#""abc"#), (None, "\"abc"));
@@ -169,4 +319,15 @@ mod test {
         assert_eq!(parse_quoted_string(r#"\""#), (None, r#"\""#));
         assert_eq!(parse_quoted_string(r#""\""#), (None, r#""\""#));
     }
+
+    #[test]
+    fn qstring_format_round_trips() {
+        let input = r#"escaped " quote \ backslash"#;
+        let formatted = format_quoted_string(input);
+        assert_eq!(formatted, r#""escaped \" quote \\ backslash""#);
+        assert_eq!(
+            parse_quoted_string(&formatted),
+            (Some(Cow::Owned(String::from(input))), "")
+        );
+    }
 }
@@ -112,7 +112,13 @@ impl<'a> Forwarded<'a> {
         headers: &'a impl AsRef<Headers>,
     ) -> Result<Option<Self>, ParseError> {
         if let Some(headers) = headers.as_ref().get(FORWARDED) {
-            Ok(Some(Self::parse(headers.as_ref().as_str())?))
+            // Multiple header lines are equivalent to a single list, with the
+            // lines appended to each other in order.
+            let mut forwarded = Forwarded::new();
+            for value in headers {
+                forwarded.merge(Self::parse(value.as_str())?);
+            }
+            Ok(Some(forwarded))
         } else {
             Ok(None)
         }
@@ -149,8 +155,8 @@ impl<'a> Forwarded<'a> {
         let forwarded_for: Vec<Cow<'a, str>> = headers
             .get(X_FORWARDED_FOR)
             .map(|hv| {
-                hv.as_str()
-                    .split(',')
+                hv.iter()
+                    .flat_map(|v| v.as_str().split(','))
                     .map(|v| {
                         let v = v.trim();
                         match v.parse::<IpAddr>().ok() {
@@ -209,14 +215,44 @@ impl<'a> Forwarded<'a> {
         let mut forwarded = Forwarded::new();
 
         while !input.is_empty() {
+            let mut element = Forwarded::new();
+            input = element.parse_element(input)?;
+            forwarded.merge(element);
+        }
+
+        Ok(forwarded)
+    }
+
+    /// Parse a single forwarded-element, as added by one proxy in a chain.
+    fn parse_element(&mut self, input: &'a str) -> Result<&'a str, ParseError> {
+        let mut input = input;
+
+        loop {
             input = if starts_with_ignore_case("for=", input) {
-                forwarded.parse_for(input)?
+                self.parse_for(input)?
             } else {
-                forwarded.parse_forwarded_pair(input)?
+                self.parse_forwarded_pair(input)?
+            };
+
+            if input.is_empty() {
+                return Ok(input);
+            }
+
+            // a comma after a forwarded-pair starts the next hop's element
+            if let Some(rest) = input.trim_start().strip_prefix(',') {
+                return Ok(rest.trim_start());
             }
         }
+    }
 
-        Ok(forwarded)
+    /// Merge the element of a later hop into this one. The `for` values are
+    /// appended to the chain, while `by`, `host`, and `proto` keep the value
+    /// closest to the client.
+    fn merge(&mut self, other: Forwarded<'a>) {
+        self.forwarded_for.extend(other.forwarded_for);
+        self.by = self.by.take().or(other.by);
+        self.host = self.host.take().or(other.host);
+        self.proto = self.proto.take().or(other.proto);
     }
 
     fn parse_forwarded_pair(&mut self, input: &'a str) -> Result<&'a str, ParseError> {
@@ -356,6 +392,13 @@ impl<'a> Forwarded<'a> {
     pub fn by(&self) -> Option<&str> {
         self.by.as_deref()
     }
+
+    /// Returns the `by` field of this header
+    ///
+    /// This is an alias for [`Forwarded::by`].
+    pub fn forwarded_by(&self) -> Option<&str> {
+        self.by()
+    }
 }
 
 impl<'a> Header for Forwarded<'a> {
@@ -596,6 +639,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn multi_hop_chain() -> Result<()> {
+        let forwarded = Forwarded::parse(
+            r#"for=192.0.2.43;proto=http;by=203.0.113.60, for="[2001:db8:cafe::17]:4711";proto=https, for=unknown"#,
+        )?;
+        assert_eq!(
+            forwarded.forwarded_for(),
+            vec!["192.0.2.43", "[2001:db8:cafe::17]:4711", "unknown"]
+        );
+        assert_eq!(forwarded.proto(), Some("http"));
+        assert_eq!(forwarded.forwarded_by(), Some("203.0.113.60"));
+        assert!(forwarded.host().is_none());
+
+        let err = Forwarded::parse("for=a;proto=http;proto=https, for=b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unable to parse forwarded header: parse error, duplicate `proto` key"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn prefers_forwarded_over_x_headers() -> Result<()> {
+        let mut request = Request::new(Get, Url::parse("http://_/")?);
+        request
+            .append_header(X_FORWARDED_FOR, "198.51.100.17")
+            .unwrap();
+        request.append_header(X_FORWARDED_PROTO, "http").unwrap();
+        request
+            .append_header("Forwarded", "for=192.0.2.43;proto=https")
+            .unwrap();
+
+        let forwarded = Forwarded::from_headers(&request)?.unwrap();
+        assert_eq!(forwarded.forwarded_for(), vec!["192.0.2.43"]);
+        assert_eq!(forwarded.proto(), Some("https"));
+        Ok(())
+    }
+
     #[test]
     fn formatting_edge_cases() {
         let mut forwarded = Forwarded::new();
@@ -0,0 +1,157 @@
+use crate::{bail_status as bail, ensure_status as ensure};
+
+use std::fmt::{self, Display};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// A range of IP addresses, written in CIDR notation.
+///
+/// Used to describe which proxies are trusted when resolving the client
+/// address of a request. IPv4-mapped IPv6 addresses match IPv4 ranges.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::IpNet;
+///
+/// let net: IpNet = "10.0.0.0/8".parse()?;
+/// assert!(net.contains("10.1.2.3".parse()?));
+/// assert!(!net.contains("192.0.2.1".parse()?));
+///
+/// let net: IpNet = "2001:db8::1".parse()?;
+/// assert_eq!(net.prefix_len(), 128);
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpNet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpNet {
+    /// Create a new range from an address and a prefix length.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the prefix length is longer than the address.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> crate::Result<Self> {
+        ensure!(
+            prefix_len <= max_prefix_len(&addr),
+            400,
+            "prefix length {} is too long for {}",
+            prefix_len,
+            addr
+        );
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// Get the network address.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Get the prefix length.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns `true` if the address is part of the range.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        let addr = match addr {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(addr, IpAddr::V4),
+            IpAddr::V4(_) => addr,
+        };
+        match (self.addr, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(self.prefix_len));
+                let mask = mask.unwrap_or(0);
+                u32::from(net) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(self.prefix_len));
+                let mask = mask.unwrap_or(0);
+                u128::from(net) & mask == u128::from(addr) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<IpAddr> for IpNet {
+    fn from(addr: IpAddr) -> Self {
+        let prefix_len = max_prefix_len(&addr);
+        Self { addr, prefix_len }
+    }
+}
+
+impl FromStr for IpNet {
+    type Err = crate::Error;
+
+    /// Parse an address with an optional prefix length, e.g. `10.0.0.0/8`.
+    ///
+    /// An address without a prefix length matches only itself.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s, None),
+        };
+        let addr: IpAddr = match addr.parse() {
+            Ok(addr) => addr,
+            Err(_) => bail!(400, "invalid IP address `{}`", addr),
+        };
+        match prefix_len {
+            None => Ok(Self::from(addr)),
+            Some(prefix_len) => match prefix_len.parse() {
+                Ok(prefix_len) => Self::new(addr, prefix_len),
+                Err(_) => bail!(400, "invalid prefix length `{}`", prefix_len),
+            },
+        }
+    }
+}
+
+impl Display for IpNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+fn max_prefix_len(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn contains() -> crate::Result<()> {
+        let net: IpNet = "192.168.0.0/16".parse()?;
+        assert!(net.contains("192.168.10.1".parse()?));
+        assert!(net.contains("::ffff:192.168.10.1".parse()?));
+        assert!(!net.contains("192.169.0.1".parse()?));
+        assert!(!net.contains("::1".parse()?));
+
+        let net: IpNet = "0.0.0.0/0".parse()?;
+        assert!(net.contains("203.0.113.9".parse()?));
+
+        let net: IpNet = "fd00::/8".parse()?;
+        assert!(net.contains("fd12:3456::1".parse()?));
+        assert!(!net.contains("fe80::1".parse()?));
+        assert_eq!(net.to_string(), "fd00::/8");
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["10.0.0.0/33", "::/129", "10.0.0/8", "10.0.0.0/x", "local"] {
+            let err = value.parse::<IpNet>().unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
@@ -1,3 +1,8 @@
 //! Headers that are set by proxies
 mod forwarded;
+mod ip_net;
+mod via;
+
 pub use forwarded::Forwarded;
+pub use ip_net::IpNet;
+pub use via::{Via, ViaEntry};
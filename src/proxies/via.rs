@@ -0,0 +1,325 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, VIA};
+use crate::parse_utils::is_quotable;
+
+use std::fmt::{self, Display, Write};
+use std::slice;
+
+/// The intermediaries a message passed through.
+///
+/// Each proxy appends an entry with the protocol it received the message
+/// with, its own name, and an optional comment identifying its software.
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 5.7.1: Via](https://tools.ietf.org/html/rfc7230#section-5.7.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::proxies::Via;
+/// use http_types::{Method, Request};
+///
+/// let mut req = Request::new(Method::Get, "https://example.com");
+/// req.insert_header("Via", "1.0 fred, 1.1 p.example.net")?;
+///
+/// let mut via = Via::from_headers(&req)?.unwrap();
+/// via.push("1.1", "proxy.example.com", Some("Nori/1.0"))?;
+/// via.apply(&mut req);
+///
+/// assert_eq!(
+///     req["Via"],
+///     "1.0 fred, 1.1 p.example.net, 1.1 proxy.example.com (Nori/1.0)"
+/// );
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Via {
+    entries: Vec<ViaEntry>,
+}
+
+/// A single intermediary in a `Via` header.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ViaEntry {
+    protocol: String,
+    received_by: String,
+    comment: Option<String>,
+}
+
+impl ViaEntry {
+    /// Get the protocol the message was received with, such as `1.1` or
+    /// `HTTP/2.0`. The protocol name defaults to `HTTP` if omitted.
+    pub fn protocol(&self) -> &str {
+        &self.protocol
+    }
+
+    /// Get the host, or pseudonym, of the intermediary.
+    pub fn received_by(&self) -> &str {
+        &self.received_by
+    }
+
+    /// Get the comment, without the surrounding parentheses.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+}
+
+impl Display for ViaEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.protocol, self.received_by)?;
+        if let Some(comment) = &self.comment {
+            f.write_str(" (")?;
+            for c in comment.chars() {
+                match c {
+                    '(' | ')' | '\\' => write!(f, "\\{}", c)?,
+                    c => f.write_char(c)?,
+                }
+            }
+            f.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+impl Via {
+    /// Create a new instance of `Via`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if any of the
+    /// entries is malformed.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(VIA) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        let mut via = Self::new();
+        for value in headers {
+            parse_entries(value.as_str(), &mut via.entries)?;
+        }
+        Ok(Some(via))
+    }
+
+    /// Append an intermediary to the chain.
+    ///
+    /// The protocol and the name of the intermediary can't contain
+    /// whitespace or commas. Parentheses and backslashes in the comment are
+    /// escaped.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the protocol or
+    /// the name of the intermediary are empty, or contain whitespace, commas,
+    /// or non-ASCII characters, or if the comment contains control or
+    /// non-ASCII characters.
+    pub fn push(
+        &mut self,
+        protocol: &str,
+        received_by: &str,
+        comment: Option<&str>,
+    ) -> crate::Result<()> {
+        if !is_word(protocol) {
+            bail!(400, "Invalid Via protocol {:?}", protocol);
+        }
+        if !is_word(received_by) {
+            bail!(400, "Invalid Via host {:?}", received_by);
+        }
+        if let Some(comment) = comment {
+            if !is_quotable(comment) {
+                bail!(400, "Invalid Via comment {:?}", comment);
+            }
+        }
+        self.entries.push(ViaEntry {
+            protocol: protocol.to_string(),
+            received_by: received_by.to_string(),
+            comment: comment.map(|comment| comment.to_string()),
+        });
+        Ok(())
+    }
+
+    /// An iterator visiting all entries, starting with the intermediary
+    /// closest to the sender.
+    pub fn iter(&self) -> slice::Iter<'_, ViaEntry> {
+        self.entries.iter()
+    }
+
+    /// Sets the `Via` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+}
+
+impl Default for Via {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Header for Via {
+    fn header_name(&self) -> HeaderName {
+        VIA
+    }
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, entry) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", entry).unwrap(),
+                _ => write!(output, ", {}", entry).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+fn is_word(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_graphic() && c != ',')
+}
+
+/// Parse a comma-separated list of `protocol received-by [comment]` entries.
+fn parse_entries(mut input: &str, entries: &mut Vec<ViaEntry>) -> crate::Result<()> {
+    let invalid = || crate::format_err!("Invalid Via header");
+    loop {
+        input = input.trim_start_matches([',', ' ', '\t']);
+        if input.is_empty() {
+            return Ok(());
+        }
+
+        let (protocol, rest) = split_word(input);
+        let (received_by, rest) = split_word(rest.trim_start());
+        if protocol.is_empty() || received_by.is_empty() {
+            return Err(with_status(invalid()));
+        }
+
+        let mut rest = rest.trim_start();
+        let comment = if rest.starts_with('(') {
+            let (comment, remainder) = parse_comment(rest).ok_or_else(|| with_status(invalid()))?;
+            rest = remainder.trim_start();
+            Some(comment)
+        } else {
+            None
+        };
+        if !(rest.is_empty() || rest.starts_with(',')) {
+            return Err(with_status(invalid()));
+        }
+
+        entries.push(ViaEntry {
+            protocol: protocol.to_string(),
+            received_by: received_by.to_string(),
+            comment,
+        });
+        input = rest;
+    }
+}
+
+fn split_word(input: &str) -> (&str, &str) {
+    let end = input.find([',', ' ', '\t', '(']).unwrap_or(input.len());
+    input.split_at(end)
+}
+
+/// Parse a parenthesized comment, which may contain nested comments and
+/// backslash-escaped characters.
+fn parse_comment(input: &str) -> Option<(String, &str)> {
+    let mut comment = String::new();
+    let mut depth = 0;
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => comment.push(chars.next()?.1),
+            '(' => {
+                if depth > 0 {
+                    comment.push(c);
+                }
+                depth += 1;
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((comment, &input[i + 1..]));
+                }
+                comment.push(c);
+            }
+            c => comment.push(c),
+        }
+    }
+    None
+}
+
+fn with_status(mut err: crate::Error) -> crate::Error {
+    err.set_status(400);
+    err
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn append_to_chain() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(VIA, "1.0 fred, 1.1 p.example.net (Apache/2.4)")?;
+
+        let mut via = Via::from_headers(&headers)?.unwrap();
+        via.push("HTTP/2.0", "proxy.example.com", Some("Nori (Unix) \\o/"))?;
+        via.apply(&mut headers);
+
+        assert_eq!(
+            headers[VIA],
+            r"1.0 fred, 1.1 p.example.net (Apache/2.4), HTTP/2.0 proxy.example.com (Nori \(Unix\) \\o/)"
+        );
+
+        let via = Via::from_headers(headers)?.unwrap();
+        let entries: Vec<_> = via.iter().collect();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].protocol(), "1.0");
+        assert_eq!(entries[0].received_by(), "fred");
+        assert_eq!(entries[0].comment(), None);
+        assert_eq!(entries[1].comment(), Some("Apache/2.4"));
+        assert_eq!(entries[2].protocol(), "HTTP/2.0");
+        assert_eq!(entries[2].comment(), Some("Nori (Unix) \\o/"));
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_headers() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(VIA, "1.0 fred")?;
+        headers.append(VIA, "1.1 nowhere.com (Apache/1.1)")?;
+
+        let via = Via::from_headers(headers)?.unwrap();
+        assert_eq!(via.iter().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid_entries() {
+        let mut via = Via::new();
+        let err = via.push("1.1", "proxy example", None).unwrap_err();
+        assert_eq!(err.status(), 400);
+        assert!(via.push("", "proxy", None).is_err());
+        let injected = Some("a\r\nSet-Cookie: evil=1");
+        assert!(via.push("1.1", "proxy", injected).is_err());
+        assert!(via.push("1.1", "proxy", Some("café")).is_err());
+        assert_eq!(via.iter().count(), 0);
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["1.1", "1.1 fred (unterminated", "1.1 fred (a) junk"] {
+            let mut headers = Headers::new();
+            headers.insert(VIA, *value).unwrap();
+            let err = Via::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", value);
+        }
+    }
+}
@@ -1,20 +1,25 @@
 use futures_lite::{io, prelude::*};
 
+use std::borrow::Cow;
 use std::convert::{Into, TryInto};
 use std::mem;
+use std::net::{IpAddr, SocketAddr};
 use std::ops::Index;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::content::ContentLength;
 #[cfg(feature = "serde")]
 use crate::convert::{DeserializeOwned, Serialize};
+use crate::cors::Origin;
 use crate::headers::{
     self, HeaderName, HeaderValue, HeaderValues, Headers, Names, ToHeaderValues, Values,
-    CONTENT_TYPE,
+    CONTENT_LENGTH, CONTENT_TYPE, FORWARDED,
 };
 use crate::mime::Mime;
+use crate::proxies::{Forwarded, IpNet};
 use crate::trailers::{self, Trailers};
-use crate::{Body, Extensions, Method, Url, Version};
+use crate::{Body, Extensions, Method, RequestBuilder, Url, Version};
 
 pin_project_lite::pin_project! {
     /// An HTTP request.
@@ -71,6 +76,22 @@ impl Request {
     /// Sets a string representation of the peer address of this
     /// request. This might take the form of an ip/fqdn and port or a
     /// local socket address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Method, Request};
+    /// use std::net::SocketAddr;
+    ///
+    /// let mut req = Request::new(Method::Get, "https://example.com");
+    /// let addr: SocketAddr = "127.0.0.1:8000".parse().unwrap();
+    /// req.set_peer_addr(Some(addr));
+    /// assert_eq!(req.peer_addr(), Some("127.0.0.1:8000"));
+    ///
+    /// // Unix domain sockets are represented by their path.
+    /// req.set_local_addr(Some("/var/run/app.sock"));
+    /// assert_eq!(req.local_addr(), Some("/var/run/app.sock"));
+    /// ```
     pub fn set_peer_addr(&mut self, peer_addr: Option<impl std::string::ToString>) {
         self.peer_addr = peer_addr.map(|addr| addr.to_string());
     }
@@ -104,6 +125,76 @@ impl Request {
         self.forwarded_for().or_else(|| self.peer_addr())
     }
 
+    /// Get the IP address of the client which made this request.
+    ///
+    /// Unlike [`Request::remote`], forwarding headers are only trusted if they
+    /// were added by one of the `trusted_proxies`. Starting with the peer
+    /// address, the chain of forwarded addresses is walked from right to left
+    /// until an address outside of `trusted_proxies` is found. If all of them
+    /// are trusted, the leftmost address is returned.
+    ///
+    /// Returns `None` if the peer address is unknown, or if a trusted proxy
+    /// forwarded an address which isn't an IP address, such as `unknown`.
+    /// Because a client can send either header itself, `None` is also
+    /// returned when a request from a trusted proxy has both a `Forwarded`
+    /// and an `X-Forwarded-For` header: it isn't known which one the proxy
+    /// added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::proxies::IpNet;
+    /// use http_types::Request;
+    ///
+    /// let trusted: Vec<IpNet> = vec!["10.0.0.0/8".parse()?];
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// req.set_peer_addr(Some("10.0.0.2:8080"));
+    /// req.insert_header("X-Forwarded-For", "6.6.6.6, 203.0.113.7, 10.0.0.1")?;
+    ///
+    /// // The proxy at 10.0.0.1 isn't trusted to tell who 203.0.113.7 is.
+    /// assert_eq!(req.client_ip(&trusted), Some("203.0.113.7".parse()?));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn client_ip(&self, trusted_proxies: &[IpNet]) -> Option<IpAddr> {
+        fn parse_ip(node: &str) -> Option<IpAddr> {
+            let node = node.trim_matches('"');
+            if let Ok(addr) = node.parse::<SocketAddr>() {
+                return Some(addr.ip());
+            }
+            node.trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse()
+                .ok()
+        }
+
+        let is_trusted = |ip: IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+
+        let mut client = parse_ip(self.peer_addr()?)?;
+        if !is_trusted(client) {
+            return Some(client);
+        }
+
+        if self.header(FORWARDED).is_some() && self.header("X-Forwarded-For").is_some() {
+            return None;
+        }
+        let forwarded = match Forwarded::from_headers(self) {
+            Ok(Some(forwarded)) => forwarded,
+            Ok(None) => return Some(client),
+            Err(_) => return None,
+        };
+        for node in forwarded.forwarded_for().into_iter().rev() {
+            client = parse_ip(node)?;
+            if !is_trusted(client) {
+                break;
+            }
+        }
+        Some(client)
+    }
+
     /// Get the destination host for this request.
     ///
     /// This is determined in the following priority:
@@ -151,6 +242,73 @@ impl Request {
         self.method = method;
     }
 
+    /// Get the origin which caused the request, from the `Origin` header.
+    ///
+    /// Returns `None` if the header is absent or malformed, and for the
+    /// opaque `null` origin, which doesn't identify a site. Use
+    /// [`cors::Origin`] to tell these cases apart, and
+    /// [`cors::origin_allowed`] to check the origin against an allowlist.
+    ///
+    /// [`cors::Origin`]: crate::cors::Origin
+    /// [`cors::origin_allowed`]: crate::cors::origin_allowed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::cors::origin_allowed;
+    /// use http_types::{Request, Url};
+    ///
+    /// let mut req = Request::post("https://api.example.com/transfer");
+    /// req.insert_header("Origin", "https://example.com")?;
+    ///
+    /// let origin = req.origin().unwrap();
+    /// assert_eq!(origin.as_str(), "https://example.com/");
+    /// assert!(origin_allowed(origin, &[Url::parse("https://example.com")?]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn origin(&self) -> Option<Url> {
+        let origin = Origin::from_headers(self).ok()??;
+        if origin.is_null() {
+            return None;
+        }
+        Url::parse(origin.as_str()).ok()
+    }
+
+    /// Get the method the client asked to override the request method with,
+    /// using the `X-HTTP-Method-Override` header.
+    ///
+    /// This lets clients which can only send `GET` and `POST` requests, such as
+    /// HTML forms, use other methods. Frameworks typically only apply the
+    /// override to `POST` requests.
+    ///
+    /// Returns `None` if the header is absent, isn't a valid method, or names
+    /// a method which can't be overridden to: `CONNECT`, `TRACE`, and `PRI`
+    /// change how the connection is handled, rather than the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::{Method, Request};
+    ///
+    /// let mut req = Request::post("https://example.com/cats/nori");
+    /// req.insert_header("X-HTTP-Method-Override", "DELETE")?;
+    /// assert_eq!(req.method_override(), Some(Method::Delete));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn method_override(&self) -> Option<Method> {
+        let value = self.header("X-HTTP-Method-Override")?.last();
+        match value.as_str().trim().parse().ok()? {
+            Method::Connect | Method::Trace | Method::Pri => None,
+            method => Some(method),
+        }
+    }
+
     /// Get a reference to the url.
     ///
     /// # Examples
@@ -200,8 +358,10 @@ impl Request {
         self.replace_body(body);
     }
 
-    /// Swaps the value of the body with another body, without deinitializing
-    /// either one.
+    /// Replace the request body with a new body, returning the old body.
+    ///
+    /// The `Content-Length` header is updated to match the new body, or
+    /// removed if its length is unknown.
     ///
     /// # Examples
     ///
@@ -224,10 +384,12 @@ impl Request {
     pub fn replace_body(&mut self, body: impl Into<Body>) -> Body {
         let body = mem::replace(&mut self.body, body.into());
         self.copy_content_type_from_body();
+        self.copy_content_length_from_body();
         body
     }
 
-    /// Replace the request body with a new body, and return the old body.
+    /// Swaps the value of the body with another body, without deinitializing
+    /// either one.
     ///
     /// # Examples
     ///
@@ -251,10 +413,13 @@ impl Request {
     pub fn swap_body(&mut self, body: &mut Body) {
         mem::swap(&mut self.body, body);
         self.copy_content_type_from_body();
+        self.copy_content_length_from_body();
     }
 
     /// Take the request body, replacing it with an empty body.
     ///
+    /// The `Content-Length` header is updated to `0` to match.
+    ///
     /// # Examples
     ///
     /// ```
@@ -470,9 +635,25 @@ impl Request {
         self.headers.append(name, values)
     }
 
-    /// Set the response MIME.
-    // TODO: return a parsed MIME
-    pub fn set_content_type(&mut self, mime: Mime) -> Option<HeaderValues> {
+    /// Set the `Content-Type` header.
+    ///
+    /// The mime type of the body is updated to match, so it's preserved when
+    /// the body is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{mime, Request};
+    ///
+    /// let mut req = Request::post("https://example.com");
+    /// req.set_body("{\"name\": \"Nori\"}");
+    /// req.set_content_type(mime::JSON);
+    /// assert_eq!(req.content_type(), Some(mime::JSON));
+    /// assert_eq!(req.take_body().mime(), Some(&mime::JSON));
+    /// ```
+    pub fn set_content_type(&mut self, mime: impl Into<Mime>) -> Option<HeaderValues> {
+        let mime = mime.into();
+        self.body.set_mime(Some(mime.clone()));
         let value: HeaderValue = mime.into();
 
         // A Mime instance is guaranteed to be valid header name.
@@ -488,7 +669,22 @@ impl Request {
         }
     }
 
-    /// Get the current content type
+    /// Keep the `Content-Length` header in sync with the body. If the length
+    /// of the body is unknown the header is removed.
+    fn copy_content_length_from_body(&mut self) {
+        match self.body.len() {
+            Some(len) => {
+                self.insert_header(CONTENT_LENGTH, len.to_string()).unwrap();
+            }
+            None => {
+                self.remove_header(CONTENT_LENGTH);
+            }
+        }
+    }
+
+    /// Get the current content type, parsed from the `Content-Type` header.
+    ///
+    /// Returns `None` if the header is missing or can't be parsed.
     pub fn content_type(&self) -> Option<Mime> {
         self.header(CONTENT_TYPE)?.last().as_str().parse().ok()
     }
@@ -503,6 +699,29 @@ impl Request {
         self.body.len()
     }
 
+    /// Get the declared length of the body, in bytes.
+    ///
+    /// This is read from the `Content-Length` header, falling back to the
+    /// length of the body if the header is absent. Returns `None` if neither
+    /// is known, or if the header is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::post("https://example.com");
+    /// req.set_body("Hello, Nori!");
+    /// assert_eq!(req.content_length(), Some(12));
+    /// ```
+    pub fn content_length(&self) -> Option<u64> {
+        match ContentLength::from_headers(self) {
+            Ok(Some(length)) => Some(length.len()),
+            Ok(None) => self.body.len(),
+            Err(_) => None,
+        }
+    }
+
     /// Returns `true` if the request has a set body stream length of zero,
     /// `false` otherwise.
     pub fn is_empty(&self) -> Option<bool> {
@@ -572,6 +791,32 @@ impl Request {
         self.has_trailers
     }
 
+    /// Render the request as text resembling its wire format, for debugging.
+    ///
+    /// Headers are sorted by name. The body is never read: a preview of it is
+    /// only shown if it's held in memory, truncated, and in hex unless it's
+    /// text. Otherwise `<stream>` is shown in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::post("https://example.com/cats");
+    /// req.insert_header("X-Cat", "Nori");
+    /// req.set_body("meow");
+    ///
+    /// let dump = req.debug_dump();
+    /// assert!(dump.starts_with("POST https://example.com/cats HTTP/1.1\r\n"));
+    /// assert!(dump.contains("x-cat: Nori\r\n"));
+    /// assert!(dump.ends_with("\r\n\r\nmeow"));
+    /// ```
+    pub fn debug_dump(&self) -> String {
+        let version = self.version.unwrap_or(Version::Http1_1);
+        let start_line = format!("{} {} {}", self.method, self.url, version);
+        crate::utils::debug_dump(&start_line, &self.headers, &self.body)
+    }
+
     /// An iterator visiting all header pairs in arbitrary order.
     pub fn iter(&self) -> headers::Iter<'_> {
         self.headers.iter()
@@ -617,11 +862,90 @@ impl Request {
         &mut self.ext
     }
 
+    /// Insert a value into the local state.
+    ///
+    /// If a value of this type already exists, it will be replaced and
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    ///
+    /// let mut req = Request::get("https://example.com");
+    /// assert_eq!(req.insert_ext(1u8), None);
+    /// assert_eq!(req.insert_ext(2u8), Some(1u8));
+    /// assert_eq!(req.ext().get::<u8>(), Some(&2));
+    /// ```
+    pub fn insert_ext<T: Send + Sync + 'static>(&mut self, val: T) -> Option<T> {
+        self.ext.insert(val)
+    }
+
+    /// Attempt to clone the request, including its body.
+    ///
+    /// The headers, method, URL and version are duplicated. This only succeeds if
+    /// the body is held in memory and hasn't been read from yet; `None` is
+    /// returned for streaming bodies. See [`Body::try_clone`] for details.
+    ///
+    /// Like [`Clone::clone`], extensions are not carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::{Method, Request};
+    ///
+    /// let mut req = Request::new(Method::Post, "https://example.com");
+    /// req.insert_header("X-Nori", "meow")?;
+    /// req.set_body("Hello, Nori!");
+    ///
+    /// let mut clone = req.try_clone().unwrap();
+    /// assert_eq!(clone.method(), Method::Post);
+    /// assert_eq!(clone["X-Nori"], "meow");
+    /// assert_eq!(clone.body_string().await?, "Hello, Nori!");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn try_clone(&self) -> Option<Self> {
+        let body = self.body.try_clone()?;
+        let mut req = self.clone();
+        req.body = body;
+        Some(req)
+    }
+
     /// Get the URL querystring.
     ///
+    /// A missing querystring is deserialized as if it were empty, so structs
+    /// whose fields are all optional always succeed.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the querystring
+    /// cannot be deserialized into `T`.
+    ///
     /// # Examples
     ///
     /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// use http_types::convert::Deserialize;
+    /// use http_types::Request;
+    ///
+    /// #[derive(Deserialize)]
+    /// # #[serde(crate = "serde_crate")]
+    /// struct Filter {
+    ///     limit: u32,
+    ///     q: Option<String>,
+    /// }
+    ///
+    /// let req = Request::get("https://example.com/search?limit=10&q=cats");
+    /// let params: Filter = req.query()?;
+    /// assert_eq!(params.limit, 10);
+    /// assert_eq!(params.q.as_deref(), Some("cats"));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// Nested structures and borrowed data are supported as well:
+    ///
+    /// ```
     /// use http_types::convert::Deserialize;
     /// use http_types::Request;
     /// use std::collections::HashMap;
@@ -668,6 +992,15 @@ impl Request {
 
     /// Set the URL querystring.
     ///
+    /// Any existing querystring is replaced rather than merged into. `None`
+    /// fields are omitted, and if nothing remains the query component is
+    /// removed entirely.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if `query` cannot
+    /// be serialized.
+    ///
     /// # Examples
     ///
     /// ```
@@ -691,10 +1024,129 @@ impl Request {
     pub fn set_query(&mut self, query: &impl Serialize) -> crate::Result<()> {
         let query = serde_qs::to_string(query)
             .map_err(|e| crate::Error::from_str(crate::StatusCode::BadRequest, format!("{}", e)))?;
-        self.url.set_query(Some(&query));
+        if query.is_empty() {
+            self.url.set_query(None);
+        } else {
+            self.url.set_query(Some(&query));
+        }
         Ok(())
     }
 
+    /// Get an iterator over the percent-decoded key/value pairs of the URL
+    /// querystring.
+    ///
+    /// Repeated keys yield one entry per occurrence, in the order they appear.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    ///
+    /// let req = Request::get("https://example.com/?topic=rust&topic=crabs%20and%20more");
+    /// let pairs: Vec<_> = req.query_pairs().collect();
+    /// assert_eq!(pairs.len(), 2);
+    /// assert_eq!(pairs[0], ("topic".into(), "rust".into()));
+    /// assert_eq!(pairs[1], ("topic".into(), "crabs and more".into()));
+    /// ```
+    pub fn query_pairs(&self) -> impl Iterator<Item = (Cow<'_, str>, Cow<'_, str>)> + '_ {
+        self.url.query_pairs()
+    }
+
+    /// Get the percent-decoded value of the first query parameter with the
+    /// given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Request;
+    ///
+    /// let req = Request::get("https://example.com/?page=2&page=3");
+    /// assert_eq!(req.query_first("page").as_deref(), Some("2"));
+    /// assert_eq!(req.query_first("limit"), None);
+    /// ```
+    pub fn query_first(&self, key: &str) -> Option<Cow<'_, str>> {
+        self.query_pairs()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Create a builder for a request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::{mime, Method, Request};
+    ///
+    /// let req = Request::builder(Method::Post, "https://example.com")
+    ///     .content_type(mime::FORM)
+    ///     .body("name=Nori")
+    ///     .build()?;
+    /// assert_eq!(req["Content-Type"], "application/x-www-form-urlencoded");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn builder<U>(method: Method, url: U) -> RequestBuilder
+    where
+        U: TryInto<Url>,
+        U::Error: std::fmt::Debug,
+    {
+        RequestBuilder::new(method, url)
+    }
+
+    /// Reconstruct the absolute url of a request from its request target.
+    ///
+    /// Servers receive the target from the request line, and usually have to
+    /// combine it with the `Host` header to construct the url of a `Request`.
+    /// The target may be in any of the following forms:
+    ///
+    /// - origin-form (`/path?query`) is joined with `scheme` and `host`.
+    /// - absolute-form (`http://example.com/path`) is used as-is, and `host`
+    ///   is ignored.
+    /// - asterisk-form (`*`, used by `OPTIONS`) resolves to the root of
+    ///   `host`.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if the target is in
+    /// any other form, or if `host` is not a valid host.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7230, section 5.3: Request Target](https://tools.ietf.org/html/rfc7230#section-5.3)
+    /// - [RFC 7230, section 5.5: Effective Request URI](https://tools.ietf.org/html/rfc7230#section-5.5)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::Request;
+    ///
+    /// let url = Request::reconstruct_url("https", "/search?q=nori", "example.com")?;
+    /// assert_eq!(url.as_str(), "https://example.com/search?q=nori");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn reconstruct_url(scheme: &str, target: &str, host: &str) -> crate::Result<Url> {
+        if target.starts_with('/') || target == "*" {
+            let host: crate::other::Host = host.parse()?;
+            let path = if target == "*" { "/" } else { target };
+            let url = format!("{}://{}{}", scheme, host, path);
+            return Url::parse(&url).map_err(|err| {
+                let mut err = crate::Error::from(err);
+                err.set_status(400);
+                err
+            });
+        }
+
+        match Url::parse(target) {
+            Ok(url) if !url.cannot_be_a_base() && url.has_host() => Ok(url),
+            _ => crate::bail_status!(400, "invalid request target `{}`", target),
+        }
+    }
+
     /// Create a `GET` request.
     ///
     /// The `GET` method requests a representation of the specified resource.
@@ -1180,6 +1632,23 @@ mod tests {
             assert_eq!(request.remote(), Some("127.0.0.1:8000"));
         }
 
+        #[test]
+        fn peer_and_local_addr() {
+            let mut request = build_test_request();
+            assert_eq!(request.peer_addr(), None);
+            assert_eq!(request.local_addr(), None);
+
+            request.set_peer_addr(Some("[::1]:51234"));
+            request.set_local_addr(Some("/tmp/http.sock"));
+            assert_eq!(request.peer_addr(), Some("[::1]:51234"));
+            assert_eq!(request.local_addr(), Some("/tmp/http.sock"));
+
+            request.set_peer_addr(None::<String>);
+            request.set_local_addr(None::<String>);
+            assert_eq!(request.peer_addr(), None);
+            assert_eq!(request.local_addr(), None);
+        }
+
         #[test]
         fn when_no_remote_available() {
             let request = build_test_request();
@@ -1188,6 +1657,426 @@ mod tests {
         }
     }
 
+    mod body {
+        use super::*;
+
+        #[async_std::test]
+        async fn replace_body_updates_content_length() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_body("Hello, Nori!");
+            assert_eq!(req[CONTENT_LENGTH], "12");
+
+            let old = req.replace_body(Body::from_reader(io::Cursor::new("streaming"), None));
+            assert_eq!(old.into_string().await?, "Hello, Nori!");
+            assert!(req.header(CONTENT_LENGTH).is_none());
+
+            let old = req.take_body();
+            assert_eq!(old.into_string().await?, "streaming");
+            assert_eq!(req[CONTENT_LENGTH], "0");
+            Ok(())
+        }
+
+        #[async_std::test]
+        async fn take_body_twice() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_body("Hello, Nori!");
+
+            let body = req.take_body();
+            assert_eq!(body.into_string().await?, "Hello, Nori!");
+
+            let body = req.take_body();
+            assert_eq!(body.len(), Some(0));
+            assert_eq!(body.into_string().await?, "");
+            assert_eq!(req[CONTENT_LENGTH], "0");
+            Ok(())
+        }
+
+        #[async_std::test]
+        async fn try_clone() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_version(Some(Version::Http1_1));
+            req.insert_header("X-Nori", "meow")?;
+            req.set_body("Hello, Nori!");
+
+            let mut clone = req.try_clone().unwrap();
+            assert_eq!(clone.method(), req.method());
+            assert_eq!(clone.url(), req.url());
+            assert_eq!(clone.version(), Some(Version::Http1_1));
+            assert_eq!(clone["X-Nori"], "meow");
+            assert_eq!(clone[CONTENT_LENGTH], "12");
+            assert_eq!(clone.body_string().await?, "Hello, Nori!");
+            assert_eq!(req.body_string().await?, "Hello, Nori!");
+
+            req.set_body(Body::from_reader(io::Cursor::new("streaming"), None));
+            assert!(req.try_clone().is_none());
+            Ok(())
+        }
+
+        #[test]
+        fn set_content_type() {
+            let mut req = build_test_request();
+            assert_eq!(req.content_type(), None);
+
+            req.set_body("{}");
+            assert_eq!(req.content_type(), Some(crate::mime::PLAIN));
+
+            req.set_content_type("application/json");
+            assert_eq!(req[CONTENT_TYPE], "application/json");
+            assert_eq!(req.content_type(), Some(crate::mime::JSON));
+            assert_eq!(req.take_body().mime(), Some(&crate::mime::JSON));
+        }
+
+        #[test]
+        fn content_length() -> crate::Result<()> {
+            let mut req = build_test_request();
+            req.set_body(Body::from_reader(io::Cursor::new("streaming"), None));
+            assert_eq!(req.content_length(), None);
+
+            req.insert_header(CONTENT_LENGTH, "9")?;
+            assert_eq!(req.content_length(), Some(9));
+
+            req.insert_header(CONTENT_LENGTH, "nine")?;
+            assert_eq!(req.content_length(), None);
+
+            req.set_body("Hello, Nori!");
+            req.remove_header(CONTENT_LENGTH);
+            assert_eq!(req.content_length(), Some(12));
+            Ok(())
+        }
+    }
+
+    mod ext {
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        struct UserId(u64);
+
+        #[derive(Debug, PartialEq)]
+        struct SpanName(&'static str);
+
+        #[test]
+        fn insert_two_types() {
+            let mut req = build_test_request();
+            assert_eq!(req.insert_ext(UserId(7)), None);
+            assert_eq!(req.insert_ext(SpanName("login")), None);
+
+            assert_eq!(req.ext().get(), Some(&UserId(7)));
+            assert_eq!(req.ext().get(), Some(&SpanName("login")));
+
+            assert_eq!(req.insert_ext(UserId(8)), Some(UserId(7)));
+            assert_eq!(req.ext().get(), Some(&UserId(8)));
+        }
+    }
+
+    mod query {
+        use super::*;
+
+        #[test]
+        fn repeated_keys() {
+            let req = Request::get("http://async.rs/?a=1&b=2&a=3");
+            let pairs: Vec<_> = req
+                .query_pairs()
+                .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                .collect();
+            assert_eq!(
+                pairs,
+                vec![
+                    ("a".to_owned(), "1".to_owned()),
+                    ("b".to_owned(), "2".to_owned()),
+                    ("a".to_owned(), "3".to_owned()),
+                ]
+            );
+            assert_eq!(req.query_first("a").as_deref(), Some("1"));
+        }
+
+        #[test]
+        fn percent_decoding() {
+            let req = Request::get("http://async.rs/?full%20name=Nori%20the%20cat&q=a%26b");
+            assert_eq!(
+                req.query_first("full name").as_deref(),
+                Some("Nori the cat")
+            );
+            assert_eq!(req.query_first("q").as_deref(), Some("a&b"));
+        }
+
+        #[test]
+        fn no_query() {
+            let req = build_test_request();
+            assert_eq!(req.query_pairs().count(), 0);
+            assert_eq!(req.query_first("a"), None);
+        }
+    }
+
+    mod client_ip {
+        use super::*;
+
+        fn trusted() -> Vec<IpNet> {
+            vec![
+                "10.0.0.0/8".parse().unwrap(),
+                "2001:db8::/32".parse().unwrap(),
+            ]
+        }
+
+        #[test]
+        fn without_proxies() {
+            let mut request = build_test_request();
+            assert_eq!(request.client_ip(&trusted()), None);
+
+            request.set_peer_addr(Some("203.0.113.7:4000"));
+            set_x_forwarded_for(&mut request, "192.0.2.1");
+            assert_eq!(request.client_ip(&trusted()), "203.0.113.7".parse().ok());
+        }
+
+        #[test]
+        fn trusted_proxy_chain() {
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("[2001:db8::2]:443"));
+            request
+                .insert_header("Forwarded", r#"for=192.0.2.60, for="[2001:db8::1]:80""#)
+                .unwrap();
+            assert_eq!(request.client_ip(&trusted()), "192.0.2.60".parse().ok());
+
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request
+                .insert_header("X-Forwarded-For", "192.0.2.60, 10.1.1.1")
+                .unwrap();
+            assert_eq!(request.client_ip(&trusted()), "192.0.2.60".parse().ok());
+        }
+
+        #[test]
+        fn spoofed_entry() {
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request
+                .insert_header("X-Forwarded-For", "10.9.9.9, 198.51.100.3, 10.1.1.1")
+                .unwrap();
+            assert_eq!(request.client_ip(&trusted()), "198.51.100.3".parse().ok());
+        }
+
+        #[test]
+        fn spoofed_forwarded_header() {
+            // The client sent its own `Forwarded` header through a proxy which
+            // only appends to `X-Forwarded-For`.
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request.insert_header("Forwarded", "for=1.2.3.4").unwrap();
+            set_x_forwarded_for(&mut request, "203.0.113.50");
+            assert_eq!(request.client_ip(&trusted()), None);
+
+            // Untrusted peers are still identified by their own address.
+            request.set_peer_addr(Some("203.0.113.9:4000"));
+            assert_eq!(request.client_ip(&trusted()), "203.0.113.9".parse().ok());
+        }
+
+        #[test]
+        fn multiple_header_lines() {
+            // The client sent its own line, and the trusted proxy appended
+            // another one rather than extending the client's.
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request.append_header("X-Forwarded-For", "6.6.6.6").unwrap();
+            request
+                .append_header("X-Forwarded-For", "203.0.113.7")
+                .unwrap();
+            assert_eq!(request.client_ip(&trusted()), "203.0.113.7".parse().ok());
+
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request.append_header("Forwarded", "for=6.6.6.6").unwrap();
+            request
+                .append_header("Forwarded", "for=203.0.113.7")
+                .unwrap();
+            assert_eq!(request.client_ip(&trusted()), "203.0.113.7".parse().ok());
+        }
+
+        #[test]
+        fn unknown_entry() {
+            let mut request = build_test_request();
+            request.set_peer_addr(Some("10.0.0.2:8080"));
+            request.insert_header("Forwarded", "for=unknown").unwrap();
+            assert_eq!(request.client_ip(&trusted()), None);
+        }
+    }
+
+    mod origin {
+        use super::*;
+
+        #[test]
+        fn origin() -> crate::Result<()> {
+            let mut req = Request::post("https://api.example.com");
+            assert_eq!(req.origin(), None);
+
+            req.insert_header("Origin", "https://example.com:8443")?;
+            assert_eq!(req.origin(), Some(Url::parse("https://example.com:8443")?));
+
+            req.insert_header("Origin", "null")?;
+            assert_eq!(req.origin(), None);
+
+            req.insert_header("Origin", "https://example.com/path")?;
+            assert_eq!(req.origin(), None);
+            Ok(())
+        }
+    }
+
+    mod method_override {
+        use super::*;
+
+        #[test]
+        fn put() -> crate::Result<()> {
+            let mut req = Request::post("https://example.com");
+            assert_eq!(req.method_override(), None);
+
+            req.insert_header("X-HTTP-Method-Override", "put")?;
+            assert_eq!(req.method_override(), Some(Method::Put));
+            assert_eq!(req.method(), Method::Post);
+            Ok(())
+        }
+
+        #[test]
+        fn rejected() -> crate::Result<()> {
+            for value in &["not a method", "", "TRACE", "connect", "PRI"] {
+                let mut req = Request::post("https://example.com");
+                req.insert_header("X-HTTP-Method-Override", *value)?;
+                assert_eq!(req.method_override(), None, "{}", value);
+            }
+            Ok(())
+        }
+    }
+
+    mod debug_dump {
+        use super::*;
+
+        #[test]
+        fn headers_and_body() -> crate::Result<()> {
+            let mut req = Request::get("https://example.com/a?b=c");
+            req.set_version(Some(Version::Http2_0));
+            req.insert_header("Accept", "text/plain")?;
+            req.append_header("Accept", "text/html")?;
+            req.insert_header("Host", "example.com")?;
+            req.set_body(Body::from_bytes(vec![0, 1, 0xff]));
+
+            let dump = req.debug_dump();
+            let lines: Vec<_> = dump.split("\r\n").collect();
+            assert_eq!(lines[0], "GET https://example.com/a?b=c HTTP/2");
+            assert_eq!(
+                &lines[1..6],
+                [
+                    "accept: text/plain",
+                    "accept: text/html",
+                    "content-length: 3",
+                    "content-type: application/octet-stream",
+                    "host: example.com",
+                ]
+            );
+            assert_eq!(lines[6..], ["", "00 01 ff"]);
+            Ok(())
+        }
+
+        #[test]
+        fn streaming_body_is_not_read() {
+            let mut req = Request::get("https://example.com");
+            req.set_body(Body::from_reader(io::Cursor::new("hello"), None));
+            assert!(req.debug_dump().ends_with("\r\n\r\n<stream>"));
+
+            req.set_body("x".repeat(300));
+            assert!(req.debug_dump().ends_with("xxx... (44 more bytes)"));
+        }
+    }
+
+    mod reconstruct_url {
+        use super::*;
+
+        #[test]
+        fn origin_form() -> crate::Result<()> {
+            let url = Request::reconstruct_url("http", "/a/b?x=1&y=2", "example.com:8080")?;
+            assert_eq!(url.as_str(), "http://example.com:8080/a/b?x=1&y=2");
+
+            let url = Request::reconstruct_url("https", "/", "[::1]")?;
+            assert_eq!(url.as_str(), "https://[::1]/");
+            Ok(())
+        }
+
+        #[test]
+        fn absolute_form() -> crate::Result<()> {
+            let url = Request::reconstruct_url("http", "https://example.com/a?x=1", "proxy.com")?;
+            assert_eq!(url.as_str(), "https://example.com/a?x=1");
+            Ok(())
+        }
+
+        #[test]
+        fn asterisk_form() -> crate::Result<()> {
+            let url = Request::reconstruct_url("http", "*", "example.com")?;
+            assert_eq!(url.as_str(), "http://example.com/");
+            Ok(())
+        }
+
+        #[test]
+        fn bad_request() {
+            for (target, host) in &[
+                ("/a", "bad host"),
+                ("/a", ""),
+                ("example.com:443", "example.com"),
+                ("mailto:nori@example.com", "example.com"),
+                ("a/b", "example.com"),
+            ] {
+                let err = Request::reconstruct_url("http", target, host).unwrap_err();
+                assert_eq!(err.status(), 400, "{} {}", target, host);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod query_deserialize {
+        use super::*;
+        use crate::convert::{Deserialize, Serialize};
+
+        #[derive(Deserialize, Serialize, Debug, PartialEq)]
+        #[serde(crate = "serde_crate")]
+        struct Filter {
+            limit: Option<u32>,
+            q: Option<String>,
+        }
+
+        #[test]
+        fn missing_query_is_empty() -> crate::Result<()> {
+            let req = build_test_request();
+            let filter: Filter = req.query()?;
+            assert_eq!(
+                filter,
+                Filter {
+                    limit: None,
+                    q: None
+                }
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn set_query_replaces_and_omits_none() -> crate::Result<()> {
+            let mut req = Request::get("http://async.rs/?page=2&q=dogs");
+            req.set_query(&Filter {
+                limit: Some(10),
+                q: None,
+            })?;
+            assert_eq!(req.url().query(), Some("limit=10"));
+
+            req.set_query(&Filter {
+                limit: None,
+                q: None,
+            })?;
+            assert_eq!(req.url().as_str(), "http://async.rs/");
+            Ok(())
+        }
+
+        #[test]
+        fn bad_request_on_invalid_query() {
+            let req = Request::get("http://async.rs/?limit=ten");
+            let err = req.query::<Filter>().unwrap_err();
+            assert_eq!(err.status(), crate::StatusCode::BadRequest);
+        }
+    }
+
     fn build_test_request() -> Request {
         let url = Url::parse("http://async.rs/").unwrap();
         Request::new(Method::Get, url)
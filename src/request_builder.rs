@@ -0,0 +1,163 @@
+use std::convert::TryInto;
+
+use crate::headers::{HeaderName, ToHeaderValues};
+use crate::mime::Mime;
+use crate::{Body, Method, Request, Url};
+
+#[cfg(feature = "serde")]
+use crate::convert::Serialize;
+
+/// A builder for constructing a `Request` in a single expression.
+///
+/// Errors from setting headers or serializing the querystring are deferred
+/// until [`RequestBuilder::build`] is called.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::convert::json;
+/// use http_types::{mime, Body, Method, Request};
+///
+/// let req = Request::builder(Method::Post, "https://example.com/cats")
+///     .header("X-Request-Id", "42")
+///     .body(Body::from_json(&json!({ "name": "Chashu" }))?)
+///     .build()?;
+///
+/// assert_eq!(req.method(), Method::Post);
+/// assert_eq!(req["X-Request-Id"], "42");
+/// assert_eq!(req.content_type(), Some(mime::JSON));
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "builders do nothing unless `build` is called"]
+pub struct RequestBuilder {
+    req: Request,
+    body: Option<Body>,
+    content_type: Option<Mime>,
+    error: Option<crate::Error>,
+}
+
+impl RequestBuilder {
+    /// Create a new instance of `RequestBuilder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the url can't be converted into a `Url`, just like
+    /// [`Request::new`].
+    pub fn new<U>(method: Method, url: U) -> Self
+    where
+        U: TryInto<Url>,
+        U::Error: std::fmt::Debug,
+    {
+        Self {
+            req: Request::new(method, url),
+            body: None,
+            content_type: None,
+            error: None,
+        }
+    }
+
+    /// Append a header.
+    pub fn header(mut self, name: impl Into<HeaderName>, values: impl ToHeaderValues) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = self.req.append_header(name, values) {
+                self.error = Some(err);
+            }
+        }
+        self
+    }
+
+    /// Set the body.
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the URL querystring, replacing any existing querystring.
+    #[cfg(feature = "serde")]
+    pub fn query(mut self, query: &impl Serialize) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = self.req.set_query(query) {
+                self.error = Some(err);
+            }
+        }
+        self
+    }
+
+    /// Set the `Content-Type` header.
+    ///
+    /// This overrides the mime type the body would otherwise set.
+    pub fn content_type(mut self, mime: impl Into<Mime>) -> Self {
+        self.content_type = Some(mime.into());
+        self
+    }
+
+    /// Construct the `Request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while setting headers or the
+    /// querystring.
+    pub fn build(self) -> crate::Result<Request> {
+        let Self {
+            mut req,
+            body,
+            content_type,
+            error,
+        } = self;
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        if let Some(body) = body {
+            req.set_body(body);
+        }
+        if let Some(mime) = content_type {
+            req.set_content_type(mime);
+        }
+        Ok(req)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mime;
+
+    #[async_std::test]
+    async fn content_type_overrides_body() -> crate::Result<()> {
+        let mut req = Request::builder(Method::Put, "https://example.com/notes/1")
+            .content_type(mime::PLAIN)
+            .body(Body::from_bytes(b"plain text".to_vec()))
+            .build()?;
+        assert_eq!(req.content_type(), Some(mime::PLAIN));
+        assert_eq!(req.len(), Some(10));
+        assert_eq!(req.body_string().await?, "plain text");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn query() -> crate::Result<()> {
+        let mut query = std::collections::HashMap::new();
+        query.insert("page", 2);
+        let req = Request::builder(Method::Get, "https://example.com/notes?page=1")
+            .query(&query)
+            .build()?;
+        assert_eq!(req.url().query(), Some("page=2"));
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_errors() {
+        let err = Request::builder(Method::Get, "https://example.com")
+            .header("X-Bad", "caf\u{e9}")
+            .header("X-Good", "ok")
+            .build()
+            .unwrap_err();
+        assert_eq!(err.status(), 500);
+    }
+}
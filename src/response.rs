@@ -7,16 +7,17 @@ use std::ops::Index;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::content::{ContentLength, Link};
 #[cfg(feature = "serde")]
 use crate::convert::DeserializeOwned;
 use crate::headers::{
-    self, HeaderName, HeaderValue, HeaderValues, Headers, Names, ToHeaderValues, Values,
-    CONTENT_TYPE,
+    self, Header, HeaderName, HeaderValue, HeaderValues, Headers, Names, ToHeaderValues, Values,
+    CONTENT_LENGTH, CONTENT_TYPE, LINK, LOCATION,
 };
 use crate::mime::Mime;
 use crate::trailers::{self, Trailers};
 use crate::upgrade;
-use crate::{Body, Extensions, StatusCode, Version};
+use crate::{Body, Extensions, ResponseBuilder, StatusCode, Version};
 
 pin_project_lite::pin_project! {
     /// An HTTP response.
@@ -81,6 +82,142 @@ impl Response {
         }
     }
 
+    /// Create a builder for a response, with status `200: OK`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let res = Response::builder()
+    ///     .status(StatusCode::Created)
+    ///     .header("Location", "/cats/nori")
+    ///     .build()?;
+    /// assert_eq!(res.status(), StatusCode::Created);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn builder() -> ResponseBuilder {
+        ResponseBuilder::new()
+    }
+
+    /// Create a `100 Continue` interim response.
+    ///
+    /// Servers send this in reply to a request with an `Expect: 100-continue`
+    /// header, to signal the client may go on to send the request body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let res = Response::continue_response();
+    /// assert_eq!(res.status(), StatusCode::Continue);
+    /// assert_eq!(res.len(), Some(0));
+    /// ```
+    pub fn continue_response() -> Self {
+        Self::interim(StatusCode::Continue)
+    }
+
+    /// Create a `103 Early Hints` interim response.
+    ///
+    /// Servers send this before the final response, so clients can start
+    /// preloading the linked resources while the final response is prepared.
+    /// The response carries only the given `Link` headers, and no body.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 8297: An HTTP Status Code for Indicating Hints](https://tools.ietf.org/html/rfc8297)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::content::{Link, LinkValue};
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut link = Link::new();
+    /// link.push(LinkValue::new("/style.css")?.with_rel("preload")?);
+    ///
+    /// let res = Response::early_hints(vec![link]);
+    /// assert_eq!(res.status(), StatusCode::EarlyHints);
+    /// assert_eq!(res["Link"], r#"</style.css>; rel="preload""#);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn early_hints(links: Vec<Link>) -> Self {
+        let mut res = Self::interim(StatusCode::EarlyHints);
+        for link in links.iter().filter(|link| link.iter().next().is_some()) {
+            res.headers.append(LINK, link.header_value()).unwrap();
+        }
+        res
+    }
+
+    /// Create an interim response, which has no body.
+    fn interim(status: StatusCode) -> Self {
+        assert!(
+            status.is_informational(),
+            "{} is not an informational status",
+            status
+        );
+        Self::new(status)
+    }
+
+    /// Create a new redirect response.
+    ///
+    /// This sets the `Location` header to the given target and leaves the body
+    /// empty.
+    ///
+    /// # Errors
+    ///
+    /// This function will error if the status code is not in the `3xx` range,
+    /// or if the location is not valid ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let res = Response::redirect(StatusCode::Found, "/login")?;
+    /// assert_eq!(res.status(), StatusCode::Found);
+    /// assert_eq!(res["Location"], "/login");
+    ///
+    /// assert!(Response::redirect(StatusCode::Ok, "/login").is_err());
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn redirect(status: StatusCode, location: impl AsRef<str>) -> crate::Result<Self> {
+        crate::ensure!(
+            status.is_redirection(),
+            "Expected a 3xx status code, got {}",
+            status
+        );
+        let mut res = Self::new(status);
+        res.insert_header(LOCATION, location.as_ref())?;
+        Ok(res)
+    }
+
+    /// Create a new `307 Temporary Redirect` response.
+    ///
+    /// Clients will repeat the request to the new location using the same
+    /// method and body.
+    pub fn redirect_temporary(location: impl AsRef<str>) -> crate::Result<Self> {
+        Self::redirect(StatusCode::TemporaryRedirect, location)
+    }
+
+    /// Create a new `308 Permanent Redirect` response.
+    ///
+    /// Clients will repeat the request to the new location using the same
+    /// method and body.
+    pub fn redirect_permanent(location: impl AsRef<str>) -> crate::Result<Self> {
+        Self::redirect(StatusCode::PermanentRedirect, location)
+    }
+
     /// Get the status
     pub fn status(&self) -> StatusCode {
         self.status
@@ -103,6 +240,9 @@ impl Response {
 
     /// Set an HTTP header.
     ///
+    /// This replaces all existing values for the header, which are returned if
+    /// there were any.
+    ///
     /// # Examples
     ///
     /// ```
@@ -111,7 +251,10 @@ impl Response {
     /// use http_types::{Method, Response, StatusCode, Url};
     ///
     /// let mut req = Response::new(StatusCode::Ok);
-    /// req.insert_header("Content-Type", "text/plain");
+    /// assert!(req.insert_header("Content-Type", "text/plain")?.is_none());
+    ///
+    /// let previous = req.insert_header("Content-Type", "text/html")?.unwrap();
+    /// assert_eq!(previous, "text/plain");
     /// #
     /// # Ok(()) }
     /// ```
@@ -169,6 +312,9 @@ impl Response {
 
     /// Replace the response body with a new body, returning the old body.
     ///
+    /// The `Content-Length` header is updated to match the new body, or
+    /// removed if its length is unknown.
+    ///
     /// # Examples
     ///
     /// ```
@@ -191,6 +337,7 @@ impl Response {
     pub fn replace_body(&mut self, body: impl Into<Body>) -> Body {
         let body = mem::replace(&mut self.body, body.into());
         self.copy_content_type_from_body();
+        self.copy_content_length_from_body();
         body
     }
 
@@ -220,10 +367,13 @@ impl Response {
     pub fn swap_body(&mut self, body: &mut Body) {
         mem::swap(&mut self.body, body);
         self.copy_content_type_from_body();
+        self.copy_content_length_from_body();
     }
 
     /// Take the response body, replacing it with an empty body.
     ///
+    /// The `Content-Length` header is updated to `0` to match.
+    ///
     /// # Examples
     ///
     /// ```
@@ -250,6 +400,41 @@ impl Response {
         self.replace_body(Body::empty())
     }
 
+    /// Convert the response into a response to a `HEAD` request.
+    ///
+    /// The body is replaced with an empty body, but the `Content-Length`
+    /// header keeps reporting the length of the original body, as the headers
+    /// of a `HEAD` response must match those of the equivalent `GET`
+    /// response. If the length isn't known, the header is removed.
+    ///
+    /// # Specifications
+    ///
+    /// - [RFC 7231, section 4.3.2: HEAD](https://tools.ietf.org/html/rfc7231#section-4.3.2)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Response;
+    ///
+    /// let mut res = Response::new(200);
+    /// res.set_body("Hello, Nori!");
+    ///
+    /// let res = res.into_head();
+    /// assert_eq!(res.len(), Some(0));
+    /// assert_eq!(res["Content-Length"], "12");
+    /// ```
+    pub fn into_head(mut self) -> Self {
+        let length = self.content_length();
+        self.take_body();
+        match length {
+            Some(length) => ContentLength::new(length).apply_header(&mut self),
+            None => {
+                self.remove_header(CONTENT_LENGTH);
+            }
+        }
+        self
+    }
+
     /// Read the body as a string.
     ///
     /// This consumes the response. If you want to read the body without
@@ -376,8 +561,25 @@ impl Response {
         body.into_form().await
     }
 
-    /// Set the response MIME.
-    pub fn set_content_type(&mut self, mime: Mime) -> Option<HeaderValues> {
+    /// Set the `Content-Type` header.
+    ///
+    /// The mime type of the body is updated to match, so it's preserved when
+    /// the body is taken.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{mime, Response};
+    ///
+    /// let mut res = Response::new(200);
+    /// res.set_body("{\"name\": \"Nori\"}");
+    /// res.set_content_type(mime::JSON);
+    /// assert_eq!(res.content_type(), Some(mime::JSON));
+    /// assert_eq!(res.take_body().mime(), Some(&mime::JSON));
+    /// ```
+    pub fn set_content_type(&mut self, mime: impl Into<Mime>) -> Option<HeaderValues> {
+        let mime = mime.into();
+        self.body.set_mime(Some(mime.clone()));
         let value: HeaderValue = mime.into();
 
         // A Mime instance is guaranteed to be valid header name.
@@ -393,7 +595,22 @@ impl Response {
         }
     }
 
-    /// Get the current content type
+    /// Keep the `Content-Length` header in sync with the body. If the length
+    /// of the body is unknown the header is removed.
+    fn copy_content_length_from_body(&mut self) {
+        match self.body.len() {
+            Some(len) => {
+                self.insert_header(CONTENT_LENGTH, len.to_string()).unwrap();
+            }
+            None => {
+                self.remove_header(CONTENT_LENGTH);
+            }
+        }
+    }
+
+    /// Get the current content type, parsed from the `Content-Type` header.
+    ///
+    /// Returns `None` if the header is missing or can't be parsed.
     pub fn content_type(&self) -> Option<Mime> {
         self.header(CONTENT_TYPE)?.last().as_str().parse().ok()
     }
@@ -408,6 +625,29 @@ impl Response {
         self.body.len()
     }
 
+    /// Get the declared length of the body, in bytes.
+    ///
+    /// This is read from the `Content-Length` header, falling back to the
+    /// length of the body if the header is absent. Returns `None` if neither
+    /// is known, or if the header is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::Response;
+    ///
+    /// let mut res = Response::new(200);
+    /// res.set_body("Hello, Nori!");
+    /// assert_eq!(res.content_length(), Some(12));
+    /// ```
+    pub fn content_length(&self) -> Option<u64> {
+        match ContentLength::from_headers(self) {
+            Ok(Some(length)) => Some(length.len()),
+            Ok(None) => self.body.len(),
+            Err(_) => None,
+        }
+    }
+
     /// Returns `true` if the set length of the body stream is zero, `false`
     /// otherwise.
     pub fn is_empty(&self) -> Option<bool> {
@@ -484,6 +724,40 @@ impl Response {
     }
 
     /// Sends trailers to the a receiver.
+    ///
+    /// Trailers should only be sent after the body has been fully written,
+    /// since receivers commonly wait for the body to be consumed before
+    /// awaiting the trailers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use http_types::trailers::Trailers;
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::Ok);
+    /// res.set_body("payload");
+    ///
+    /// let sender = res.send_trailers();
+    /// let receiver = res.recv_trailers();
+    ///
+    /// let body = res.take_body().into_string().await?;
+    /// let mut trailers = Trailers::new();
+    /// trailers.insert("Grpc-Status", "0")?;
+    /// sender.send(trailers).await;
+    ///
+    /// let trailers = receiver.await.unwrap();
+    /// assert_eq!(body, "payload");
+    /// assert_eq!(trailers["Grpc-Status"], "0");
+    /// #
+    /// # Ok(()) }) }
+    /// ```
     pub fn send_trailers(&mut self) -> trailers::Sender {
         self.has_trailers = true;
         let sender = self
@@ -494,6 +768,13 @@ impl Response {
     }
 
     /// Receive trailers from a sender.
+    ///
+    /// The returned future resolves once the trailers have been sent, or to
+    /// `None` if the sender was dropped without sending any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
     pub fn recv_trailers(&mut self) -> trailers::Receiver {
         let receiver = self
             .trailers_receiver
@@ -508,6 +789,41 @@ impl Response {
     }
 
     /// Sends an upgrade connection to the a receiver.
+    ///
+    /// The server calls this once it has written the `101 Switching Protocols`
+    /// response, and sends the underlying transport through the returned
+    /// `Sender`. From then on the server no longer reads from or writes to
+    /// the transport; driving the upgraded protocol is up to the holder of
+    /// the `Receiver`. Dropping the `Sender` without sending a connection
+    /// signals that the upgrade failed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// #
+    /// use futures_lite::io::{AsyncReadExt, Cursor};
+    /// use http_types::upgrade::Connection;
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::SwitchingProtocols);
+    /// let receiver = res.recv_upgrade().await;
+    ///
+    /// // The server hands over the transport after writing the response.
+    /// let sender = res.send_upgrade();
+    /// sender.send(Connection::new(Cursor::new(b"hello".to_vec()))).await;
+    ///
+    /// let mut conn = receiver.await.unwrap();
+    /// let mut buf = String::new();
+    /// conn.read_to_string(&mut buf).await?;
+    /// assert_eq!(buf, "hello");
+    /// #
+    /// # Ok(()) }) }
+    /// ```
     #[cfg_attr(feature = "docs", doc(cfg(unstable)))]
     pub fn send_upgrade(&mut self) -> upgrade::Sender {
         self.has_upgrade = true;
@@ -519,6 +835,14 @@ impl Response {
     }
 
     /// Receive an upgraded connection from a sender.
+    ///
+    /// The returned `Receiver` resolves once the server has completed the
+    /// handshake and sent the connection, or to `None` if the sender was
+    /// dropped without sending one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called more than once.
     #[cfg_attr(feature = "docs", doc(cfg(unstable)))]
     pub async fn recv_upgrade(&mut self) -> upgrade::Receiver {
         self.has_upgrade = true;
@@ -535,6 +859,35 @@ impl Response {
         self.has_upgrade
     }
 
+    /// Render the response as text resembling its wire format, for debugging.
+    ///
+    /// Headers are sorted by name. The body is never read: a preview of it is
+    /// only shown if it's held in memory, truncated, and in hex unless it's
+    /// text. Otherwise `<stream>` is shown in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::NotFound);
+    /// res.insert_header("X-Cat", "Nori");
+    ///
+    /// let dump = res.debug_dump();
+    /// assert!(dump.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    /// assert!(dump.contains("x-cat: Nori\r\n"));
+    /// ```
+    pub fn debug_dump(&self) -> String {
+        let version = self.version.unwrap_or(Version::Http1_1);
+        let start_line = format!(
+            "{} {} {}",
+            version,
+            self.status as u16,
+            self.status.canonical_reason()
+        );
+        crate::utils::debug_dump(&start_line, &self.headers, &self.body)
+    }
+
     /// An iterator visiting all header pairs in arbitrary order.
     pub fn iter(&self) -> headers::Iter<'_> {
         self.headers.iter()
@@ -580,6 +933,56 @@ impl Response {
     pub fn ext_mut(&mut self) -> &mut Extensions {
         &mut self.ext
     }
+
+    /// Insert a value into the local state.
+    ///
+    /// If a value of this type already exists, it will be replaced and
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::Ok);
+    /// assert_eq!(res.insert_ext(1u8), None);
+    /// assert_eq!(res.insert_ext(2u8), Some(1u8));
+    /// assert_eq!(res.ext().get::<u8>(), Some(&2));
+    /// ```
+    pub fn insert_ext<T: Send + Sync + 'static>(&mut self, val: T) -> Option<T> {
+        self.ext.insert(val)
+    }
+
+    /// Attempt to clone the response, including its body.
+    ///
+    /// The headers, status and version are duplicated. This only succeeds if
+    /// the body is held in memory and hasn't been read from yet; `None` is
+    /// returned for streaming bodies. See [`Body::try_clone`] for details.
+    ///
+    /// Like [`Clone::clone`], extensions are not carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+    /// use http_types::{Response, StatusCode};
+    ///
+    /// let mut res = Response::new(StatusCode::Ok);
+    /// res.insert_header("X-Nori", "meow")?;
+    /// res.set_body("Hello, Nori!");
+    ///
+    /// let mut clone = res.try_clone().unwrap();
+    /// assert_eq!(clone.status(), StatusCode::Ok);
+    /// assert_eq!(clone["X-Nori"], "meow");
+    /// assert_eq!(clone.body_string().await?, "Hello, Nori!");
+    /// # Ok(()) }) }
+    /// ```
+    pub fn try_clone(&self) -> Option<Self> {
+        let body = self.body.try_clone()?;
+        let mut res = self.clone();
+        res.body = body;
+        Some(res)
+    }
 }
 
 impl Clone for Response {
@@ -724,6 +1127,32 @@ impl<'a> IntoIterator for &'a mut Response {
 #[cfg(test)]
 mod test {
     use super::Response;
+    use crate::content::{Link, LinkValue};
+    use crate::headers::{CONTENT_LENGTH, CONTENT_TYPE, LINK, LOCATION};
+    use crate::trailers::Trailers;
+    use crate::{mime, Body, StatusCode};
+
+    #[test]
+    fn early_hints() -> crate::Result<()> {
+        let mut styles = Link::new();
+        styles.push(LinkValue::new("/style.css")?.with_rel("preload")?);
+        let mut scripts = Link::new();
+        scripts.push(LinkValue::new("/app.js")?.with_rel("preload")?);
+        scripts.push(LinkValue::new("https://cdn.example.com")?.with_rel("preconnect")?);
+
+        let res = Response::early_hints(vec![styles, Link::new(), scripts]);
+        assert_eq!(res.status(), StatusCode::EarlyHints);
+        assert_eq!(res.len(), Some(0));
+        assert_eq!(res.iter().count(), 1);
+        assert_eq!(
+            res[LINK],
+            [
+                r#"</style.css>; rel="preload""#,
+                r#"</app.js>; rel="preload", <https://cdn.example.com>; rel="preconnect""#,
+            ][..]
+        );
+        Ok(())
+    }
 
     #[test]
     fn construct_shorthand_with_valid_status_code() {
@@ -735,4 +1164,212 @@ mod test {
     fn construct_shorthand_with_invalid_status_code() {
         let _res = Response::new(600);
     }
+
+    #[async_std::test]
+    async fn replace_body_updates_content_length() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.set_body("Hello, Nori!");
+        assert_eq!(res[CONTENT_LENGTH], "12");
+
+        let old = res.replace_body("Hello, Chashu!");
+        assert_eq!(old.into_string().await?, "Hello, Nori!");
+        assert_eq!(res[CONTENT_LENGTH], "14");
+
+        let reader = async_std::io::Cursor::new("streaming");
+        let old = res.replace_body(Body::from_reader(reader, None));
+        assert_eq!(old.into_string().await?, "Hello, Chashu!");
+        assert!(res.header(CONTENT_LENGTH).is_none());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn take_body_twice() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.set_body("Hello, Nori!");
+
+        let body = res.take_body();
+        assert_eq!(body.into_string().await?, "Hello, Nori!");
+
+        let body = res.take_body();
+        assert_eq!(body.len(), Some(0));
+        assert_eq!(body.into_string().await?, "");
+        assert_eq!(res[CONTENT_LENGTH], "0");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn trailers_after_streamed_body() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        let reader = async_std::io::Cursor::new("streamed payload");
+        res.set_body(Body::from_reader(reader, None));
+
+        let sender = res.send_trailers();
+        let receiver = res.recv_trailers();
+        assert!(res.has_trailers());
+
+        let body = res.take_body().into_string().await?;
+        assert_eq!(body, "streamed payload");
+
+        let mut trailers = Trailers::new();
+        trailers.insert("Grpc-Status", "0")?;
+        sender.send(trailers).await;
+
+        let trailers = receiver.await.unwrap();
+        assert_eq!(trailers["Grpc-Status"], "0");
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn upgrade_connection() -> crate::Result<()> {
+        use crate::upgrade::Connection;
+        use futures_lite::io::{AsyncReadExt, AsyncWriteExt, Cursor};
+
+        let mut res = Response::new(StatusCode::SwitchingProtocols);
+        assert!(!res.has_upgrade());
+        let receiver = res.recv_upgrade().await;
+        let sender = res.send_upgrade();
+        assert!(res.has_upgrade());
+
+        async_std::task::spawn(async move {
+            let conn = Connection::new(Cursor::new(b"ping".to_vec()));
+            sender.send(conn).await;
+        });
+
+        let mut conn = receiver.await.expect("a connection was sent");
+        let mut buf = [0; 4];
+        conn.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"ping");
+        conn.write_all(b"pong").await?;
+        conn.close().await?;
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn upgrade_sender_dropped() {
+        let mut res = Response::new(StatusCode::SwitchingProtocols);
+        let receiver = res.recv_upgrade().await;
+        drop(res.send_upgrade());
+        assert!(receiver.await.is_none());
+    }
+
+    #[test]
+    fn redirect() -> crate::Result<()> {
+        let res = Response::redirect(StatusCode::Found, "https://example.com/login")?;
+        assert_eq!(res.status(), StatusCode::Found);
+        assert_eq!(res[LOCATION], "https://example.com/login");
+        assert_eq!(res.len(), Some(0));
+
+        let res = Response::redirect_temporary("/a")?;
+        assert_eq!(res.status(), StatusCode::TemporaryRedirect);
+        let res = Response::redirect_permanent("/b")?;
+        assert_eq!(res.status(), StatusCode::PermanentRedirect);
+        assert_eq!(res[LOCATION], "/b");
+
+        assert!(Response::redirect(StatusCode::Ok, "/").is_err());
+        assert!(Response::redirect(StatusCode::NotFound, "/").is_err());
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn try_clone() -> crate::Result<()> {
+        let mut res = Response::new(StatusCode::Created);
+        res.insert_header("X-Nori", "meow")?;
+        res.set_body("Hello, Nori!");
+
+        let mut clone = res.try_clone().unwrap();
+        assert_eq!(clone.status(), StatusCode::Created);
+        assert_eq!(clone["X-Nori"], "meow");
+        assert_eq!(clone[CONTENT_LENGTH], "12");
+        assert_eq!(clone.body_string().await?, "Hello, Nori!");
+        assert_eq!(res.body_string().await?, "Hello, Nori!");
+
+        let reader = async_std::io::Cursor::new("streaming");
+        res.set_body(Body::from_reader(reader, None));
+        assert!(res.try_clone().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn insert_header_returns_previous_values() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        assert!(res.insert_header("X-Nori", "meow")?.is_none());
+
+        let previous = res.insert_header("X-Nori", "purr")?.unwrap();
+        assert_eq!(previous.iter().collect::<Vec<_>>(), vec!["meow"]);
+
+        res.append_header("X-Nori", "hiss")?;
+        let previous = res.insert_header("X-Nori", "chirp")?.unwrap();
+        assert_eq!(previous.iter().collect::<Vec<_>>(), vec!["purr", "hiss"]);
+        assert_eq!(res["X-Nori"], "chirp");
+        Ok(())
+    }
+
+    #[test]
+    fn set_content_type() {
+        let mut res = Response::new(200);
+        assert_eq!(res.content_type(), None);
+
+        res.set_body(Body::from_bytes(b"{}".to_vec()));
+        res.set_content_type(mime::JSON);
+        assert_eq!(res[CONTENT_TYPE], "application/json");
+        assert_eq!(res.content_type(), Some(mime::JSON));
+        assert_eq!(res.take_body().mime(), Some(&mime::JSON));
+    }
+
+    #[test]
+    fn content_length() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.set_body(Body::from_reader(
+            async_std::io::Cursor::new("streaming"),
+            None,
+        ));
+        assert_eq!(res.content_length(), None);
+
+        res.insert_header(CONTENT_LENGTH, "9")?;
+        assert_eq!(res.content_length(), Some(9));
+
+        res.insert_header(CONTENT_LENGTH, "-1")?;
+        assert_eq!(res.content_length(), None);
+
+        res.set_body("Hello, Nori!");
+        res.remove_header(CONTENT_LENGTH);
+        assert_eq!(res.content_length(), Some(12));
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn into_head() -> crate::Result<()> {
+        let mut res = Response::new(200);
+        res.set_body(vec![7_u8; 100]);
+
+        let mut res = res.into_head();
+        assert_eq!(res[CONTENT_LENGTH], "100");
+        assert_eq!(res[CONTENT_TYPE], "application/octet-stream");
+        assert!(res.take_body().into_bytes().await?.is_empty());
+
+        let mut res = Response::new(200);
+        res.set_body(Body::from_reader(async_std::io::Cursor::new("Nori"), None));
+        let res = res.into_head();
+        assert!(res.header(CONTENT_LENGTH).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn insert_ext_two_types() {
+        #[derive(Debug, PartialEq)]
+        struct UserId(u64);
+
+        #[derive(Debug, PartialEq)]
+        struct SpanName(&'static str);
+
+        let mut res = Response::new(200);
+        assert_eq!(res.insert_ext(UserId(7)), None);
+        assert_eq!(res.insert_ext(SpanName("login")), None);
+
+        assert_eq!(res.ext().get(), Some(&UserId(7)));
+        assert_eq!(res.ext().get(), Some(&SpanName("login")));
+
+        assert_eq!(res.insert_ext(UserId(8)), Some(UserId(7)));
+        assert_eq!(res.ext().get(), Some(&UserId(8)));
+    }
 }
@@ -0,0 +1,162 @@
+use std::convert::TryInto;
+use std::fmt::Debug;
+
+use crate::headers::{HeaderName, ToHeaderValues};
+use crate::mime::Mime;
+use crate::{Body, Response, StatusCode};
+
+/// A builder for constructing a `Response` in a single expression.
+///
+/// The status defaults to `200: OK`. Setting a body with a known length also
+/// sets the `Content-Length` header. Errors from setting headers are deferred
+/// until [`ResponseBuilder::build`] is called.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::convert::json;
+/// use http_types::{mime, Body, Response, StatusCode};
+///
+/// let res = Response::builder()
+///     .status(StatusCode::NotFound)
+///     .body(Body::from_json(&json!({ "error": "No cat named Nori" }))?)
+///     .build()?;
+///
+/// assert_eq!(res.status(), StatusCode::NotFound);
+/// assert_eq!(res.content_type(), Some(mime::JSON));
+/// assert_eq!(res["Content-Length"], "29");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+#[must_use = "builders do nothing unless `build` is called"]
+pub struct ResponseBuilder {
+    res: Response,
+    body: Option<Body>,
+    content_type: Option<Mime>,
+    error: Option<crate::Error>,
+}
+
+impl ResponseBuilder {
+    /// Create a new instance of `ResponseBuilder` with status `200: OK`.
+    pub fn new() -> Self {
+        Self {
+            res: Response::new(StatusCode::Ok),
+            body: None,
+            content_type: None,
+            error: None,
+        }
+    }
+
+    /// Set the status.
+    pub fn status<S>(mut self, status: S) -> Self
+    where
+        S: TryInto<StatusCode>,
+        S::Error: Debug,
+    {
+        match status.try_into() {
+            Ok(status) => self.res.set_status(status),
+            Err(err) => {
+                let message = format!("Could not convert into a valid `StatusCode`: {:?}", err);
+                self.error.get_or_insert(crate::Error::from_str(
+                    StatusCode::InternalServerError,
+                    message,
+                ));
+            }
+        }
+        self
+    }
+
+    /// Append a header.
+    pub fn header(mut self, name: impl Into<HeaderName>, values: impl ToHeaderValues) -> Self {
+        if self.error.is_none() {
+            if let Err(err) = self.res.append_header(name, values) {
+                self.error = Some(err);
+            }
+        }
+        self
+    }
+
+    /// Set the body.
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the `Content-Type` header.
+    ///
+    /// This overrides the mime type the body would otherwise set.
+    pub fn content_type(mut self, mime: impl Into<Mime>) -> Self {
+        self.content_type = Some(mime.into());
+        self
+    }
+
+    /// Construct the `Response`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while setting the status or
+    /// headers.
+    pub fn build(self) -> crate::Result<Response> {
+        let Self {
+            mut res,
+            body,
+            content_type,
+            error,
+        } = self;
+        if let Some(err) = error {
+            return Err(err);
+        }
+
+        if let Some(body) = body {
+            res.set_body(body);
+        }
+        if let Some(mime) = content_type {
+            res.set_content_type(mime);
+        }
+        Ok(res)
+    }
+}
+
+impl Default for ResponseBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::CONTENT_LENGTH;
+    use crate::mime;
+
+    #[async_std::test]
+    async fn defaults() -> crate::Result<()> {
+        let mut res = Response::builder().body("Hello, Nori!").build()?;
+        assert_eq!(res.status(), StatusCode::Ok);
+        assert_eq!(res[CONTENT_LENGTH], "12");
+        assert_eq!(res.content_type(), Some(mime::PLAIN));
+        assert_eq!(res.body_string().await?, "Hello, Nori!");
+        Ok(())
+    }
+
+    #[test]
+    fn content_type_overrides_body() -> crate::Result<()> {
+        let res = Response::builder()
+            .content_type(mime::HTML)
+            .body("<p>Nori</p>")
+            .header("Cache-Control", "no-cache")
+            .build()?;
+        assert_eq!(res.content_type(), Some(mime::HTML));
+        assert_eq!(res["Cache-Control"], "no-cache");
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_errors() {
+        let err = Response::builder().status(999).build().unwrap_err();
+        assert_eq!(err.status(), 500);
+    }
+}
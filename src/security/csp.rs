@@ -161,8 +161,7 @@ impl ContentSecurityPolicy {
     fn insert_directive<T: AsRef<str>>(&mut self, directive: &str, source: T) {
         let directive = String::from(directive);
         let directives = self.directives.entry(directive).or_insert_with(Vec::new);
-        let source: String = source.as_ref().to_string();
-        directives.push(source);
+        directives.push(quote_source(source.as_ref()));
     }
 
     /// Defines the Content-Security-Policy `base-uri` directive
@@ -345,17 +344,20 @@ impl ContentSecurityPolicy {
     }
 
     /// Create and retrieve the policy value
-    fn value(&mut self) -> String {
+    fn value(&self) -> String {
+        let mut policy = self.policy.clone();
         for (directive, sources) in &self.directives {
-            let policy = format!("{} {}", directive, sources.join(" "));
-            self.policy.push(policy);
-            self.policy.sort();
+            policy.push(format!("{} {}", directive, sources.join(" ")));
         }
-        self.policy.join("; ")
+        policy.sort();
+        policy.join("; ")
     }
 
     /// Sets the `Content-Security-Policy` (CSP) HTTP header to prevent cross-site injections
-    pub fn apply(&mut self, mut headers: impl AsMut<Headers>) {
+    ///
+    /// Directives are emitted in alphabetical order, so applying the same
+    /// policy always produces the same header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
         let name = if self.report_only_flag {
             "Content-Security-Policy-Report-Only"
         } else {
@@ -364,3 +366,25 @@ impl ContentSecurityPolicy {
         headers.as_mut().insert(name, self.value()).unwrap();
     }
 }
+
+/// Keyword, nonce, and hash sources must be single-quoted, while host and
+/// scheme sources must not be. Quote the former if they were passed in bare.
+fn quote_source(source: &str) -> String {
+    const KEYWORDS: [&str; 7] = [
+        "self",
+        "none",
+        "unsafe-inline",
+        "unsafe-eval",
+        "unsafe-hashes",
+        "strict-dynamic",
+        "report-sample",
+    ];
+    const PREFIXES: [&str; 4] = ["nonce-", "sha256-", "sha384-", "sha512-"];
+
+    let lower = source.to_ascii_lowercase();
+    if KEYWORDS.contains(&lower.as_str()) || PREFIXES.iter().any(|p| lower.starts_with(p)) {
+        format!("'{}'", source)
+    } else {
+        source.to_string()
+    }
+}
@@ -0,0 +1,243 @@
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, STRICT_TRANSPORT_SECURITY};
+use crate::parse_utils::{parse_quoted_string, parse_token};
+use crate::Status;
+
+use std::time::Duration;
+
+/// Tell browsers to only access a site over HTTPS.
+///
+/// # Specifications
+///
+/// - [RFC 6797, section 6.1: Strict-Transport-Security](https://tools.ietf.org/html/rfc6797#section-6.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::security::StrictTransportSecurity;
+/// use std::time::Duration;
+///
+/// let mut hsts = StrictTransportSecurity::new(Duration::from_secs(63_072_000));
+/// hsts.include_subdomains().preload();
+/// hsts.validate()?;
+///
+/// let mut res = Response::new(200);
+/// hsts.apply(&mut res);
+/// assert_eq!(
+///     res["Strict-Transport-Security"],
+///     "max-age=63072000; includeSubDomains; preload"
+/// );
+///
+/// let hsts = StrictTransportSecurity::from_headers(res)?.unwrap();
+/// assert_eq!(hsts.max_age(), Duration::from_secs(63_072_000));
+/// assert!(hsts.includes_subdomains());
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct StrictTransportSecurity {
+    max_age: Duration,
+    include_subdomains: bool,
+    preload: bool,
+}
+
+impl StrictTransportSecurity {
+    /// Create a new instance of `StrictTransportSecurity`.
+    ///
+    /// The duration is truncated to whole seconds.
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            include_subdomains: false,
+            preload: false,
+        }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// Directive names are matched case-insensitively, and unknown directives
+    /// are ignored.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let headers = match headers.as_ref().get(STRICT_TRANSPORT_SECURITY) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        // If we successfully parsed the header then there's always at least one
+        // entry. We want the last entry.
+        let value = headers.iter().last().unwrap();
+
+        let mut max_age = None;
+        let mut include_subdomains = false;
+        let mut preload = false;
+        for directive in value.as_str().split(';') {
+            let directive = directive.trim();
+            if directive.is_empty() {
+                continue;
+            }
+
+            let (name, rest) = match parse_token(directive) {
+                (Some(name), rest) => (name.to_ascii_lowercase(), rest.trim_start()),
+                (None, _) => bail!(400, "Invalid Strict-Transport-Security directive"),
+            };
+            match name.as_str() {
+                "max-age" => {
+                    if max_age.is_some() {
+                        bail!(400, "Duplicate max-age directive");
+                    }
+                    let value = match rest.strip_prefix('=').map(str::trim_start) {
+                        Some(value) if value.starts_with('"') => parse_quoted_string(value).0,
+                        Some(value) => parse_token(value).0.map(Into::into),
+                        None => None,
+                    };
+                    let secs: u64 = value.status(400)?.parse().status(400)?;
+                    max_age = Some(Duration::from_secs(secs));
+                }
+                "includesubdomains" => include_subdomains = true,
+                "preload" => preload = true,
+                _ => { /* unknown directives must be ignored */ }
+            }
+        }
+
+        let max_age = match max_age {
+            Some(max_age) => max_age,
+            None => bail!(
+                400,
+                "Strict-Transport-Security requires a max-age directive"
+            ),
+        };
+
+        Ok(Some(Self {
+            max_age,
+            include_subdomains,
+            preload,
+        }))
+    }
+
+    /// Sets the `Strict-Transport-Security` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Check whether the policy is eligible for the browser preload lists.
+    ///
+    /// # Errors
+    ///
+    /// Preload registries require `includeSubDomains` to be set alongside
+    /// `preload`, so an error is returned if it's missing.
+    pub fn validate(&self) -> crate::Result<()> {
+        crate::ensure!(
+            !self.preload || self.include_subdomains,
+            "preload requires includeSubDomains to be set"
+        );
+        Ok(())
+    }
+
+    /// Apply the policy to all subdomains as well.
+    pub fn include_subdomains(&mut self) -> &mut Self {
+        self.include_subdomains = true;
+        self
+    }
+
+    /// Signal consent to being included in browser preload lists.
+    pub fn preload(&mut self) -> &mut Self {
+        self.preload = true;
+        self
+    }
+
+    /// Get the time browsers should remember to only use HTTPS.
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Returns `true` if the `includeSubDomains` directive is set.
+    pub fn includes_subdomains(&self) -> bool {
+        self.include_subdomains
+    }
+
+    /// Returns `true` if the `preload` directive is set.
+    pub fn is_preload(&self) -> bool {
+        self.preload
+    }
+}
+
+impl Header for StrictTransportSecurity {
+    fn header_name(&self) -> HeaderName {
+        STRICT_TRANSPORT_SECURITY
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = format!("max-age={}", self.max_age.as_secs());
+        if self.include_subdomains {
+            output.push_str("; includeSubDomains");
+        }
+        if self.preload {
+            output.push_str("; preload");
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::Headers;
+
+    #[test]
+    fn smoke() -> crate::Result<()> {
+        let mut hsts = StrictTransportSecurity::new(Duration::from_millis(31_536_000_900));
+        hsts.include_subdomains();
+
+        let mut headers = Headers::new();
+        hsts.apply(&mut headers);
+        assert_eq!(
+            headers[STRICT_TRANSPORT_SECURITY],
+            "max-age=31536000; includeSubDomains"
+        );
+
+        let parsed = StrictTransportSecurity::from_headers(headers)?.unwrap();
+        assert_eq!(parsed.max_age(), Duration::from_secs(31_536_000));
+        assert!(parsed.includes_subdomains());
+        assert!(!parsed.is_preload());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_quoted_and_mixed_case() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(
+            STRICT_TRANSPORT_SECURITY,
+            r#"Max-Age="600"; INCLUDESUBDOMAINS; preload; unknown=1"#,
+        )?;
+        let hsts = StrictTransportSecurity::from_headers(headers)?.unwrap();
+        assert_eq!(hsts.max_age(), Duration::from_secs(600));
+        assert!(hsts.includes_subdomains());
+        assert!(hsts.is_preload());
+        Ok(())
+    }
+
+    #[test]
+    fn preload_requires_subdomains() {
+        let mut hsts = StrictTransportSecurity::new(Duration::from_secs(600));
+        hsts.preload();
+        assert!(hsts.validate().is_err());
+
+        hsts.include_subdomains();
+        assert!(hsts.validate().is_ok());
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        for value in &["includeSubDomains", "max-age=-1", "max-age=1; max-age=2"] {
+            let mut headers = Headers::new();
+            headers.insert(STRICT_TRANSPORT_SECURITY, *value).unwrap();
+            let err = StrictTransportSecurity::from_headers(headers).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+    }
+}
@@ -3,14 +3,17 @@
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, ALLOW};
 use crate::Method;
 
-use std::collections::{hash_set, HashSet};
 use std::fmt::{self, Debug, Write};
 use std::iter::Iterator;
+use std::slice;
 
 use std::str::FromStr;
 
 /// List the set of methods supported by a resource.
 ///
+/// Methods are listed in the order they were inserted, and each method is only
+/// listed once.
+///
 /// # Specifications
 ///
 /// - [RFC 7231, section 7.4.1: Allow](https://tools.ietf.org/html/rfc7231#section-7.4.1)
@@ -27,8 +30,9 @@ use std::str::FromStr;
 /// allow.insert(Method::Put);
 /// allow.insert(Method::Post);
 ///
-/// let mut res = Response::new(200);
-/// res.insert_header(&allow, &allow);
+/// let mut res = Response::new(405);
+/// allow.apply(&mut res);
+/// assert_eq!(res["Allow"], "PUT, POST");
 ///
 /// let allow = Allow::from_headers(res)?.unwrap();
 /// assert!(allow.contains(Method::Put));
@@ -37,20 +41,18 @@ use std::str::FromStr;
 /// # Ok(()) }
 /// ```
 pub struct Allow {
-    entries: HashSet<Method>,
+    entries: Vec<Method>,
 }
 
 impl Allow {
     /// Create a new instance of `Allow`.
     pub fn new() -> Self {
-        Self {
-            entries: HashSet::new(),
-        }
+        Self { entries: vec![] }
     }
 
     /// Create a new instance from headers.
     pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
-        let mut entries = HashSet::new();
+        let mut allow = Self::new();
         let headers = match headers.as_ref().get(ALLOW) {
             Some(headers) => headers,
             None => return Ok(None),
@@ -58,17 +60,30 @@ impl Allow {
 
         for value in headers {
             for part in value.as_str().trim().split(',') {
-                let method = Method::from_str(part.trim())?;
-                entries.insert(method);
+                let part = part.trim();
+                // An empty `Allow` header means no methods are allowed.
+                if part.is_empty() {
+                    continue;
+                }
+                allow.insert(Method::from_str(part)?);
             }
         }
 
-        Ok(Some(Self { entries }))
+        Ok(Some(allow))
+    }
+
+    /// Sets the `Allow` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
     }
 
     /// Push a method into the set of methods.
+    ///
+    /// Methods which are already listed are ignored.
     pub fn insert(&mut self, method: Method) {
-        self.entries.insert(method);
+        if !self.contains(method) {
+            self.entries.push(method);
+        }
     }
 
     /// An iterator visiting all server entries.
@@ -127,7 +142,7 @@ impl<'a> IntoIterator for &'a Allow {
 /// A borrowing iterator over entries in `Allow`.
 #[derive(Debug)]
 pub struct IntoIter {
-    inner: hash_set::IntoIter<Method>,
+    inner: std::vec::IntoIter<Method>,
 }
 
 impl Iterator for IntoIter {
@@ -146,7 +161,7 @@ impl Iterator for IntoIter {
 /// A lending iterator over entries in `Allow`.
 #[derive(Debug)]
 pub struct Iter<'a> {
-    inner: hash_set::Iter<'a, Method>,
+    inner: slice::Iter<'a, Method>,
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -191,4 +206,32 @@ mod test {
         assert!(allow.contains(Method::Post));
         Ok(())
     }
+
+    #[test]
+    fn serialized_in_insertion_order() {
+        let mut allow = Allow::new();
+        allow.insert(Method::Get);
+        allow.insert(Method::Head);
+        allow.insert(Method::Get);
+        allow.insert(Method::Options);
+
+        let mut headers = Headers::new();
+        allow.apply(&mut headers);
+        assert_eq!(headers[ALLOW], "GET, HEAD, OPTIONS");
+    }
+
+    #[test]
+    fn parse() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(ALLOW, "GET, POST")?;
+        let allow = Allow::from_headers(headers)?.unwrap();
+        let methods: Vec<_> = allow.into_iter().collect();
+        assert_eq!(methods, [Method::Get, Method::Post]);
+
+        let mut headers = Headers::new();
+        headers.insert(ALLOW, "")?;
+        let allow = Allow::from_headers(headers)?.unwrap();
+        assert_eq!(allow.iter().count(), 0);
+        Ok(())
+    }
 }
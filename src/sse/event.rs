@@ -0,0 +1,202 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+/// A server-sent event.
+///
+/// # Specifications
+///
+/// - [HTML Living Standard, section 9.2.5: Parsing an event stream](https://html.spec.whatwg.org/multipage/server-sent-events.html#parsing-an-event-stream)
+///
+/// # Examples
+///
+/// ```
+/// use http_types::sse::Event;
+/// use std::time::Duration;
+///
+/// let mut event = Event::new("{\"temperature\": 21}");
+/// event
+///     .set_name("reading")
+///     .set_id("42")
+///     .set_retry(Duration::from_secs(5));
+///
+/// assert_eq!(event.name(), Some("reading"));
+/// assert_eq!(event.data(), "{\"temperature\": 21}");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Event {
+    name: Option<String>,
+    data: String,
+    id: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl Event {
+    /// Create a new instance of `Event` with the given data.
+    ///
+    /// Data may span multiple lines.
+    pub fn new(data: impl Into<String>) -> Self {
+        Self {
+            name: None,
+            data: data.into(),
+            id: None,
+            retry: None,
+        }
+    }
+
+    /// Get the event name, as sent in the `event` field.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Set the event name, as sent in the `event` field.
+    ///
+    /// Clients dispatch events without a name as `message` events.
+    pub fn set_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Get the event data.
+    pub fn data(&self) -> &str {
+        &self.data
+    }
+
+    /// Get the event id.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Set the event id, which clients send back in the `Last-Event-ID`
+    /// header when reconnecting.
+    pub fn set_id(&mut self, id: impl Into<String>) -> &mut Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Get the reconnection time.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    /// Set the time clients should wait before reconnecting. The duration is
+    /// truncated to whole milliseconds.
+    pub fn set_retry(&mut self, retry: Duration) -> &mut Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Encode the event in the `text/event-stream` format.
+    ///
+    /// # Errors
+    ///
+    /// This function will error if the name or id contain line breaks, or if
+    /// the id contains a NULL character.
+    pub(crate) fn encode(&self) -> crate::Result<String> {
+        let mut output = String::new();
+        if let Some(name) = &self.name {
+            crate::ensure!(
+                !has_line_break(name),
+                "Event names may not contain line breaks"
+            );
+            writeln!(output, "event: {}", name).unwrap();
+        }
+        for line in lines(&self.data) {
+            writeln!(output, "data: {}", line).unwrap();
+        }
+        if let Some(id) = &self.id {
+            crate::ensure!(
+                !has_line_break(id) && !id.contains('\0'),
+                "Event ids may not contain line breaks or NULL characters"
+            );
+            writeln!(output, "id: {}", id).unwrap();
+        }
+        if let Some(retry) = self.retry {
+            writeln!(output, "retry: {}", retry.as_millis()).unwrap();
+        }
+        output.push('\n');
+        Ok(output)
+    }
+}
+
+/// Encode a comment in the `text/event-stream` format. Comments are ignored by
+/// clients, but can be used to keep connections alive.
+pub(crate) fn encode_comment(comment: &str) -> String {
+    let mut output = String::new();
+    for line in lines(comment) {
+        writeln!(output, ": {}", line).unwrap();
+    }
+    output
+}
+
+/// Split a string on CRLF, LF, and CR line breaks.
+fn lines(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(s);
+    std::iter::from_fn(move || {
+        let s = rest?;
+        match s.find(['\r', '\n']) {
+            Some(i) => {
+                let skip = if s[i..].starts_with("\r\n") { 2 } else { 1 };
+                rest = Some(&s[i + skip..]);
+                Some(&s[..i])
+            }
+            None => {
+                rest = None;
+                Some(s)
+            }
+        }
+    })
+}
+
+fn has_line_break(s: &str) -> bool {
+    s.contains(['\r', '\n'])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multi_line_data() -> crate::Result<()> {
+        let mut event = Event::new("first\nsecond\r\nthird\rfourth");
+        event
+            .set_name("update")
+            .set_id("7")
+            .set_retry(Duration::from_millis(1500));
+        assert_eq!(
+            event.encode()?,
+            "event: update\n\
+             data: first\n\
+             data: second\n\
+             data: third\n\
+             data: fourth\n\
+             id: 7\n\
+             retry: 1500\n\
+             \n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_data() -> crate::Result<()> {
+        assert_eq!(Event::new("").encode()?, "data: \n\n");
+        assert_eq!(Event::new("a\n").encode()?, "data: a\ndata: \n\n");
+        Ok(())
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(encode_comment("keep-alive"), ": keep-alive\n");
+        assert_eq!(encode_comment("a\nb"), ": a\n: b\n");
+    }
+
+    #[test]
+    fn reject_line_breaks() {
+        let mut event = Event::new("data");
+        event.set_name("a\nb");
+        assert!(event.encode().is_err());
+
+        let mut event = Event::new("data");
+        event.set_id("1\r");
+        assert!(event.encode().is_err());
+    }
+}
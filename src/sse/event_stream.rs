@@ -0,0 +1,184 @@
+use futures_lite::{io, prelude::*, ready};
+
+use crate::mime;
+use crate::sse::event::encode_comment;
+use crate::sse::Event;
+use crate::Body;
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A `text/event-stream` response body.
+///
+/// Events are sent through the [`Sender`] half, and are encoded as they're
+/// read from the stream. The stream ends once all senders have been dropped.
+/// Converting an `EventStream` into a `Body` sets the mime type to
+/// `text/event-stream`, without a length.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> { async_std::task::block_on(async {
+/// #
+/// use http_types::sse::{Event, EventStream};
+/// use http_types::{Response, StatusCode};
+///
+/// let (sender, stream) = EventStream::new();
+///
+/// let mut res = Response::new(StatusCode::Ok);
+/// res.set_body(stream);
+/// assert_eq!(res.content_type(), Some(http_types::mime::SSE));
+///
+/// sender.send(&Event::new("Hello Nori")).await?;
+/// drop(sender);
+///
+/// assert_eq!(res.body_string().await?, "data: Hello Nori\n\n");
+/// #
+/// # Ok(()) }) }
+/// ```
+#[derive(Debug)]
+pub struct EventStream {
+    receiver: async_channel::Receiver<Vec<u8>>,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl EventStream {
+    /// Create a new event stream, returning the sending half along with the
+    /// stream itself.
+    pub fn new() -> (Sender, Self) {
+        let (sender, receiver) = async_channel::bounded(1);
+        let stream = Self {
+            receiver,
+            buf: vec![],
+            pos: 0,
+        };
+        (Sender { sender }, stream)
+    }
+}
+
+impl AsyncRead for EventStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let bytes = ready!(self.as_mut().poll_fill_buf(cx))?;
+        let len = bytes.len().min(buf.len());
+        buf[..len].copy_from_slice(&bytes[..len]);
+        self.consume(len);
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncBufRead for EventStream {
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        let this = self.get_mut();
+        if this.pos == this.buf.len() {
+            match ready!(Pin::new(&mut this.receiver).poll_next(cx)) {
+                Some(bytes) => {
+                    this.buf = bytes;
+                    this.pos = 0;
+                }
+                None => return Poll::Ready(Ok(&[])),
+            }
+        }
+        Poll::Ready(Ok(&this.buf[this.pos..]))
+    }
+
+    fn consume(mut self: Pin<&mut Self>, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buf.len());
+    }
+}
+
+impl From<EventStream> for Body {
+    fn from(stream: EventStream) -> Self {
+        let mut body = Body::from_reader(stream, None);
+        body.set_mime(Some(mime::SSE));
+        body
+    }
+}
+
+/// The sending half of an [`EventStream`].
+///
+/// Senders can be cloned to send events from multiple tasks.
+#[derive(Debug, Clone)]
+pub struct Sender {
+    sender: async_channel::Sender<Vec<u8>>,
+}
+
+impl Sender {
+    /// Send an event.
+    ///
+    /// # Errors
+    ///
+    /// This function will error if the event can't be encoded, or if the
+    /// stream has been dropped.
+    pub async fn send(&self, event: &Event) -> crate::Result<()> {
+        let bytes = event.encode()?.into_bytes();
+        self.send_bytes(bytes).await
+    }
+
+    /// Send a comment. Comments are ignored by clients, which makes them
+    /// useful as keep-alives.
+    ///
+    /// # Errors
+    ///
+    /// This function will error if the stream has been dropped.
+    pub async fn send_comment(&self, comment: &str) -> crate::Result<()> {
+        self.send_bytes(encode_comment(comment).into_bytes()).await
+    }
+
+    async fn send_bytes(&self, bytes: Vec<u8>) -> crate::Result<()> {
+        match self.sender.send(bytes).await {
+            Ok(()) => Ok(()),
+            Err(_) => crate::bail!("The event stream has been closed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[async_std::test]
+    async fn wire_format() -> crate::Result<()> {
+        let (sender, stream) = EventStream::new();
+        let body = Body::from(stream);
+        assert_eq!(body.len(), None);
+        assert_eq!(body.mime(), Some(&mime::SSE));
+
+        async_std::task::spawn(async move {
+            let mut event = Event::new("line one\nline two");
+            event.set_name("update").set_id("1");
+            sender.send(&event).await?;
+            sender.send_comment("keep-alive").await?;
+
+            let mut event = Event::new("bye");
+            event.set_retry(Duration::from_secs(10));
+            sender.send(&event).await
+        });
+
+        assert_eq!(
+            body.into_string().await?,
+            "event: update\n\
+             data: line one\n\
+             data: line two\n\
+             id: 1\n\
+             \n\
+             : keep-alive\n\
+             data: bye\n\
+             retry: 10000\n\
+             \n"
+        );
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn send_after_close() {
+        let (sender, stream) = EventStream::new();
+        drop(stream);
+        assert!(sender.send(&Event::new("lost")).await.is_err());
+    }
+}
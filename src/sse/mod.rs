@@ -0,0 +1,16 @@
+//! Server-sent events.
+//!
+//! Server-sent events allow a server to push a stream of events to a client
+//! over a long-lived response with the `text/event-stream` mime type. Events
+//! are sent through a [`Sender`], and read from the matching [`EventStream`]
+//! body.
+//!
+//! # Specifications
+//!
+//! - [HTML Living Standard, section 9.2: Server-sent events](https://html.spec.whatwg.org/multipage/server-sent-events.html)
+
+mod event;
+mod event_stream;
+
+pub use event::Event;
+pub use event_stream::{EventStream, Sender};
@@ -419,7 +419,84 @@ pub enum StatusCode {
     NetworkAuthenticationRequired = 511,
 }
 
+/// Every known status code, in ascending order.
+static ALL: [StatusCode; 59] = [
+    StatusCode::Continue,
+    StatusCode::SwitchingProtocols,
+    StatusCode::EarlyHints,
+    StatusCode::Ok,
+    StatusCode::Created,
+    StatusCode::Accepted,
+    StatusCode::NonAuthoritativeInformation,
+    StatusCode::NoContent,
+    StatusCode::ResetContent,
+    StatusCode::PartialContent,
+    StatusCode::MultiStatus,
+    StatusCode::ImUsed,
+    StatusCode::MultipleChoice,
+    StatusCode::MovedPermanently,
+    StatusCode::Found,
+    StatusCode::SeeOther,
+    StatusCode::NotModified,
+    StatusCode::TemporaryRedirect,
+    StatusCode::PermanentRedirect,
+    StatusCode::BadRequest,
+    StatusCode::Unauthorized,
+    StatusCode::PaymentRequired,
+    StatusCode::Forbidden,
+    StatusCode::NotFound,
+    StatusCode::MethodNotAllowed,
+    StatusCode::NotAcceptable,
+    StatusCode::ProxyAuthenticationRequired,
+    StatusCode::RequestTimeout,
+    StatusCode::Conflict,
+    StatusCode::Gone,
+    StatusCode::LengthRequired,
+    StatusCode::PreconditionFailed,
+    StatusCode::PayloadTooLarge,
+    StatusCode::UriTooLong,
+    StatusCode::UnsupportedMediaType,
+    StatusCode::RequestedRangeNotSatisfiable,
+    StatusCode::ExpectationFailed,
+    StatusCode::ImATeapot,
+    StatusCode::MisdirectedRequest,
+    StatusCode::UnprocessableEntity,
+    StatusCode::Locked,
+    StatusCode::FailedDependency,
+    StatusCode::TooEarly,
+    StatusCode::UpgradeRequired,
+    StatusCode::PreconditionRequired,
+    StatusCode::TooManyRequests,
+    StatusCode::RequestHeaderFieldsTooLarge,
+    StatusCode::UnavailableForLegalReasons,
+    StatusCode::InternalServerError,
+    StatusCode::NotImplemented,
+    StatusCode::BadGateway,
+    StatusCode::ServiceUnavailable,
+    StatusCode::GatewayTimeout,
+    StatusCode::HttpVersionNotSupported,
+    StatusCode::VariantAlsoNegotiates,
+    StatusCode::InsufficientStorage,
+    StatusCode::LoopDetected,
+    StatusCode::NotExtended,
+    StatusCode::NetworkAuthenticationRequired,
+];
+
 impl StatusCode {
+    /// An iterator visiting all known status codes, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::StatusCode;
+    ///
+    /// let redirects: Vec<_> = StatusCode::iter().filter(|s| s.is_redirection()).collect();
+    /// assert_eq!(redirects.first(), Some(&StatusCode::MultipleChoice));
+    /// ```
+    pub fn iter() -> impl Iterator<Item = StatusCode> {
+        ALL.iter().copied()
+    }
+
     /// Returns `true` if the status code is `1xx` range.
     ///
     /// If this returns `true` it indicates that the request was received,
@@ -465,7 +542,16 @@ impl StatusCode {
         (500..600).contains(&num)
     }
 
-    /// The canonical reason for a given status code
+    /// The canonical reason for a given status code, as registered in the
+    /// [IANA HTTP Status Code Registry](https://www.iana.org/assignments/http-status-codes/http-status-codes.xhtml).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::StatusCode;
+    ///
+    /// assert_eq!(StatusCode::NotFound.canonical_reason(), "Not Found");
+    /// ```
     pub fn canonical_reason(&self) -> &'static str {
         match self {
             StatusCode::Continue => "Continue",
@@ -474,13 +560,13 @@ impl StatusCode {
             StatusCode::Ok => "OK",
             StatusCode::Created => "Created",
             StatusCode::Accepted => "Accepted",
-            StatusCode::NonAuthoritativeInformation => "Non Authoritative Information",
+            StatusCode::NonAuthoritativeInformation => "Non-Authoritative Information",
             StatusCode::NoContent => "No Content",
             StatusCode::ResetContent => "Reset Content",
             StatusCode::PartialContent => "Partial Content",
             StatusCode::MultiStatus => "Multi-Status",
-            StatusCode::ImUsed => "Im Used",
-            StatusCode::MultipleChoice => "Multiple Choice",
+            StatusCode::ImUsed => "IM Used",
+            StatusCode::MultipleChoice => "Multiple Choices",
             StatusCode::MovedPermanently => "Moved Permanently",
             StatusCode::Found => "Found",
             StatusCode::SeeOther => "See Other",
@@ -626,7 +712,27 @@ impl From<StatusCode> for u16 {
 impl std::convert::TryFrom<u16> for StatusCode {
     type Error = crate::Error;
 
+    /// Convert a numeric status code into a `StatusCode`.
+    ///
+    /// This is the conversion to use when reading status codes off the wire.
+    /// Values outside of the `100..=599` range, as well as codes which fall in
+    /// that range but aren't known to this crate, return an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use http_types::StatusCode;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(StatusCode::try_from(404).unwrap(), StatusCode::NotFound);
+    /// assert!(StatusCode::try_from(299).is_err());
+    /// assert!(StatusCode::try_from(600).is_err());
+    /// ```
     fn try_from(num: u16) -> Result<Self, Self::Error> {
+        if !(100..600).contains(&num) {
+            crate::bail!("Status code {} is out of range", num);
+        }
+
         match num {
             100 => Ok(StatusCode::Continue),
             101 => Ok(StatusCode::SwitchingProtocols),
@@ -687,7 +793,7 @@ impl std::convert::TryFrom<u16> for StatusCode {
             508 => Ok(StatusCode::LoopDetected),
             510 => Ok(StatusCode::NotExtended),
             511 => Ok(StatusCode::NetworkAuthenticationRequired),
-            _ => crate::bail!("Invalid status code"),
+            _ => crate::bail!("Unknown status code {}", num),
         }
     }
 }
@@ -719,6 +825,58 @@ impl Display for StatusCode {
 #[cfg(test)]
 mod test {
     use super::StatusCode;
+
+    #[test]
+    fn canonical_reason() {
+        assert_eq!(StatusCode::NotFound.canonical_reason(), "Not Found");
+        assert_eq!(StatusCode::ImATeapot.canonical_reason(), "I'm a teapot");
+        assert_eq!(format!("{}", StatusCode::NotFound), "404: Not Found");
+    }
+
+    #[test]
+    fn classes() {
+        assert!(StatusCode::NoContent.is_success());
+        assert!(!StatusCode::NoContent.is_redirection());
+
+        assert!(StatusCode::MovedPermanently.is_redirection());
+        assert!(!StatusCode::MovedPermanently.is_client_error());
+
+        assert!(StatusCode::InternalServerError.is_server_error());
+        assert!(!StatusCode::InternalServerError.is_client_error());
+
+        assert!(StatusCode::Continue.is_informational());
+        assert!(StatusCode::NotFound.is_client_error());
+    }
+
+    #[test]
+    fn try_from_u16() {
+        use std::convert::TryFrom;
+
+        assert_eq!(StatusCode::try_from(204).unwrap(), StatusCode::NoContent);
+
+        let err = StatusCode::try_from(299).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown status code 299");
+
+        let err = StatusCode::try_from(1000).unwrap_err();
+        assert_eq!(err.to_string(), "Status code 1000 is out of range");
+        assert!(StatusCode::try_from(99).is_err());
+    }
+
+    #[test]
+    fn iter() {
+        use std::convert::TryFrom;
+
+        assert_eq!(StatusCode::iter().count(), 59);
+        let codes: Vec<u16> = StatusCode::iter().map(u16::from).collect();
+        assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Every code which can be parsed is included.
+        let known: Vec<u16> = (100..600)
+            .filter(|&n| StatusCode::try_from(n).is_ok())
+            .collect();
+        assert_eq!(codes, known);
+    }
+
     #[test]
     fn serde_as_u16() -> Result<(), serde_json::Error> {
         let status_code: StatusCode = serde_json::from_str("202")?;
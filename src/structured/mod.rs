@@ -0,0 +1,329 @@
+//! Structured field values for HTTP headers.
+//!
+//! Newer headers, such as `Priority` and `Content-Digest`, define their
+//! values in terms of a shared set of types: items, lists, and dictionaries.
+//! This module implements the parser and serializer shared by those headers.
+//!
+//! Parsing is strict: input which doesn't match the grammar of the
+//! specification fails with an error, rather than being partially accepted.
+//!
+//! # Specifications
+//!
+//! - [RFC 8941: Structured Field Values for HTTP](https://tools.ietf.org/html/rfc8941)
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() -> http_types::Result<()> {
+//! #
+//! use http_types::structured::{self, BareItem};
+//!
+//! let dict = structured::parse_dictionary("u=2, i")?;
+//! assert_eq!(dict.get("u").unwrap().as_item().unwrap().value, BareItem::Integer(2));
+//! assert_eq!(dict.get("i").unwrap().as_item().unwrap().value, BareItem::Boolean(true));
+//! assert_eq!(structured::serialize_dictionary(&dict)?, "u=2, i");
+//! #
+//! # Ok(()) }
+//! ```
+
+mod parser;
+mod serializer;
+
+pub use parser::{parse_dictionary, parse_item, parse_list};
+pub use serializer::{serialize_dictionary, serialize_item, serialize_list};
+
+use std::slice;
+
+/// A bare item: the value of an item or parameter, without parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BareItem {
+    /// An integer, of at most 15 digits.
+    Integer(i64),
+    /// A decimal, of at most 12 integer and 3 fractional digits.
+    Decimal(f64),
+    /// A string of printable ASCII characters.
+    String(String),
+    /// A token, such as `text/html` or `*`.
+    Token(String),
+    /// A sequence of bytes, serialized as base64.
+    ByteSequence(Vec<u8>),
+    /// A boolean.
+    Boolean(bool),
+}
+
+impl BareItem {
+    /// Get the integer, if this is an integer.
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Get the decimal, if this is a decimal.
+    pub fn as_decimal(&self) -> Option<f64> {
+        match self {
+            Self::Decimal(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Get the string, if this is a string.
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get the token, if this is a token.
+    pub fn as_token(&self) -> Option<&str> {
+        match self {
+            Self::Token(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Get the bytes, if this is a byte sequence.
+    pub fn as_byte_sequence(&self) -> Option<&[u8]> {
+        match self {
+            Self::ByteSequence(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Get the boolean, if this is a boolean.
+    pub fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+/// An ordered map of parameters, attached to items and inner lists.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Parameters {
+    entries: Vec<(String, BareItem)>,
+}
+
+impl Parameters {
+    /// Create a new, empty, instance of `Parameters`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Get the value of a parameter.
+    pub fn get(&self, key: &str) -> Option<&BareItem> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Set a parameter, overwriting the value of an existing parameter with
+    /// the same key, without changing its position.
+    ///
+    /// Keys are validated when the parameters are serialized.
+    pub fn insert(&mut self, key: impl Into<String>, value: BareItem) {
+        insert(&mut self.entries, key.into(), value);
+    }
+
+    /// Returns the number of parameters.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator visiting all parameters in order.
+    pub fn iter(&self) -> Iter<'_, BareItem> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+}
+
+/// A bare item, with parameters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    /// The value of the item.
+    pub value: BareItem,
+    /// The parameters of the item.
+    pub params: Parameters,
+}
+
+impl Item {
+    /// Create a new item without parameters.
+    pub fn new(value: BareItem) -> Self {
+        Self {
+            value,
+            params: Parameters::new(),
+        }
+    }
+}
+
+/// A list of items, nested in a list or dictionary, with parameters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InnerList {
+    /// The items of the inner list.
+    pub items: Vec<Item>,
+    /// The parameters of the inner list.
+    pub params: Parameters,
+}
+
+impl InnerList {
+    /// Create a new inner list without parameters.
+    pub fn new(items: Vec<Item>) -> Self {
+        Self {
+            items,
+            params: Parameters::new(),
+        }
+    }
+}
+
+/// A member of a list or dictionary: either an item, or an inner list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Member {
+    /// An item.
+    Item(Item),
+    /// An inner list.
+    InnerList(InnerList),
+}
+
+impl Member {
+    /// Get the item, if this is an item.
+    pub fn as_item(&self) -> Option<&Item> {
+        match self {
+            Self::Item(item) => Some(item),
+            Self::InnerList(_) => None,
+        }
+    }
+
+    /// Get the inner list, if this is an inner list.
+    pub fn as_inner_list(&self) -> Option<&InnerList> {
+        match self {
+            Self::Item(_) => None,
+            Self::InnerList(list) => Some(list),
+        }
+    }
+
+    /// Get the parameters of the member.
+    pub fn params(&self) -> &Parameters {
+        match self {
+            Self::Item(item) => &item.params,
+            Self::InnerList(list) => &list.params,
+        }
+    }
+}
+
+impl From<Item> for Member {
+    fn from(item: Item) -> Self {
+        Self::Item(item)
+    }
+}
+
+impl From<InnerList> for Member {
+    fn from(list: InnerList) -> Self {
+        Self::InnerList(list)
+    }
+}
+
+/// A list of members.
+pub type List = Vec<Member>;
+
+/// An ordered map of members.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dictionary {
+    entries: Vec<(String, Member)>,
+}
+
+impl Dictionary {
+    /// Create a new, empty, instance of `Dictionary`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Get the value of a member.
+    pub fn get(&self, key: &str) -> Option<&Member> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Set a member, overwriting the value of an existing member with the
+    /// same key, without changing its position.
+    ///
+    /// Keys are validated when the dictionary is serialized.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Member>) {
+        insert(&mut self.entries, key.into(), value.into());
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no members.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator visiting all members in order.
+    pub fn iter(&self) -> Iter<'_, Member> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+}
+
+/// Insert into an ordered map, overwriting the value of an existing key.
+fn insert<T>(entries: &mut Vec<(String, T)>, key: String, value: T) {
+    match entries.iter_mut().find(|(name, _)| *name == key) {
+        Some(entry) => entry.1 = value,
+        None => entries.push((key, value)),
+    }
+}
+
+/// A borrowing iterator over the entries of `Parameters` or a `Dictionary`.
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    inner: slice::Iter<'a, (String, T)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (&'a str, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, value)| (key.as_str(), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a Parameters {
+    type Item = (&'a str, &'a BareItem);
+    type IntoIter = Iter<'a, BareItem>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Dictionary {
+    type Item = (&'a str, &'a Member);
+    type IntoIter = Iter<'a, Member>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
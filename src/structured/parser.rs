@@ -0,0 +1,495 @@
+use super::{BareItem, Dictionary, InnerList, Item, List, Member, Parameters};
+use crate::bail_status as bail;
+
+/// Parse a header value as a structured list.
+///
+/// An empty value is parsed as an empty list.
+///
+/// # Errors
+///
+/// An error with status `400: Bad Request` is returned if the value is
+/// malformed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::structured::{self, BareItem};
+///
+/// let list = structured::parse_list(r#"sugar, "tea", (1 2);sweet"#)?;
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list[1].as_item().unwrap().value, BareItem::String("tea".into()));
+/// assert_eq!(list[2].params().get("sweet"), Some(&BareItem::Boolean(true)));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn parse_list(input: &str) -> crate::Result<List> {
+    let mut parser = Parser::new(input)?;
+    let mut list = vec![];
+    while !parser.is_empty() {
+        list.push(parser.parse_member()?);
+        if !parser.parse_separator()? {
+            break;
+        }
+    }
+    parser.finish()?;
+    Ok(list)
+}
+
+/// Parse a header value as a structured dictionary.
+///
+/// An empty value is parsed as an empty dictionary.
+///
+/// # Errors
+///
+/// An error with status `400: Bad Request` is returned if the value is
+/// malformed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::structured::{self, BareItem};
+///
+/// let dict = structured::parse_dictionary("a=?0, b, c;foo=bar")?;
+/// let c = dict.get("c").unwrap().as_item().unwrap();
+/// assert_eq!(c.value, BareItem::Boolean(true));
+/// assert_eq!(c.params.get("foo"), Some(&BareItem::Token("bar".into())));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn parse_dictionary(input: &str) -> crate::Result<Dictionary> {
+    let mut parser = Parser::new(input)?;
+    let mut dict = Dictionary::new();
+    while !parser.is_empty() {
+        let key = parser.parse_key()?;
+        let member = if parser.eat(b'=') {
+            parser.parse_member()?
+        } else {
+            let params = parser.parse_parameters()?;
+            Member::Item(Item {
+                value: BareItem::Boolean(true),
+                params,
+            })
+        };
+        dict.insert(key, member);
+        if !parser.parse_separator()? {
+            break;
+        }
+    }
+    parser.finish()?;
+    Ok(dict)
+}
+
+/// Parse a header value as a structured item.
+///
+/// # Errors
+///
+/// An error with status `400: Bad Request` is returned if the value is
+/// malformed.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::structured::{self, BareItem};
+///
+/// let item = structured::parse_item("12.5;unit=ms")?;
+/// assert_eq!(item.value, BareItem::Decimal(12.5));
+/// assert_eq!(item.params.get("unit"), Some(&BareItem::Token("ms".into())));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn parse_item(input: &str) -> crate::Result<Item> {
+    let mut parser = Parser::new(input)?;
+    let item = parser.parse_item()?;
+    parser.finish()?;
+    Ok(item)
+}
+
+/// A parser over the bytes of a header value.
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Create a parser, skipping leading spaces.
+    fn new(input: &'a str) -> crate::Result<Self> {
+        if !input.is_ascii() {
+            bail!(400, "structured field contains non-ASCII characters");
+        }
+        let mut parser = Self {
+            input: input.as_bytes(),
+            pos: 0,
+        };
+        parser.skip_spaces();
+        Ok(parser)
+    }
+
+    /// Check that only trailing spaces are left.
+    fn finish(mut self) -> crate::Result<()> {
+        self.skip_spaces();
+        if !self.is_empty() {
+            bail!(400, "unexpected trailing characters in structured field");
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.input.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    /// Consume the byte if it's next.
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.eat(b' ') {}
+    }
+
+    fn skip_ows(&mut self) {
+        while self.eat(b' ') || self.eat(b'\t') {}
+    }
+
+    /// Parse the separator between members of a list or dictionary.
+    ///
+    /// Returns `false` if the end of the input was reached instead.
+    fn parse_separator(&mut self) -> crate::Result<bool> {
+        self.skip_ows();
+        if self.is_empty() {
+            return Ok(false);
+        }
+        if !self.eat(b',') {
+            bail!(400, "expected a comma in structured field");
+        }
+        self.skip_ows();
+        if self.is_empty() {
+            bail!(400, "trailing comma in structured field");
+        }
+        Ok(true)
+    }
+
+    fn parse_member(&mut self) -> crate::Result<Member> {
+        if self.peek() == Some(b'(') {
+            Ok(Member::InnerList(self.parse_inner_list()?))
+        } else {
+            Ok(Member::Item(self.parse_item()?))
+        }
+    }
+
+    fn parse_inner_list(&mut self) -> crate::Result<InnerList> {
+        self.eat(b'(');
+        let mut items = vec![];
+        loop {
+            self.skip_spaces();
+            if self.eat(b')') {
+                let params = self.parse_parameters()?;
+                return Ok(InnerList { items, params });
+            }
+            items.push(self.parse_item()?);
+            if !matches!(self.peek(), Some(b' ') | Some(b')')) {
+                bail!(400, "unterminated inner list in structured field");
+            }
+        }
+    }
+
+    fn parse_item(&mut self) -> crate::Result<Item> {
+        let value = self.parse_bare_item()?;
+        let params = self.parse_parameters()?;
+        Ok(Item { value, params })
+    }
+
+    fn parse_parameters(&mut self) -> crate::Result<Parameters> {
+        let mut params = Parameters::new();
+        while self.eat(b';') {
+            self.skip_spaces();
+            let key = self.parse_key()?;
+            let value = match self.eat(b'=') {
+                true => self.parse_bare_item()?,
+                false => BareItem::Boolean(true),
+            };
+            params.insert(key, value);
+        }
+        Ok(params)
+    }
+
+    fn parse_key(&mut self) -> crate::Result<String> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'a'..=b'z') | Some(b'*') => self.pos += 1,
+            _ => bail!(400, "invalid key in structured field"),
+        }
+        while let Some(b'a'..=b'z') | Some(b'0'..=b'9') | Some(b'_' | b'-' | b'.' | b'*') =
+            self.peek()
+        {
+            self.pos += 1;
+        }
+        Ok(self.slice(start).to_owned())
+    }
+
+    fn parse_bare_item(&mut self) -> crate::Result<BareItem> {
+        match self.peek() {
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            Some(b'"') => self.parse_string(),
+            Some(b'A'..=b'Z') | Some(b'a'..=b'z') | Some(b'*') => Ok(self.parse_token()),
+            Some(b':') => self.parse_byte_sequence(),
+            Some(b'?') => self.parse_boolean(),
+            _ => bail!(400, "invalid item in structured field"),
+        }
+    }
+
+    fn parse_number(&mut self) -> crate::Result<BareItem> {
+        let start = self.pos;
+        self.eat(b'-');
+        let digits_start = self.pos;
+        let mut dot = None;
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'0'..=b'9' => {}
+                b'.' if dot.is_none() => {
+                    if self.pos - digits_start > 12 {
+                        bail!(400, "decimal in structured field is too large");
+                    }
+                    dot = Some(self.pos);
+                }
+                _ => break,
+            }
+            self.pos += 1;
+            let max_len = if dot.is_some() { 16 } else { 15 };
+            if self.pos - digits_start > max_len {
+                bail!(400, "number in structured field is too large");
+            }
+        }
+
+        let number = self.slice(start);
+        match dot {
+            None if self.pos == digits_start => bail!(400, "invalid number in structured field"),
+            None => Ok(BareItem::Integer(number.parse().unwrap())),
+            Some(dot) => {
+                let fraction_len = self.pos - dot - 1;
+                if dot == digits_start || fraction_len == 0 || fraction_len > 3 {
+                    bail!(400, "invalid decimal in structured field");
+                }
+                Ok(BareItem::Decimal(number.parse().unwrap()))
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> crate::Result<BareItem> {
+        self.eat(b'"');
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some(b'\\') => match self.next() {
+                    Some(byte @ b'"') | Some(byte @ b'\\') => string.push(byte as char),
+                    _ => bail!(400, "invalid escape in structured field string"),
+                },
+                Some(b'"') => return Ok(BareItem::String(string)),
+                Some(byte @ 0x20..=0x7e) => string.push(byte as char),
+                Some(_) => bail!(400, "invalid character in structured field string"),
+                None => bail!(400, "unterminated string in structured field"),
+            }
+        }
+    }
+
+    fn parse_token(&mut self) -> BareItem {
+        let start = self.pos;
+        self.pos += 1;
+        while let Some(byte) = self.peek() {
+            if !(is_tchar(byte) || byte == b':' || byte == b'/') {
+                break;
+            }
+            self.pos += 1;
+        }
+        BareItem::Token(self.slice(start).to_owned())
+    }
+
+    fn parse_byte_sequence(&mut self) -> crate::Result<BareItem> {
+        self.eat(b':');
+        let start = self.pos;
+        while let Some(byte) = self.peek() {
+            if !(byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'/' | b'=')) {
+                break;
+            }
+            self.pos += 1;
+        }
+        let encoded = self.slice(start);
+        if !self.eat(b':') {
+            bail!(400, "unterminated byte sequence in structured field");
+        }
+        match base64::decode(encoded) {
+            Ok(bytes) => Ok(BareItem::ByteSequence(bytes)),
+            Err(_) => bail!(400, "invalid byte sequence in structured field"),
+        }
+    }
+
+    fn parse_boolean(&mut self) -> crate::Result<BareItem> {
+        self.eat(b'?');
+        match self.next() {
+            Some(b'1') => Ok(BareItem::Boolean(true)),
+            Some(b'0') => Ok(BareItem::Boolean(false)),
+            _ => bail!(400, "invalid boolean in structured field"),
+        }
+    }
+
+    /// Get the input from `start` up to the current position.
+    fn slice(&self, start: usize) -> &'a str {
+        // The input was checked to be ASCII, so any slice is valid UTF-8.
+        std::str::from_utf8(&self.input[start..self.pos]).unwrap()
+    }
+}
+
+/// https://tools.ietf.org/html/rfc7230#section-3.2.6
+pub(super) fn is_tchar(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn items() -> crate::Result<()> {
+        assert_eq!(parse_item("42")?.value, BareItem::Integer(42));
+        assert_eq!(parse_item("-0")?.value, BareItem::Integer(0));
+        assert_eq!(
+            parse_item("999999999999999")?.value,
+            BareItem::Integer(999_999_999_999_999)
+        );
+        assert_eq!(parse_item("4.5")?.value, BareItem::Decimal(4.5));
+        assert_eq!(parse_item("-0.001")?.value, BareItem::Decimal(-0.001));
+        assert_eq!(
+            parse_item(r#""hello \"world\"""#)?.value,
+            BareItem::String(r#"hello "world""#.into())
+        );
+        assert_eq!(
+            parse_item("foo123/456")?.value,
+            BareItem::Token("foo123/456".into())
+        );
+        assert_eq!(
+            parse_item(":cHJldGVuZCB0aGlzIGlzIGJpbmFyeSBjb250ZW50Lg==:")?.value,
+            BareItem::ByteSequence(b"pretend this is binary content.".to_vec())
+        );
+        assert_eq!(parse_item("?0")?.value, BareItem::Boolean(false));
+        assert_eq!(parse_item("  ?1  ")?.value, BareItem::Boolean(true));
+        Ok(())
+    }
+
+    #[test]
+    fn parameters() -> crate::Result<()> {
+        let item = parse_item("text/html; charset=utf-8;q=0.5;a;a=?0")?;
+        assert_eq!(item.value, BareItem::Token("text/html".into()));
+        let params: Vec<_> = item.params.iter().collect();
+        assert_eq!(
+            params,
+            [
+                ("charset", &BareItem::Token("utf-8".into())),
+                ("q", &BareItem::Decimal(0.5)),
+                // Later values overwrite earlier ones, in the original position.
+                ("a", &BareItem::Boolean(false)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lists() -> crate::Result<()> {
+        let list = parse_list("sugar, tea,\trum")?;
+        let tokens: Vec<_> = list
+            .iter()
+            .map(|member| member.as_item().unwrap().value.as_token().unwrap())
+            .collect();
+        assert_eq!(tokens, ["sugar", "tea", "rum"]);
+
+        let list = parse_list(r#"("foo" "bar");lvl=5, ( ), 1"#)?;
+        let inner = list[0].as_inner_list().unwrap();
+        assert_eq!(inner.items.len(), 2);
+        assert_eq!(inner.params.get("lvl"), Some(&BareItem::Integer(5)));
+        assert!(list[1].as_inner_list().unwrap().items.is_empty());
+        assert_eq!(list[2].as_item().unwrap().value, BareItem::Integer(1));
+
+        assert!(parse_list("")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn dictionaries() -> crate::Result<()> {
+        let dict = parse_dictionary("en=\"Applepie\", da=:w4ZibGV0w6ZydGU=:, fr;q=1, en=1")?;
+        let keys: Vec<_> = dict.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["en", "da", "fr"]);
+        assert_eq!(
+            dict.get("en").unwrap().as_item().unwrap().value,
+            BareItem::Integer(1)
+        );
+        let fr = dict.get("fr").unwrap().as_item().unwrap();
+        assert_eq!(fr.value, BareItem::Boolean(true));
+        assert_eq!(fr.params.get("q"), Some(&BareItem::Integer(1)));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_malformed() {
+        for input in &[
+            "",
+            "1 2",
+            "1000000000000000",
+            "1234567890123.0",
+            "1.2345",
+            "1.",
+            "-",
+            "\"unterminated",
+            "\"bad \\escape\"",
+            "\"tab\t\"",
+            ":not base64!:",
+            "?2",
+            "Ä",
+            "1;KEY=1",
+            "1;=1",
+        ] {
+            assert_eq!(parse_item(input).unwrap_err().status(), 400, "{}", input);
+        }
+        for input in &["a,", "a,,b", "a b", "(a b", "(a,b)"] {
+            assert_eq!(parse_list(input).unwrap_err().status(), 400, "{}", input);
+        }
+        for input in &["A=1", "a=1,", "a=", "1=a"] {
+            let err = parse_dictionary(input).unwrap_err();
+            assert_eq!(err.status(), 400, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use super::parser::is_tchar;
+use super::{BareItem, Dictionary, InnerList, Item, Member, Parameters};
+use crate::bail_status as bail;
+
+use std::fmt::Write;
+
+/// Serialize a structured list into a header value.
+///
+/// # Errors
+///
+/// An error with status `500: Internal Server Error` is returned if any of
+/// the values can't be represented, such as an out of range integer or a
+/// string containing non-printable characters.
+pub fn serialize_list(list: &[Member]) -> crate::Result<String> {
+    let mut output = String::new();
+    for (i, member) in list.iter().enumerate() {
+        if i > 0 {
+            output.push_str(", ");
+        }
+        write_member(&mut output, member)?;
+    }
+    Ok(output)
+}
+
+/// Serialize a structured dictionary into a header value.
+///
+/// Members with a value of `?1` are serialized as just their key and
+/// parameters.
+///
+/// # Errors
+///
+/// An error with status `500: Internal Server Error` is returned if any of
+/// the keys or values can't be represented.
+pub fn serialize_dictionary(dict: &Dictionary) -> crate::Result<String> {
+    let mut output = String::new();
+    for (i, (key, member)) in dict.iter().enumerate() {
+        if i > 0 {
+            output.push_str(", ");
+        }
+        write_key(&mut output, key)?;
+        match member {
+            Member::Item(item) if item.value == BareItem::Boolean(true) => {
+                write_parameters(&mut output, &item.params)?;
+            }
+            member => {
+                output.push('=');
+                write_member(&mut output, member)?;
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// Serialize a structured item into a header value.
+///
+/// # Errors
+///
+/// An error with status `500: Internal Server Error` is returned if the
+/// value or any of its parameters can't be represented.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::structured::{self, BareItem, Item};
+///
+/// let mut item = Item::new(BareItem::Decimal(0.25));
+/// item.params.insert("unit", BareItem::String("s".into()));
+/// assert_eq!(structured::serialize_item(&item)?, r#"0.25;unit="s""#);
+/// #
+/// # Ok(()) }
+/// ```
+pub fn serialize_item(item: &Item) -> crate::Result<String> {
+    let mut output = String::new();
+    write_item(&mut output, item)?;
+    Ok(output)
+}
+
+fn write_member(output: &mut String, member: &Member) -> crate::Result<()> {
+    match member {
+        Member::Item(item) => write_item(output, item),
+        Member::InnerList(list) => write_inner_list(output, list),
+    }
+}
+
+fn write_inner_list(output: &mut String, list: &InnerList) -> crate::Result<()> {
+    output.push('(');
+    for (i, item) in list.items.iter().enumerate() {
+        if i > 0 {
+            output.push(' ');
+        }
+        write_item(output, item)?;
+    }
+    output.push(')');
+    write_parameters(output, &list.params)
+}
+
+fn write_item(output: &mut String, item: &Item) -> crate::Result<()> {
+    write_bare_item(output, &item.value)?;
+    write_parameters(output, &item.params)
+}
+
+fn write_parameters(output: &mut String, params: &Parameters) -> crate::Result<()> {
+    for (key, value) in params {
+        output.push(';');
+        write_key(output, key)?;
+        if *value != BareItem::Boolean(true) {
+            output.push('=');
+            write_bare_item(output, value)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_key(output: &mut String, key: &str) -> crate::Result<()> {
+    let mut bytes = key.bytes();
+    let valid = matches!(bytes.next(), Some(b'a'..=b'z') | Some(b'*'))
+        && bytes.all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-' | b'.' | b'*'));
+    if !valid {
+        bail!(500, "invalid structured field key: {:?}", key);
+    }
+    output.push_str(key);
+    Ok(())
+}
+
+fn write_bare_item(output: &mut String, value: &BareItem) -> crate::Result<()> {
+    match value {
+        BareItem::Integer(n) => {
+            if n.unsigned_abs() > 999_999_999_999_999 {
+                bail!(500, "structured field integer out of range: {}", n);
+            }
+            write!(output, "{}", n).unwrap();
+        }
+        BareItem::Decimal(n) => {
+            let rounded = (n * 1000.0).round() / 1000.0;
+            if !rounded.is_finite() || rounded.abs().trunc() > 999_999_999_999.0 {
+                bail!(500, "structured field decimal out of range: {}", n);
+            }
+            let formatted = format!("{:.3}", rounded);
+            let trimmed = formatted.trim_end_matches('0');
+            // Always keep at least one fractional digit.
+            output.push_str(trimmed);
+            if trimmed.ends_with('.') {
+                output.push('0');
+            }
+        }
+        BareItem::String(s) => {
+            output.push('"');
+            for c in s.chars() {
+                match c {
+                    '"' | '\\' => {
+                        output.push('\\');
+                        output.push(c);
+                    }
+                    ' '..='~' => output.push(c),
+                    _ => bail!(500, "invalid character in structured field string"),
+                }
+            }
+            output.push('"');
+        }
+        BareItem::Token(s) => {
+            let mut bytes = s.bytes();
+            let valid = matches!(bytes.next(), Some(b) if b.is_ascii_alphabetic() || b == b'*')
+                && bytes.all(|b| is_tchar(b) || b == b':' || b == b'/');
+            if !valid {
+                bail!(500, "invalid structured field token: {:?}", s);
+            }
+            output.push_str(s);
+        }
+        BareItem::ByteSequence(bytes) => {
+            output.push(':');
+            output.push_str(&base64::encode(bytes));
+            output.push(':');
+        }
+        BareItem::Boolean(b) => output.push_str(if *b { "?1" } else { "?0" }),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::structured::{parse_dictionary, parse_list};
+
+    #[test]
+    fn bare_items() -> crate::Result<()> {
+        let cases = [
+            (BareItem::Integer(-42), "-42"),
+            (BareItem::Decimal(1.0), "1.0"),
+            (BareItem::Decimal(0.12345), "0.123"),
+            (BareItem::Decimal(-2.5), "-2.5"),
+            (BareItem::String(r#"a "b" \c"#.into()), r#""a \"b\" \\c""#),
+            (BareItem::Token("*foo:bar/baz".into()), "*foo:bar/baz"),
+            (BareItem::ByteSequence(b"hello".to_vec()), ":aGVsbG8=:"),
+            (BareItem::Boolean(false), "?0"),
+        ];
+        for (value, expected) in cases.iter() {
+            assert_eq!(serialize_item(&Item::new(value.clone()))?, *expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip() -> crate::Result<()> {
+        let input = r#"a=(1 2);x, b;y="z", c=?0"#;
+        assert_eq!(serialize_dictionary(&parse_dictionary(input)?)?, input);
+        let input = r#"sugar;q=0.5, ("tea" :AAE=:);lvl=2"#;
+        assert_eq!(serialize_list(&parse_list(input)?)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for value in &[
+            BareItem::Integer(1_000_000_000_000_000),
+            BareItem::Decimal(1e12),
+            BareItem::Decimal(f64::NAN),
+            BareItem::String("tab\t".into()),
+            BareItem::Token("1abc".into()),
+            BareItem::Token("".into()),
+        ] {
+            let err = serialize_item(&Item::new(value.clone())).unwrap_err();
+            assert_eq!(err.status(), 500, "{:?}", value);
+        }
+
+        let mut dict = Dictionary::new();
+        dict.insert("Upper", Item::new(BareItem::Integer(1)));
+        assert_eq!(serialize_dictionary(&dict).unwrap_err().status(), 500);
+    }
+}
@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use crate::headers::HeaderValue;
+use crate::parse_utils::parse_token;
 
 /// An individual entry into `ServerTiming`.
 //
@@ -26,9 +27,13 @@ impl Metric {
     ///
     /// # Errors
     ///
-    /// An error will be returned if the string values are invalid ASCII.
+    /// An error will be returned if the name is not a valid token, or if the
+    /// description is invalid ASCII.
     pub fn new(name: String, dur: Option<Duration>, desc: Option<String>) -> crate::Result<Self> {
-        crate::ensure!(name.is_ascii(), "Name should be valid ASCII");
+        crate::ensure!(
+            matches!(parse_token(&name), (Some(_), "")),
+            "Name should be a valid token"
+        );
         if let Some(desc) = desc.as_ref() {
             crate::ensure!(desc.is_ascii(), "Description should be valid ASCII");
         };
@@ -56,18 +61,22 @@ impl From<Metric> for HeaderValue {
     fn from(entry: Metric) -> HeaderValue {
         let mut string = entry.name;
 
-        // Format a `Duration` into the format that the spec expects.
-        let f = |d: Duration| d.as_secs_f64() * 1000.0;
-
-        match (entry.dur, entry.desc) {
-            (Some(dur), Some(desc)) => {
-                string.push_str(&format!("; dur={}; desc=\"{}\"", f(dur), desc))
+        // Format a `Duration` as milliseconds, rounded to at most one decimal.
+        let f = |d: Duration| {
+            let tenths = (d.as_micros() + 50) / 100;
+            match tenths % 10 {
+                0 => format!("{}", tenths / 10),
+                frac => format!("{}.{}", tenths / 10, frac),
             }
-            (Some(dur), None) => string.push_str(&format!("; dur={}", f(dur))),
-            (None, Some(desc)) => string.push_str(&format!("; desc=\"{}\"", desc)),
-            (None, None) => {}
         };
 
+        if let Some(dur) = entry.dur {
+            string.push_str(&format!(";dur={}", f(dur)));
+        }
+        if let Some(desc) = entry.desc {
+            string.push_str(&format!(";desc=\"{}\"", desc));
+        }
+
         // SAFETY: we validate that the values are valid ASCII on creation.
         unsafe { HeaderValue::from_bytes_unchecked(string.into_bytes()) }
     }
@@ -90,13 +99,17 @@ mod test {
         assert_eq!(val, "Server");
 
         let val: HeaderValue = Metric::new(name.clone(), Some(dur), None)?.into();
-        assert_eq!(val, "Server; dur=1000");
+        assert_eq!(val, "Server;dur=1000");
 
         let val: HeaderValue = Metric::new(name.clone(), None, Some(desc.clone()))?.into();
-        assert_eq!(val, r#"Server; desc="A server timing""#);
+        assert_eq!(val, r#"Server;desc="A server timing""#);
 
         let val: HeaderValue = Metric::new(name.clone(), Some(dur), Some(desc.clone()))?.into();
-        assert_eq!(val, r#"Server; dur=1000; desc="A server timing""#);
+        assert_eq!(val, r#"Server;dur=1000;desc="A server timing""#);
+
+        let dur = Duration::from_micros(1_250);
+        let val: HeaderValue = Metric::new(name.clone(), Some(dur), None)?.into();
+        assert_eq!(val, "Server;dur=1.3");
         Ok(())
     }
 }
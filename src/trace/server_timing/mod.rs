@@ -31,6 +31,7 @@ use std::fmt::Write;
 use std::iter::Iterator;
 
 use std::slice;
+use std::time::Duration;
 
 use crate::headers::{Header, HeaderName, HeaderValue, Headers, SERVER_TIMING};
 
@@ -85,11 +86,52 @@ impl ServerTiming {
         Ok(Some(Self { timings }))
     }
 
+    /// Create a new metric and push it into the list of entries.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the name is not a valid token, or if the
+    /// description is invalid ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::Response;
+    /// use http_types::trace::ServerTiming;
+    /// use std::time::Duration;
+    ///
+    /// let mut timings = ServerTiming::new();
+    /// timings.push_timing("db", Some(Duration::from_millis(53)), None)?;
+    /// timings.push_timing("cache", None, Some("Cache Read".into()))?;
+    ///
+    /// let mut res = Response::new(200);
+    /// timings.apply(&mut res);
+    /// assert_eq!(res["Server-Timing"], r#"db;dur=53, cache;desc="Cache Read""#);
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn push_timing(
+        &mut self,
+        name: impl Into<String>,
+        dur: Option<Duration>,
+        desc: Option<String>,
+    ) -> crate::Result<()> {
+        self.push(Metric::new(name.into(), dur, desc)?);
+        Ok(())
+    }
+
     /// Push an entry into the list of entries.
     pub fn push(&mut self, entry: Metric) {
         self.timings.push(entry);
     }
 
+    /// Sets the `Server-Timing` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
     /// An iterator visiting all server timings.
     pub fn iter(&self) -> Iter<'_> {
         Iter {
@@ -247,6 +289,41 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn exact_output() -> crate::Result<()> {
+        let mut timings = ServerTiming::new();
+        timings.push_timing("db", Some(Duration::from_millis(53)), None)?;
+        timings.push_timing(
+            "app",
+            Some(Duration::from_micros(47_213)),
+            Some("App".into()),
+        )?;
+
+        let mut headers = Headers::new();
+        timings.apply(&mut headers);
+        assert_eq!(
+            headers[SERVER_TIMING],
+            r#"db;dur=53, app;dur=47.2;desc="App""#
+        );
+
+        let timings = ServerTiming::from_headers(headers)?.unwrap();
+        let mut iter = timings.iter();
+        assert_eq!(
+            iter.next().unwrap().duration(),
+            Some(Duration::from_millis(53))
+        );
+        assert_eq!(iter.next().unwrap().description(), Some("App"));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_name() {
+        let mut timings = ServerTiming::new();
+        assert!(timings.push_timing("db query", None, None).is_err());
+        assert!(timings.push_timing("db;dur=1", None, None).is_err());
+        assert!(timings.push_timing("", None, None).is_err());
+    }
+
     #[test]
     fn bad_request_on_parse_error() {
         let mut headers = Headers::new();
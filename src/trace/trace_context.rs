@@ -1,6 +1,7 @@
 use std::fmt;
 
-use crate::headers::{Header, HeaderName, HeaderValue, Headers, TRACEPARENT};
+use crate::bail_status as bail;
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, TRACEPARENT, TRACESTATE};
 use crate::Status;
 
 /// Extract and apply [Trace-Context](https://w3c.github.io/trace-context/) headers.
@@ -41,6 +42,7 @@ pub struct TraceContext {
     trace_id: u128,
     parent_id: Option<u64>,
     flags: u8,
+    state: Option<String>,
 }
 
 impl TraceContext {
@@ -65,15 +67,19 @@ impl TraceContext {
             trace_id: fastrand::u128(..),
             parent_id: None,
             flags: 1,
+            state: None,
         }
     }
 
     /// Create and return TraceContext object based on `traceparent` HTTP header.
     ///
+    /// The `tracestate` header is carried along as-is if present.
+    ///
     /// # Errors
     ///
-    /// This function may error if the header is malformed. An error with a
-    /// status code of `400: Bad Request` will be generated.
+    /// This function may error if the header is malformed, or if either the
+    /// trace id or parent id consist of only zeroes. An error with a status
+    /// code of `400: Bad Request` will be generated.
     ///
     /// # Examples
     ///
@@ -107,16 +113,74 @@ impl TraceContext {
             None => return Ok(None),
         };
         let parts: Vec<&str> = traceparent.as_str().split('-').collect();
+        if parts.len() < 4 {
+            bail!(400, "Invalid traceparent header");
+        }
+
+        let version = u8::from_str_radix(parts[0], 16).status(400)?;
+        let trace_id = u128::from_str_radix(parts[1], 16).status(400)?;
+        let parent_id = u64::from_str_radix(parts[2], 16).status(400)?;
+        let flags = u8::from_str_radix(parts[3], 16).status(400)?;
+
+        // Only version 00 has a fixed number of fields; later versions may append more.
+        if version == 0xff || (version == 0 && parts.len() != 4) {
+            bail!(400, "Invalid traceparent version");
+        }
+        if trace_id == 0 || parent_id == 0 {
+            bail!(400, "traceparent ids may not be all zeroes");
+        }
+
+        let state = headers.get(TRACESTATE).map(|values| {
+            values
+                .iter()
+                .map(|value| value.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        });
 
         Ok(Some(Self {
             id: fastrand::u64(..),
-            version: u8::from_str_radix(parts[0], 16)?,
-            trace_id: u128::from_str_radix(parts[1], 16).status(400)?,
-            parent_id: Some(u64::from_str_radix(parts[2], 16).status(400)?),
-            flags: u8::from_str_radix(parts[3], 16).status(400)?,
+            version,
+            trace_id,
+            parent_id: Some(parent_id),
+            flags,
+            state,
         }))
     }
 
+    /// Sets the `traceparent` header, and the `tracestate` header if there is
+    /// any vendor-specific state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn main() -> http_types::Result<()> {
+    /// #
+    /// use http_types::trace::TraceContext;
+    /// use http_types::{Method, Request, Response};
+    ///
+    /// let mut res = Response::new(200);
+    /// res.insert_header("traceparent", "00-0af7651916cd43dd8448eb211c80319c-00f067aa0ba902b7-01")?;
+    /// res.insert_header("tracestate", "congo=t61rcWkgMzE")?;
+    /// let context = TraceContext::from_headers(&res)?.unwrap();
+    ///
+    /// let mut req = Request::new(Method::Get, "https://example.com");
+    /// context.child().apply(&mut req);
+    /// assert!(req["traceparent"].as_str().starts_with("00-0af7651916cd43dd8448eb211c80319c-"));
+    /// assert_eq!(req["tracestate"], "congo=t61rcWkgMzE");
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        self.apply_header(&mut *headers);
+        if let Some(state) = &self.state {
+            // SAFETY: the internal string is validated to be ASCII.
+            let value = unsafe { HeaderValue::from_bytes_unchecked(state.clone().into()) };
+            headers.insert(TRACESTATE, value).unwrap();
+        }
+    }
+
     /// Generate a child of the current TraceContext and return it.
     ///
     /// The child will have a new randomly genrated `id` and its `parent_id` will be set to the
@@ -128,6 +192,7 @@ impl TraceContext {
             trace_id: self.trace_id,
             parent_id: Some(self.id),
             flags: self.flags,
+            state: self.state.clone(),
         }
     }
 
@@ -156,6 +221,18 @@ impl TraceContext {
         self.parent_id
     }
 
+    /// Return the vendor-specific `tracestate` of the TraceContext, if any.
+    pub fn tracestate(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// Set the vendor-specific `tracestate`.
+    ///
+    /// The state must be valid ASCII.
+    pub fn set_tracestate(&mut self, state: Option<String>) {
+        self.state = state;
+    }
+
     /// Returns true if the trace is sampled
     ///
     /// # Examples
@@ -259,4 +336,54 @@ mod test {
         assert!(context.sampled());
         Ok(())
     }
+
+    #[test]
+    fn canonical_example() -> crate::Result<()> {
+        let mut headers = crate::headers::Headers::new();
+        headers.insert(
+            TRACEPARENT,
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        )?;
+        headers.insert(TRACESTATE, "rojo=00f067aa0ba902b7,congo=t61rcWkgMzE")?;
+        let context = TraceContext::from_headers(&headers)?.unwrap();
+        assert_eq!(context.trace_id(), 0x4bf92f3577b34da6a3ce929d0e0e4736);
+        assert_eq!(context.parent_id(), Some(0x00f067aa0ba902b7));
+        assert!(context.sampled());
+
+        let child = context.child();
+        assert_eq!(child.parent_id(), Some(context.id()));
+
+        let mut headers = crate::headers::Headers::new();
+        child.apply(&mut headers);
+        assert_eq!(
+            headers[TRACEPARENT],
+            format!("00-4bf92f3577b34da6a3ce929d0e0e4736-{:016x}-01", child.id())
+        );
+        assert_eq!(
+            headers[TRACESTATE],
+            "rojo=00f067aa0ba902b7,congo=t61rcWkgMzE"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bad_request_on_zero_ids() {
+        for value in &[
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+        ] {
+            let mut headers = crate::headers::Headers::new();
+            headers.insert(TRACEPARENT, *value).unwrap();
+            let err = TraceContext::from_headers(&headers).unwrap_err();
+            assert_eq!(err.status(), 400);
+        }
+    }
+
+    #[test]
+    fn bad_request_on_parse_error() {
+        let mut headers = crate::headers::Headers::new();
+        headers.insert(TRACEPARENT, "00-01").unwrap();
+        let err = TraceContext::from_headers(&headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
 }
@@ -24,22 +24,22 @@
 //! #
 //! use http_types::{Url, Method, Request};
 //! use http_types::trailers::Trailers;
-//! use http_types::headers::{HeaderName, HeaderValue};
-//! use async_std::task;
-//! use std::str::FromStr;
 //!
 //! let mut req = Request::new(Method::Get, Url::parse("https://example.com").unwrap());
+//! req.set_body("hello world");
 //!
 //! let sender = req.send_trailers();
+//! let receiver = req.recv_trailers();
+//!
+//! // Trailers are sent once the body has been fully written.
+//! let body = req.take_body().into_string().await?;
 //! let mut trailers = Trailers::new();
 //! trailers.insert("Content-Type", "text/plain");
-//!
-//! task::spawn(async move {
-//!     let trailers = req.recv_trailers().await;
-//!     # drop(trailers)
-//! });
-//!
 //! sender.send(trailers).await;
+//!
+//! let trailers = receiver.await.unwrap();
+//! assert_eq!(body, "hello world");
+//! assert_eq!(trailers["Content-Type"], "text/plain");
 //! #
 //! # Ok(()) })}
 //! ```
@@ -0,0 +1,270 @@
+use futures_lite::{io, prelude::*, ready};
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::trailers::{Receiver, Trailers};
+use crate::Body;
+
+/// The maximum number of bytes of the body framed as a single chunk.
+const MAX_CHUNK_SIZE: usize = 8 * 1024;
+
+/// A reader which frames a body using chunked transfer-encoding.
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 4.1: Chunked Transfer Coding](https://tools.ietf.org/html/rfc7230#section-4.1)
+#[derive(Debug)]
+pub(crate) struct ChunkedEncoder {
+    inner: Body,
+    /// Scratch space the next chunk of the body is read into.
+    chunk: Vec<u8>,
+    /// The framed output which hasn't been read yet.
+    framed: Vec<u8>,
+    position: usize,
+    done: bool,
+}
+
+impl ChunkedEncoder {
+    /// Wrap a body, framing its contents as chunks.
+    pub(crate) fn new(inner: Body) -> Self {
+        Self {
+            inner,
+            chunk: vec![0; MAX_CHUNK_SIZE],
+            framed: Vec::with_capacity(MAX_CHUNK_SIZE + 12),
+            position: 0,
+            done: false,
+        }
+    }
+}
+
+impl AsyncRead for ChunkedEncoder {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        if this.position == this.framed.len() {
+            if this.done {
+                return Poll::Ready(Ok(0));
+            }
+
+            let bytes = ready!(Pin::new(&mut this.inner).poll_read(cx, &mut this.chunk))?;
+            this.framed.clear();
+            this.position = 0;
+            if bytes == 0 {
+                // The last chunk, without any trailers.
+                this.framed.extend_from_slice(b"0\r\n\r\n");
+                this.done = true;
+            } else {
+                this.framed
+                    .extend_from_slice(format!("{:X}\r\n", bytes).as_bytes());
+                this.framed.extend_from_slice(&this.chunk[..bytes]);
+                this.framed.extend_from_slice(b"\r\n");
+            }
+        }
+
+        let len = buf.len().min(this.framed.len() - this.position);
+        buf[..len].copy_from_slice(&this.framed[this.position..this.position + len]);
+        this.position += len;
+        Poll::Ready(Ok(len))
+    }
+}
+
+/// The maximum length of a chunk-size or trailer line.
+const MAX_LINE_LEN: usize = 8 * 1024;
+
+/// The maximum combined length of the trailers.
+const MAX_TRAILERS_LEN: usize = 64 * 1024;
+
+/// The state of a `ChunkedDecoder`.
+#[derive(Debug)]
+enum DecodeState {
+    /// Reading the chunk-size line.
+    Size,
+    /// Reading the chunk data, of which the given number of bytes is left.
+    Data(u64),
+    /// Reading the CRLF which follows the chunk data.
+    DataEnd,
+    /// Reading the trailers which follow the last chunk.
+    Trailers,
+    /// The last chunk and trailers have been read.
+    Done,
+}
+
+/// A reader which decodes a body framed using chunked transfer-encoding.
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 4.1: Chunked Transfer Coding](https://tools.ietf.org/html/rfc7230#section-4.1)
+#[derive(Debug)]
+pub(crate) struct ChunkedDecoder<R> {
+    inner: R,
+    state: DecodeState,
+    /// The line read so far.
+    line: Vec<u8>,
+    trailers: Trailers,
+    trailers_len: usize,
+    sender: Option<async_channel::Sender<Trailers>>,
+}
+
+impl<R: AsyncBufRead + Unpin> ChunkedDecoder<R> {
+    /// Wrap a reader, and return it along with a receiver for the trailers.
+    ///
+    /// The trailers are sent once the last chunk has been read.
+    pub(crate) fn new(inner: R) -> (Self, Receiver) {
+        let (sender, receiver) = async_channel::bounded(1);
+        let decoder = Self {
+            inner,
+            state: DecodeState::Size,
+            line: Vec::new(),
+            trailers: Trailers::new(),
+            trailers_len: 0,
+            sender: Some(sender),
+        };
+        (decoder, Receiver::new(receiver))
+    }
+
+    /// Read a line, without its line ending.
+    fn poll_line(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Vec<u8>>> {
+        loop {
+            let available = ready!(Pin::new(&mut self.inner).poll_fill_buf(cx))?;
+            if available.is_empty() {
+                return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+            }
+
+            let (used, found) = match available.iter().position(|&b| b == b'\n') {
+                Some(i) => (i + 1, true),
+                None => (available.len(), false),
+            };
+            if self.line.len() + used > MAX_LINE_LEN {
+                return Poll::Ready(Err(invalid_data("chunked line too long")));
+            }
+            self.line.extend_from_slice(&available[..used]);
+            Pin::new(&mut self.inner).consume(used);
+
+            if found {
+                let mut line = std::mem::take(&mut self.line);
+                line.pop();
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+                return Poll::Ready(Ok(line));
+            }
+        }
+    }
+
+    /// Parse a trailer field, and add it to the trailers.
+    fn push_trailer(&mut self, line: &[u8]) -> io::Result<()> {
+        self.trailers_len += line.len();
+        if self.trailers_len > MAX_TRAILERS_LEN {
+            return Err(invalid_data("chunked trailers too long"));
+        }
+        let line = std::str::from_utf8(line).map_err(|_| invalid_data("invalid trailer"))?;
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| invalid_data("invalid trailer"))?;
+        self.trailers
+            .append(name.trim(), value.trim())
+            .map_err(|_| invalid_data("invalid trailer"))
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncRead for ChunkedDecoder<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        loop {
+            match this.state {
+                DecodeState::Size => {
+                    let line = ready!(this.poll_line(cx))?;
+                    let size = parse_chunk_size(&line)?;
+                    this.state = match size {
+                        0 => DecodeState::Trailers,
+                        size => DecodeState::Data(size),
+                    };
+                }
+                DecodeState::Data(remaining) => {
+                    if buf.is_empty() {
+                        return Poll::Ready(Ok(0));
+                    }
+                    let available = ready!(Pin::new(&mut this.inner).poll_fill_buf(cx))?;
+                    if available.is_empty() {
+                        return Poll::Ready(Err(io::ErrorKind::UnexpectedEof.into()));
+                    }
+                    let len = (remaining.min(buf.len() as u64) as usize).min(available.len());
+                    buf[..len].copy_from_slice(&available[..len]);
+                    Pin::new(&mut this.inner).consume(len);
+
+                    let remaining = remaining - len as u64;
+                    this.state = match remaining {
+                        0 => DecodeState::DataEnd,
+                        remaining => DecodeState::Data(remaining),
+                    };
+                    return Poll::Ready(Ok(len));
+                }
+                DecodeState::DataEnd => {
+                    let line = ready!(this.poll_line(cx))?;
+                    if !line.is_empty() {
+                        return Poll::Ready(Err(invalid_data("missing CRLF after chunk data")));
+                    }
+                    this.state = DecodeState::Size;
+                }
+                DecodeState::Trailers => {
+                    let line = ready!(this.poll_line(cx))?;
+                    if !line.is_empty() {
+                        this.push_trailer(&line)?;
+                        continue;
+                    }
+                    this.state = DecodeState::Done;
+                    if let Some(sender) = this.sender.take() {
+                        let trailers = std::mem::replace(&mut this.trailers, Trailers::new());
+                        // The channel is bounded to one, so this can't be full. If the
+                        // receiver has been dropped nobody is interested in the trailers.
+                        let _ = sender.try_send(trailers);
+                    }
+                }
+                DecodeState::Done => return Poll::Ready(Ok(0)),
+            }
+        }
+    }
+}
+
+/// Parse a chunk-size line, ignoring any chunk extensions.
+fn parse_chunk_size(line: &[u8]) -> io::Result<u64> {
+    let line = std::str::from_utf8(line).map_err(|_| invalid_data("invalid chunk size"))?;
+    let size = match line.split_once(';') {
+        Some((size, _extensions)) => size,
+        None => line,
+    };
+    let size = size.trim_matches(|c| c == ' ' || c == '\t');
+    if size.is_empty() || !size.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid_data("invalid chunk size"));
+    }
+    u64::from_str_radix(size, 16).map_err(|_| invalid_data("chunk size too large"))
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chunk_sizes() -> io::Result<()> {
+        assert_eq!(parse_chunk_size(b"1a")?, 26);
+        assert_eq!(parse_chunk_size(b"1A ; name=value")?, 26);
+        assert_eq!(parse_chunk_size(b"0")?, 0);
+        assert!(parse_chunk_size(b"").is_err());
+        assert!(parse_chunk_size(b"-1").is_err());
+        assert!(parse_chunk_size(b"0x1a").is_err());
+        assert!(parse_chunk_size(b"11111111111111111").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use crate::headers::{Header, HeaderName, HeaderValue, Headers, CONNECTION};
+
+use std::fmt::{self, Debug, Write};
+use std::iter::Iterator;
+use std::slice;
+use std::str::FromStr;
+
+/// Control options for the current connection.
+///
+/// Besides the `close` and `keep-alive` options, the header lists the names of
+/// hop-by-hop headers which must be removed before a message is forwarded.
+/// Options are compared case-insensitively.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Connection)
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 6.1: Connection](https://tools.ietf.org/html/rfc7230#section-6.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Request;
+/// use http_types::transfer::Connection;
+///
+/// let mut req = Request::get("https://example.com");
+/// req.insert_header("Connection", "keep-alive, Upgrade")?;
+///
+/// let connection = Connection::from_headers(req)?.unwrap();
+/// assert!(connection.is_keep_alive());
+/// assert!(!connection.is_close());
+/// assert!(connection.contains("upgrade"));
+/// #
+/// # Ok(()) }
+/// ```
+pub struct Connection {
+    options: Vec<HeaderName>,
+}
+
+impl Connection {
+    /// Create a new instance of `Connection`.
+    pub fn new() -> Self {
+        Self { options: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let mut connection = Self::new();
+        let headers = match headers.as_ref().get(CONNECTION) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        for value in headers {
+            for part in value.as_str().split(',') {
+                let part = part.trim();
+                if part.is_empty() {
+                    continue;
+                }
+                let option = HeaderName::from_str(part).map_err(|mut err| {
+                    err.set_status(400);
+                    err
+                })?;
+                connection.push(option);
+            }
+        }
+
+        Ok(Some(connection))
+    }
+
+    /// Sets the `Connection` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Push an option into the list of options.
+    ///
+    /// Options which are already listed are ignored.
+    pub fn push(&mut self, option: impl Into<HeaderName>) {
+        let option = option.into();
+        if !self.options.contains(&option) {
+            self.options.push(option);
+        }
+    }
+
+    /// Returns `true` if the header lists the given option.
+    pub fn contains(&self, option: impl AsRef<str>) -> bool {
+        let option = option.as_ref();
+        self.options
+            .iter()
+            .any(|name| name.as_str().eq_ignore_ascii_case(option))
+    }
+
+    /// Returns `true` if the connection should be closed after the current
+    /// message.
+    pub fn is_close(&self) -> bool {
+        self.contains("close")
+    }
+
+    /// Returns `true` if the connection should be kept open after the
+    /// current message.
+    pub fn is_keep_alive(&self) -> bool {
+        self.contains("keep-alive")
+    }
+
+    /// An iterator visiting all options.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.options.iter(),
+        }
+    }
+}
+
+impl Header for Connection {
+    fn header_name(&self) -> HeaderName {
+        CONNECTION
+    }
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, option) in self.options.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", option).unwrap(),
+                _ => write!(output, ", {}", option).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Connection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Connection {
+    type Item = &'a HeaderName;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A lending iterator over options in `Connection`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, HeaderName>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a HeaderName;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for option in &self.options {
+            list.entry(option);
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{Headers, UPGRADE};
+
+    #[test]
+    fn keep_alive() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(CONNECTION, "Keep-Alive")?;
+
+        let connection = Connection::from_headers(headers)?.unwrap();
+        assert!(connection.is_keep_alive());
+        assert!(!connection.is_close());
+        Ok(())
+    }
+
+    #[test]
+    fn close() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(CONNECTION, "close")?;
+
+        let connection = Connection::from_headers(headers)?.unwrap();
+        assert!(connection.is_close());
+        assert!(!connection.is_keep_alive());
+        Ok(())
+    }
+
+    #[test]
+    fn hop_by_hop_list() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        headers.insert(CONNECTION, "keep-alive, Upgrade")?;
+        headers.append(CONNECTION, "x-custom,,")?;
+
+        let connection = Connection::from_headers(headers)?.unwrap();
+        assert!(connection.contains("UPGRADE"));
+        assert!(connection.contains("X-Custom"));
+        assert!(!connection.contains("close"));
+
+        let names: Vec<_> = connection.iter().map(|name| name.as_str()).collect();
+        assert_eq!(names, ["keep-alive", "upgrade", "x-custom"]);
+        assert_eq!(connection.iter().nth(1), Some(&UPGRADE));
+        Ok(())
+    }
+
+    #[test]
+    fn apply() {
+        let mut connection = Connection::new();
+        connection.push("Upgrade");
+        connection.push("upgrade");
+        connection.push(CONNECTION);
+
+        let mut headers = Headers::new();
+        connection.apply(&mut headers);
+        assert_eq!(headers[CONNECTION], "upgrade, connection");
+    }
+}
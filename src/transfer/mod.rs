@@ -2,12 +2,19 @@
 //!
 //! [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers#Transfer_coding)
 
+mod chunked;
+mod connection;
 mod encoding;
 mod encoding_proposal;
 mod te;
+mod trailer;
 mod transfer_encoding;
 
+pub use connection::Connection;
 pub use encoding::Encoding;
 pub use encoding_proposal::EncodingProposal;
 pub use te::TE;
+pub use trailer::Trailer;
 pub use transfer_encoding::TransferEncoding;
+
+pub(crate) use chunked::{ChunkedDecoder, ChunkedEncoder};
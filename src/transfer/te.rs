@@ -39,6 +39,7 @@ use std::slice;
 /// ```
 pub struct TE {
     wildcard: bool,
+    trailers: bool,
     entries: Vec<EncodingProposal>,
 }
 
@@ -48,6 +49,7 @@ impl TE {
         Self {
             entries: vec![],
             wildcard: false,
+            trailers: false,
         }
     }
 
@@ -60,6 +62,7 @@ impl TE {
         };
 
         let mut wildcard = false;
+        let mut trailers = false;
 
         for value in headers {
             for part in value.as_str().trim().split(',') {
@@ -71,6 +74,9 @@ impl TE {
                 } else if part == "*" {
                     wildcard = true;
                     continue;
+                } else if part.eq_ignore_ascii_case("trailers") {
+                    trailers = true;
+                    continue;
                 }
 
                 // Try and parse a directive from a str. If the directive is
@@ -81,7 +87,11 @@ impl TE {
             }
         }
 
-        Ok(Some(Self { wildcard, entries }))
+        Ok(Some(Self {
+            wildcard,
+            trailers,
+            entries,
+        }))
     }
 
     /// Push a directive into the list of entries.
@@ -99,6 +109,32 @@ impl TE {
         self.wildcard = wildcard
     }
 
+    /// Returns `true` if the client is willing to accept trailer fields.
+    ///
+    /// Servers should only send trailers if this returns `true`.
+    pub fn accepts_trailers(&self) -> bool {
+        self.trailers
+    }
+
+    /// Set whether trailer fields are accepted.
+    pub fn set_trailers(&mut self, trailers: bool) {
+        self.trailers = trailers
+    }
+
+    /// Returns `true` if the client is willing to accept the encoding.
+    ///
+    /// The `chunked` encoding is always accepted, and encodings with a weight
+    /// of `0` are refused.
+    pub fn accepts(&self, encoding: Encoding) -> bool {
+        if encoding == Encoding::Chunked {
+            return true;
+        }
+        match self.entries.iter().find(|entry| **entry == encoding) {
+            Some(entry) => entry.weight() != Some(0.0),
+            None => self.wildcard,
+        }
+    }
+
     /// Sort the header directives by weight.
     ///
     /// Headers with a higher `q=` value will be returned first. If two
@@ -173,6 +209,13 @@ impl Header for TE {
             }
         }
 
+        if self.trailers {
+            match output.len() {
+                0 => write!(output, "trailers").unwrap(),
+                _ => write!(output, ", trailers").unwrap(),
+            }
+        }
+
         // SAFETY: the internal string is validated to be ASCII.
         unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
     }
@@ -324,6 +367,36 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn trailers() -> crate::Result<()> {
+        let mut headers = Response::new(200);
+        headers.insert_header("TE", "trailers")?;
+
+        let te = TE::from_headers(headers)?.unwrap();
+        assert!(te.accepts_trailers());
+        assert!(te.accepts(Encoding::Chunked));
+        assert!(!te.accepts(Encoding::Gzip));
+        assert_eq!(te.iter().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn trailers_and_encodings() -> crate::Result<()> {
+        let mut headers = Response::new(200);
+        headers.insert_header("TE", "gzip;q=1.0, deflate;q=0, trailers")?;
+
+        let te = TE::from_headers(&headers)?.unwrap();
+        assert!(te.accepts_trailers());
+        assert!(te.accepts(Encoding::Gzip));
+        assert!(!te.accepts(Encoding::Deflate));
+        assert!(!te.accepts(Encoding::Brotli));
+        assert_eq!(te.iter().next().unwrap().weight(), Some(1.0));
+
+        te.apply_header(&mut headers);
+        assert_eq!(headers["TE"], "gzip;q=1.000, deflate;q=0.000, trailers");
+        Ok(())
+    }
+
     #[test]
     fn iter() -> crate::Result<()> {
         let mut accept = TE::new();
@@ -0,0 +1,252 @@
+use crate::bail_status as bail;
+use crate::headers::{self, Header, HeaderName, HeaderValue, Headers};
+
+use std::fmt::{self, Debug, Write};
+use std::iter::Iterator;
+use std::slice;
+use std::str::FromStr;
+
+/// Header fields which must not be sent as trailers, because they are needed
+/// before the body can be processed.
+const FORBIDDEN: &[HeaderName] = &[
+    // Message framing.
+    headers::CONTENT_LENGTH,
+    headers::TRANSFER_ENCODING,
+    headers::TRAILER,
+    // Routing.
+    headers::HOST,
+    // Request modifiers.
+    headers::CACHE_CONTROL,
+    headers::EXPECT,
+    headers::IF_MATCH,
+    headers::IF_MODIFIED_SINCE,
+    headers::IF_NONE_MATCH,
+    headers::IF_RANGE,
+    headers::IF_UNMODIFIED_SINCE,
+    headers::MAX_FORWARDS,
+    headers::PRAGMA,
+    headers::RANGE,
+    headers::TE,
+    // Authentication.
+    headers::AUTHORIZATION,
+    headers::PROXY_AUTHENTICATE,
+    headers::PROXY_AUTHORIZATION,
+    headers::WWW_AUTHENTICATE,
+    headers::COOKIE,
+    headers::SET_COOKIE,
+    // Content format.
+    headers::CONTENT_ENCODING,
+    headers::CONTENT_RANGE,
+    headers::CONTENT_TYPE,
+];
+
+/// Declares the header fields which will be sent as trailers.
+///
+/// Fields needed to process the message, such as those describing framing,
+/// routing, or authentication, are not allowed as trailers.
+///
+/// [MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Trailer)
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 4.1.2: Chunked Trailer Part](https://tools.ietf.org/html/rfc7230#section-4.1.2)
+/// - [RFC 7230, section 4.4: Trailer](https://tools.ietf.org/html/rfc7230#section-4.4)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::Response;
+/// use http_types::transfer::Trailer;
+///
+/// let mut trailer = Trailer::new();
+/// trailer.push("Expires")?;
+/// trailer.push("Server-Timing")?;
+/// assert!(trailer.push("Content-Length").is_err());
+///
+/// let mut res = Response::new(200);
+/// trailer.apply(&mut res);
+/// assert_eq!(res["Trailer"], "expires, server-timing");
+///
+/// let trailer = Trailer::from_headers(res)?.unwrap();
+/// assert!(trailer.contains("Server-Timing"));
+/// #
+/// # Ok(()) }
+/// ```
+pub struct Trailer {
+    entries: Vec<HeaderName>,
+}
+
+impl Trailer {
+    /// Create a new instance of `Trailer`.
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// Create a new instance from headers.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `400: Bad Request` is returned if a field is
+    /// declared which isn't allowed as a trailer.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> crate::Result<Option<Self>> {
+        let mut trailer = Self::new();
+        let headers = match headers.as_ref().get(headers::TRAILER) {
+            Some(headers) => headers,
+            None => return Ok(None),
+        };
+
+        for value in headers {
+            for part in value.as_str().split(',') {
+                let part = part.trim();
+                if part.is_empty() {
+                    continue;
+                }
+                trailer
+                    .push(HeaderName::from_str(part)?)
+                    .map_err(|mut err| {
+                        err.set_status(400);
+                        err
+                    })?;
+            }
+        }
+
+        Ok(Some(trailer))
+    }
+
+    /// Sets the `Trailer` header.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        self.apply_header(headers.as_mut());
+    }
+
+    /// Declare a field which will be sent as a trailer.
+    ///
+    /// Fields which are already declared are ignored.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the field isn't allowed as a trailer.
+    pub fn push(&mut self, name: impl Into<HeaderName>) -> crate::Result<()> {
+        let name = name.into();
+        if FORBIDDEN.contains(&name) {
+            bail!(500, "`{}` is not allowed as a trailer", name);
+        }
+        if !self.contains(&name) {
+            self.entries.push(name);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the field is declared as a trailer.
+    pub fn contains(&self, name: impl Into<HeaderName>) -> bool {
+        self.entries.contains(&name.into())
+    }
+
+    /// An iterator visiting all declared fields.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+}
+
+impl Header for Trailer {
+    fn header_name(&self) -> HeaderName {
+        headers::TRAILER
+    }
+
+    fn header_value(&self) -> HeaderValue {
+        let mut output = String::new();
+        for (n, name) in self.entries.iter().enumerate() {
+            match n {
+                0 => write!(output, "{}", name).unwrap(),
+                _ => write!(output, ", {}", name).unwrap(),
+            };
+        }
+
+        // SAFETY: the internal string is validated to be ASCII.
+        unsafe { HeaderValue::from_bytes_unchecked(output.into()) }
+    }
+}
+
+impl Default for Trailer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a Trailer {
+    type Item = &'a HeaderName;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A lending iterator over fields in `Trailer`.
+#[derive(Debug)]
+pub struct Iter<'a> {
+    inner: slice::Iter<'a, HeaderName>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a HeaderName;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl Debug for Trailer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        for name in &self.entries {
+            list.entry(name);
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::headers::{Headers, CONTENT_LENGTH, EXPIRES, TRAILER};
+
+    #[test]
+    fn accept_expires() -> crate::Result<()> {
+        let mut trailer = Trailer::new();
+        trailer.push(EXPIRES)?;
+        trailer.push("expires")?;
+
+        let mut headers = Headers::new();
+        trailer.apply(&mut headers);
+        assert_eq!(headers[TRAILER], "expires");
+
+        let trailer = Trailer::from_headers(headers)?.unwrap();
+        assert!(trailer.contains(EXPIRES));
+        assert_eq!(trailer.iter().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_content_length() -> crate::Result<()> {
+        let mut trailer = Trailer::new();
+        assert!(trailer.push(CONTENT_LENGTH).is_err());
+        assert!(trailer.push("Transfer-Encoding").is_err());
+        assert!(!trailer.contains(CONTENT_LENGTH));
+
+        let mut headers = Headers::new();
+        headers.insert(TRAILER, "Expires, Content-Length")?;
+        let err = Trailer::from_headers(headers).unwrap_err();
+        assert_eq!(err.status(), 400);
+        Ok(())
+    }
+}
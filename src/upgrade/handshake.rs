@@ -0,0 +1,44 @@
+use crate::headers::{HeaderName, Headers, CONNECTION, UPGRADE};
+
+/// Returns `true` if the headers request a protocol upgrade, meaning the
+/// `Connection` header contains the `upgrade` option and an `Upgrade` header
+/// is present.
+///
+/// # Specifications
+///
+/// - [RFC 7230, section 6.7: Upgrade](https://tools.ietf.org/html/rfc7230#section-6.7)
+pub fn is_upgrade_request(headers: impl AsRef<Headers>) -> bool {
+    let headers = headers.as_ref();
+    headers.get(UPGRADE).is_some() && contains_token(headers, CONNECTION, "upgrade")
+}
+
+/// Returns `true` if a comma-separated header contains the token, ignoring
+/// case.
+pub(crate) fn contains_token(headers: &Headers, name: HeaderName, token: &str) -> bool {
+    match headers.get(name) {
+        Some(values) => values
+            .iter()
+            .flat_map(|value| value.as_str().split(','))
+            .any(|option| option.trim().eq_ignore_ascii_case(token)),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn upgrade_request() -> crate::Result<()> {
+        let mut headers = Headers::new();
+        assert!(!is_upgrade_request(&headers));
+
+        headers.insert(UPGRADE, "h2c")?;
+        headers.insert(CONNECTION, "keep-alive")?;
+        assert!(!is_upgrade_request(&headers));
+
+        headers.append(CONNECTION, "HTTP2-Settings, Upgrade")?;
+        assert!(is_upgrade_request(&headers));
+        Ok(())
+    }
+}
@@ -9,11 +9,32 @@
 //! after which it is turned into a stream of bytes. This module provides
 //! primitives for upgrading from HTTP request-response pairs to alternate
 //! protocols.
+//!
+//! # Lifecycle
+//!
+//! 1. The server receives a request asking for an upgrade, for example by
+//!    checking [`is_upgrade_request`].
+//! 2. The application replies with a `101 Switching Protocols` response, and
+//!    keeps the [`Receiver`] returned by [`Response::recv_upgrade`].
+//! 3. After writing the response, the server calls
+//!    [`Response::send_upgrade`] and sends the underlying transport as a
+//!    [`Connection`]. The server must not touch the transport afterwards.
+//! 4. The application awaits the `Receiver`, and is responsible for driving
+//!    the upgraded protocol over the `Connection` until it's closed.
+//!
+//! [`Response::recv_upgrade`]: crate::Response::recv_upgrade
+//! [`Response::send_upgrade`]: crate::Response::send_upgrade
 
 mod connection;
+mod handshake;
 mod receiver;
 mod sender;
+#[cfg(feature = "websocket")]
+mod websocket;
 
 pub use connection::Connection;
+pub use handshake::is_upgrade_request;
 pub use receiver::Receiver;
 pub use sender::Sender;
+#[cfg(feature = "websocket")]
+pub use websocket::{websocket_accept, WebSocketUpgrade};
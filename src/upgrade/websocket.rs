@@ -0,0 +1,212 @@
+use crate::headers::{
+    Headers, CONNECTION, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_PROTOCOL,
+    SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use crate::upgrade::handshake::contains_token;
+use crate::upgrade::is_upgrade_request;
+use crate::{Method, Request, Response, StatusCode};
+
+use sha1::{Digest, Sha1};
+
+/// The GUID appended to the client's key to compute the accept value.
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// The only WebSocket protocol version defined.
+const WEBSOCKET_VERSION: &str = "13";
+
+/// Compute the `Sec-WebSocket-Accept` value for a `Sec-WebSocket-Key`.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 4.2.2: Sending the Server's Opening Handshake](https://tools.ietf.org/html/rfc6455#section-4.2.2)
+///
+/// # Examples
+///
+/// ```
+/// use http_types::upgrade::websocket_accept;
+///
+/// let accept = websocket_accept("dGhlIHNhbXBsZSBub25jZQ==");
+/// assert_eq!(accept, "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+/// ```
+pub fn websocket_accept(key: &str) -> String {
+    let digest = Sha1::digest(format!("{}{}", key.trim(), WEBSOCKET_GUID).as_bytes());
+    base64::encode(digest)
+}
+
+/// A validated WebSocket opening handshake.
+///
+/// # Specifications
+///
+/// - [RFC 6455, section 4.2: Server-Side Requirements](https://tools.ietf.org/html/rfc6455#section-4.2)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::upgrade::WebSocketUpgrade;
+/// use http_types::{Method, Request, StatusCode};
+///
+/// let mut req = Request::new(Method::Get, "https://example.com/chat");
+/// req.insert_header("Upgrade", "websocket")?;
+/// req.insert_header("Connection", "Upgrade")?;
+/// req.insert_header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==")?;
+/// req.insert_header("Sec-WebSocket-Version", "13")?;
+///
+/// let upgrade = WebSocketUpgrade::from_request(&req)?;
+/// let res = upgrade.response();
+/// assert_eq!(res.status(), StatusCode::SwitchingProtocols);
+/// assert_eq!(res["Sec-WebSocket-Accept"], "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+/// #
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WebSocketUpgrade {
+    key: String,
+    protocols: Vec<String>,
+}
+
+impl WebSocketUpgrade {
+    /// Validate the opening handshake of a request.
+    ///
+    /// # Errors
+    ///
+    /// An error with status `426: Upgrade Required` is returned if the request
+    /// uses an unsupported WebSocket version. Any other invalid handshake
+    /// returns an error with status `400: Bad Request`.
+    pub fn from_request(req: &Request) -> crate::Result<Self> {
+        crate::ensure_status!(
+            req.method() == Method::Get,
+            400,
+            "WebSocket handshakes must use the GET method"
+        );
+        crate::ensure_status!(
+            is_upgrade_request(req) && contains_token(req.as_ref(), UPGRADE, "websocket"),
+            400,
+            "Expected an upgrade to the websocket protocol"
+        );
+
+        let version = req.header(SEC_WEBSOCKET_VERSION).map(|v| v.last().as_str());
+        crate::ensure_status!(
+            version.map(str::trim) == Some(WEBSOCKET_VERSION),
+            426,
+            "Unsupported WebSocket version"
+        );
+
+        let key = match req.header(SEC_WEBSOCKET_KEY) {
+            Some(key) => key.last().as_str().trim(),
+            None => crate::bail_status!(400, "Missing the Sec-WebSocket-Key header"),
+        };
+        // The key is a base64-encoded 16-byte nonce.
+        crate::ensure_status!(
+            matches!(base64::decode(key), Ok(nonce) if nonce.len() == 16),
+            400,
+            "Invalid Sec-WebSocket-Key header"
+        );
+
+        let protocols = match req.header(SEC_WEBSOCKET_PROTOCOL) {
+            Some(values) => values
+                .iter()
+                .flat_map(|value| value.as_str().split(','))
+                .map(|protocol| protocol.trim().to_string())
+                .filter(|protocol| !protocol.is_empty())
+                .collect(),
+            None => vec![],
+        };
+
+        Ok(Self {
+            key: key.to_string(),
+            protocols,
+        })
+    }
+
+    /// Get the `Sec-WebSocket-Key` sent by the client.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the subprotocols requested by the client, in order of preference.
+    pub fn protocols(&self) -> &[String] {
+        &self.protocols
+    }
+
+    /// Get the `Sec-WebSocket-Accept` value to respond with.
+    pub fn accept(&self) -> String {
+        websocket_accept(&self.key)
+    }
+
+    /// Sets the `Upgrade`, `Connection`, and `Sec-WebSocket-Accept` headers
+    /// of the handshake response.
+    pub fn apply(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(UPGRADE, "websocket").unwrap();
+        headers.insert(CONNECTION, "Upgrade").unwrap();
+        headers.insert(SEC_WEBSOCKET_ACCEPT, self.accept()).unwrap();
+    }
+
+    /// Create the `101 Switching Protocols` handshake response.
+    pub fn response(&self) -> Response {
+        let mut res = Response::new(StatusCode::SwitchingProtocols);
+        self.apply(&mut res);
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn handshake() -> crate::Result<Request> {
+        let mut req = Request::new(Method::Get, "https://server.example.com/chat");
+        req.insert_header(UPGRADE, "websocket")?;
+        req.insert_header(CONNECTION, "keep-alive, Upgrade")?;
+        req.insert_header(SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")?;
+        req.insert_header(SEC_WEBSOCKET_PROTOCOL, "chat, superchat")?;
+        req.insert_header(SEC_WEBSOCKET_VERSION, "13")?;
+        Ok(req)
+    }
+
+    #[test]
+    fn rfc_6455_example() -> crate::Result<()> {
+        assert_eq!(
+            websocket_accept("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+
+        let req = handshake()?;
+        assert!(is_upgrade_request(&req));
+
+        let upgrade = WebSocketUpgrade::from_request(&req)?;
+        assert_eq!(upgrade.key(), "dGhlIHNhbXBsZSBub25jZQ==");
+        assert_eq!(upgrade.protocols(), ["chat", "superchat"]);
+
+        let res = upgrade.response();
+        assert_eq!(res.status(), StatusCode::SwitchingProtocols);
+        assert_eq!(res[UPGRADE], "websocket");
+        assert_eq!(res[CONNECTION], "Upgrade");
+        assert_eq!(res[SEC_WEBSOCKET_ACCEPT], "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+        Ok(())
+    }
+
+    #[test]
+    fn not_an_upgrade() {
+        let req = Request::new(Method::Get, "https://server.example.com/chat");
+        assert!(!is_upgrade_request(&req));
+        let err = WebSocketUpgrade::from_request(&req).unwrap_err();
+        assert_eq!(err.status(), 400);
+    }
+
+    #[test]
+    fn bad_request_on_invalid_handshake() -> crate::Result<()> {
+        let mut req = handshake()?;
+        req.insert_header(SEC_WEBSOCKET_KEY, "c2hvcnQ=")?;
+        let err = WebSocketUpgrade::from_request(&req).unwrap_err();
+        assert_eq!(err.status(), 400);
+
+        let mut req = handshake()?;
+        req.insert_header(SEC_WEBSOCKET_VERSION, "8")?;
+        let err = WebSocketUpgrade::from_request(&req).unwrap_err();
+        assert_eq!(err.status(), 426);
+        Ok(())
+    }
+}
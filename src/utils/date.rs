@@ -13,11 +13,36 @@ const YEAR_9999_SECONDS: u64 = 253402300800;
 const SECONDS_IN_DAY: u64 = 86400;
 const SECONDS_IN_HOUR: u64 = 3600;
 
-/// Format using the `Display` trait.
-/// Convert timestamp into/from `SytemTime` to use.
-/// Supports comparison and sorting.
+/// A date as used in HTTP header fields.
+///
+/// Dates are parsed from the preferred IMF-fixdate format as well as the
+/// legacy RFC 850 and asctime formats, and are always formatted as
+/// IMF-fixdate using the `Display` trait. Convert to and from `SystemTime` to
+/// do arithmetic. Supports comparison and sorting.
+///
+/// # Specifications
+///
+/// - [RFC 7231, section 7.1.1.1: Date/Time Formats](https://tools.ietf.org/html/rfc7231#section-7.1.1.1)
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> http_types::Result<()> {
+/// #
+/// use http_types::HttpDate;
+///
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+///
+/// let date: HttpDate = "Sunday, 06-Nov-94 08:49:37 GMT".parse()?;
+/// assert_eq!(date.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+///
+/// let time: SystemTime = date.into();
+/// assert_eq!(time, UNIX_EPOCH + Duration::from_secs(784111777));
+/// #
+/// # Ok(()) }
+/// ```
 #[derive(Copy, Clone, Debug, Eq)]
-pub(crate) struct HttpDate {
+pub struct HttpDate {
     /// 0...59
     second: u8,
     /// 0...59
@@ -54,6 +79,13 @@ pub(crate) fn fmt_http_date(d: SystemTime) -> String {
 }
 
 impl HttpDate {
+    /// Create a new instance with the date set to now.
+    ///
+    /// The date is truncated to whole seconds.
+    pub fn now() -> Self {
+        SystemTime::now().into()
+    }
+
     fn is_valid(self) -> bool {
         self.second < 60
             && self.minute < 60
@@ -418,6 +450,7 @@ mod tests {
     use std::time::{Duration, UNIX_EPOCH};
 
     use super::{fmt_http_date, parse_http_date, HttpDate, SECONDS_IN_DAY, SECONDS_IN_HOUR};
+    use std::time::SystemTime;
 
     #[test]
     fn test_rfc_example() {
@@ -475,6 +508,29 @@ mod tests {
         assert_eq!(fmt_http_date(d), "Sun, 02 Oct 2016 14:44:11 GMT");
     }
 
+    #[test]
+    fn input_formats() -> crate::Result<()> {
+        let expected = HttpDate::from(UNIX_EPOCH + Duration::from_secs(784111777));
+        let imf_fixdate: HttpDate = "Sun, 06 Nov 1994 08:49:37 GMT".parse()?;
+        let rfc850: HttpDate = "Sunday, 06-Nov-94 08:49:37 GMT".parse()?;
+        let asctime: HttpDate = "Sun Nov  6 08:49:37 1994".parse()?;
+        assert_eq!(imf_fixdate, expected);
+        assert_eq!(rfc850, expected);
+        assert_eq!(asctime, expected);
+
+        // All formats are written as IMF-fixdate.
+        assert_eq!(rfc850.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(asctime.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+        Ok(())
+    }
+
+    #[test]
+    fn now() {
+        let before = SystemTime::now() - Duration::from_secs(1);
+        let now = SystemTime::from(HttpDate::now());
+        assert!(now >= before && now <= SystemTime::now());
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(::std::mem::size_of::<HttpDate>(), 8);
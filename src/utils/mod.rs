@@ -2,11 +2,13 @@ mod date;
 
 pub(crate) use date::fmt_http_date;
 pub(crate) use date::parse_http_date;
-pub(crate) use date::HttpDate;
+pub use date::HttpDate;
 
-use crate::{Error, Status, StatusCode};
+use crate::headers::Headers;
+use crate::{Body, Error, Status, StatusCode};
 
 use std::cmp::Ordering;
+use std::fmt::Write;
 use std::str::FromStr;
 
 /// Parse a weight of the form `q=0.123`.
@@ -40,3 +42,56 @@ pub(crate) fn sort_by_weight<T: PartialOrd + Clone>(props: &mut Vec<T>) {
     });
     *props = arr.into_iter().map(|(_, t)| t).collect::<Vec<T>>();
 }
+
+/// The maximum number of bytes of the body shown by `debug_dump`.
+const DEBUG_BODY_PREVIEW_LEN: usize = 256;
+
+/// Render a message as text resembling its wire format, for debugging.
+///
+/// Headers are sorted by name. Bodies are only shown if they're held in
+/// memory, in which case they're truncated, and shown in hex unless they're
+/// text. The body is never read.
+pub(crate) fn debug_dump(start_line: &str, headers: &Headers, body: &Body) -> String {
+    let mut output = format!("{}\r\n", start_line);
+    for (name, values) in headers.iter_sorted() {
+        for value in values {
+            let _ = write!(output, "{}: {}\r\n", name, value);
+        }
+    }
+    output.push_str("\r\n");
+
+    let bytes = match body.peek() {
+        Some(bytes) => bytes,
+        None => {
+            output.push_str("<stream>");
+            return output;
+        }
+    };
+    let len = bytes.len().min(DEBUG_BODY_PREVIEW_LEN);
+    let preview = &bytes[..len];
+    let text = match std::str::from_utf8(preview) {
+        Ok(text) => Some(text),
+        // The preview may end in the middle of a character.
+        Err(err) if err.error_len().is_none() => {
+            std::str::from_utf8(&preview[..err.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    };
+    match text {
+        Some(text) if !text.chars().any(|c| c.is_control() && !c.is_whitespace()) => {
+            output.push_str(text);
+        }
+        _ => {
+            for (i, byte) in preview.iter().enumerate() {
+                if i > 0 {
+                    output.push(' ');
+                }
+                let _ = write!(output, "{:02x}", byte);
+            }
+        }
+    }
+    if bytes.len() > len {
+        let _ = write!(output, "... ({} more bytes)", bytes.len() - len);
+    }
+    output
+}
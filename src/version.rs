@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 /// The version of the HTTP protocol in use.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 #[non_exhaustive]
@@ -48,14 +50,8 @@ mod serde {
         where
             E: Error,
         {
-            match v {
-                "HTTP/0.9" => Ok(Version::Http0_9),
-                "HTTP/1.0" => Ok(Version::Http1_0),
-                "HTTP/1.1" => Ok(Version::Http1_1),
-                "HTTP/2" => Ok(Version::Http2_0),
-                "HTTP/3" => Ok(Version::Http3_0),
-                _ => Err(Error::invalid_value(Unexpected::Str(v), &self)),
-            }
+            v.parse()
+                .map_err(|_| Error::invalid_value(Unexpected::Str(v), &self))
         }
 
         fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
@@ -88,6 +84,24 @@ impl AsRef<str> for Version {
     }
 }
 
+impl FromStr for Version {
+    type Err = crate::Error;
+
+    /// Parse a version as it appears on the wire, e.g. `HTTP/1.1`.
+    ///
+    /// `HTTP/2` and `HTTP/3` may also be written as `HTTP/2.0` and `HTTP/3.0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "HTTP/0.9" => Ok(Version::Http0_9),
+            "HTTP/1.0" => Ok(Version::Http1_0),
+            "HTTP/1.1" => Ok(Version::Http1_1),
+            "HTTP/2" | "HTTP/2.0" => Ok(Version::Http2_0),
+            "HTTP/3" | "HTTP/3.0" => Ok(Version::Http3_0),
+            _ => crate::bail!("Invalid HTTP version"),
+        }
+    }
+}
+
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.as_ref())
@@ -120,6 +134,23 @@ mod test {
         assert_eq!("HTTP/0.9 HTTP/1.0 HTTP/1.1 HTTP/2 HTTP/3", output);
     }
 
+    #[test]
+    fn from_str() -> crate::Result<()> {
+        assert_eq!("HTTP/0.9".parse::<Version>()?, Version::Http0_9);
+        assert_eq!("HTTP/1.0".parse::<Version>()?, Version::Http1_0);
+        assert_eq!("HTTP/1.1".parse::<Version>()?, Version::Http1_1);
+        assert_eq!("HTTP/2".parse::<Version>()?, Version::Http2_0);
+        assert_eq!("HTTP/3".parse::<Version>()?, Version::Http3_0);
+        assert_eq!("HTTP/3.0".parse::<Version>()?, Version::Http3_0);
+        assert!("HTTP/4".parse::<Version>().is_err());
+        assert!("http/1.1".parse::<Version>().is_err());
+
+        for version in &[Version::Http1_1, Version::Http2_0, Version::Http3_0] {
+            assert_eq!(version.to_string().parse::<Version>()?, *version);
+        }
+        Ok(())
+    }
+
     #[test]
     fn ord() {
         use Version::*;
@@ -133,6 +164,8 @@ mod test {
     fn serde() -> Result<(), serde_json::Error> {
         assert_eq!("\"HTTP/3\"", serde_json::to_string(&Version::Http3_0)?);
         assert_eq!(Version::Http1_1, serde_json::from_str("\"HTTP/1.1\"")?);
+        assert_eq!(Version::Http3_0, serde_json::from_str("\"HTTP/3\"")?);
+        assert!(serde_json::from_str::<Version>("\"HTTP/4\"").is_err());
         Ok(())
     }
 }
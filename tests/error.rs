@@ -124,3 +124,48 @@ fn fail_test_u16_into_status_code_in_http_types_error_new() {
 fn fail_test_u16_into_status_code_in_http_types_error_from_str() {
     let _http_types_error = Error::from_str(1000, "Incorrect status code");
 }
+
+#[derive(Debug)]
+struct CustomError(u32);
+
+impl std::fmt::Display for CustomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "custom error {}", self.0)
+    }
+}
+
+impl std::error::Error for CustomError {}
+
+#[test]
+fn downcast_to_custom_error() {
+    let mut err = Error::new(StatusCode::Conflict, CustomError(7));
+    assert_eq!(err.downcast_ref::<CustomError>().unwrap().0, 7);
+
+    err.downcast_mut::<CustomError>().unwrap().0 = 8;
+    let custom = err.downcast::<CustomError>().unwrap();
+    assert_eq!(custom.0, 8);
+}
+
+#[test]
+fn downcast_to_wrong_type_keeps_status() {
+    let err = Error::new(StatusCode::Conflict, CustomError(7));
+    assert!(err.downcast_ref::<io::Error>().is_none());
+
+    let err = err.downcast::<io::Error>().unwrap_err();
+    assert_eq!(err.status(), StatusCode::Conflict);
+    assert_eq!(err.to_string(), "custom error 7");
+}
+
+#[test]
+fn status_and_message() {
+    let err = Error::from_str(StatusCode::NotFound, format!("No user with id {}", 12));
+    assert_eq!(err.status(), StatusCode::NotFound);
+    assert_eq!(err.to_string(), "No user with id 12");
+    assert!(err.type_name().is_none());
+
+    let source = io::Error::new(io::ErrorKind::PermissionDenied, "access denied");
+    let err = Error::new(StatusCode::Forbidden, source);
+    assert_eq!(err.status(), StatusCode::Forbidden);
+    assert_eq!(err.to_string(), "access denied");
+    assert!(err.type_name().is_some());
+}
@@ -20,3 +20,44 @@ fn security_test() {
 
     assert_eq!(res["content-security-policy"], "base-uri 'none'; default-src 'self' areweasyncyet.rs; object-src 'none'; script-src 'self' 'unsafe-inline'; upgrade-insecure-requests");
 }
+
+#[test]
+fn csp_two_directives() {
+    let mut policy = security::ContentSecurityPolicy::new();
+    policy
+        .script_src(security::Source::SameOrigin)
+        .script_src("cdn.example.com")
+        .default_src(security::Source::None);
+
+    let mut res = Response::new(StatusCode::Ok);
+    policy.apply(&mut res);
+    assert_eq!(
+        res["content-security-policy"],
+        "default-src 'none'; script-src 'self' cdn.example.com"
+    );
+
+    // Applying the policy again yields the same header.
+    policy.apply(&mut res);
+    assert_eq!(
+        res["content-security-policy"],
+        "default-src 'none'; script-src 'self' cdn.example.com"
+    );
+}
+
+#[test]
+fn csp_quotes_keyword_sources() {
+    let mut policy = security::ContentSecurityPolicy::new();
+    policy
+        .script_src("self")
+        .script_src("'unsafe-inline'")
+        .script_src("nonce-2726c7f26c")
+        .script_src("https:")
+        .script_src("*.example.com");
+
+    let mut res = Response::new(StatusCode::Ok);
+    policy.apply(&mut res);
+    assert_eq!(
+        res["content-security-policy"],
+        "script-src 'self' 'unsafe-inline' 'nonce-2726c7f26c' https: *.example.com"
+    );
+}